@@ -34,6 +34,16 @@ mod m20251119_142500_create_organisation_dimension_table;
 mod m20251121_112500_add_coop_and_org_ids_to_assessments;
 mod m20251122_000001_create_report_enums;
 mod m20251203_133800_alter_reports_timestamp_columns;
+mod m20261018_000001_add_scores_to_assessments;
+mod m20261018_000002_add_gap_thresholds_to_dimensions;
+mod m20261018_000003_add_previous_assessment_id_to_assessments;
+mod m20261018_000004_add_job_columns_to_reports;
+mod m20261018_000005_create_report_templates;
+mod m20261018_000006_add_translations;
+mod m20261018_000007_add_portfolio_reports;
+mod m20261018_000008_add_file_sha256_to_reports;
+mod m20261018_000009_create_framework_versions;
+mod m20261018_000010_add_claimed_at_to_reports;
 
 pub struct Migrator;
 
@@ -77,6 +87,16 @@ impl MigratorTrait for Migrator {
             Box::new(m20251121_112500_add_coop_and_org_ids_to_assessments::Migration),
             Box::new(m20251122_000001_create_report_enums::Migration),
             Box::new(m20251203_133800_alter_reports_timestamp_columns::Migration),
+            Box::new(m20261018_000001_add_scores_to_assessments::Migration),
+            Box::new(m20261018_000002_add_gap_thresholds_to_dimensions::Migration),
+            Box::new(m20261018_000003_add_previous_assessment_id_to_assessments::Migration),
+            Box::new(m20261018_000004_add_job_columns_to_reports::Migration),
+            Box::new(m20261018_000005_create_report_templates::Migration),
+            Box::new(m20261018_000006_add_translations::Migration),
+            Box::new(m20261018_000007_add_portfolio_reports::Migration),
+            Box::new(m20261018_000008_add_file_sha256_to_reports::Migration),
+            Box::new(m20261018_000009_create_framework_versions::Migration),
+            Box::new(m20261018_000010_add_claimed_at_to_reports::Migration),
         ]
     }
 }
@@ -0,0 +1,38 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Assessments::Table)
+                    .add_column(ColumnDef::new(Assessments::OverallScore).double().null())
+                    .add_column(ColumnDef::new(Assessments::Scores).json_binary().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Assessments::Table)
+                    .drop_column(Assessments::OverallScore)
+                    .drop_column(Assessments::Scores)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Assessments {
+    Table,
+    OverallScore,
+    Scores,
+}
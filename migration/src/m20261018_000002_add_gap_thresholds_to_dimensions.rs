@@ -0,0 +1,46 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Dimensions::Table)
+                    .add_column(
+                        ColumnDef::new(Dimensions::GapMediumThreshold)
+                            .integer()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(Dimensions::GapHighThreshold)
+                            .integer()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Dimensions::Table)
+                    .drop_column(Dimensions::GapMediumThreshold)
+                    .drop_column(Dimensions::GapHighThreshold)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Dimensions {
+    Table,
+    GapMediumThreshold,
+    GapHighThreshold,
+}
@@ -0,0 +1,62 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Assessments::Table)
+                    .add_column(
+                        ColumnDef::new(Assessments::PreviousAssessmentId)
+                            .uuid()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Deleting a predecessor keeps its re-assessments
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_assessments_previous_assessment")
+                    .from(Assessments::Table, Assessments::PreviousAssessmentId)
+                    .to(Assessments::Table, Assessments::AssessmentId)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("fk_assessments_previous_assessment")
+                    .table(Assessments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Assessments::Table)
+                    .drop_column(Assessments::PreviousAssessmentId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Assessments {
+    Table,
+    AssessmentId,
+    PreviousAssessmentId,
+}
@@ -0,0 +1,82 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Reports::Table)
+                    .add_column(ColumnDef::new(Reports::Options).json_binary().null())
+                    .add_column(
+                        ColumnDef::new(Reports::Progress)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .add_column(
+                        ColumnDef::new(Reports::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .add_column(
+                        ColumnDef::new(Reports::NextAttemptAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .add_column(ColumnDef::new(Reports::FailureReason).text().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_reports_status_next_attempt_at")
+                    .table(Reports::Table)
+                    .col(Reports::Status)
+                    .col(Reports::NextAttemptAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_reports_status_next_attempt_at")
+                    .table(Reports::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Reports::Table)
+                    .drop_column(Reports::Options)
+                    .drop_column(Reports::Progress)
+                    .drop_column(Reports::Attempts)
+                    .drop_column(Reports::NextAttemptAt)
+                    .drop_column(Reports::FailureReason)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Reports {
+    Table,
+    Status,
+    Options,
+    Progress,
+    Attempts,
+    NextAttemptAt,
+    FailureReason,
+}
@@ -0,0 +1,113 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReportTemplates::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ReportTemplates::TemplateId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    // NULL for the default template of all organizations
+                    .col(
+                        ColumnDef::new(ReportTemplates::OrganizationId)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(ReportTemplates::ReportType)
+                            .enumeration(
+                                ReportType::Table,
+                                [
+                                    ReportType::Summary,
+                                    ReportType::Detailed,
+                                    ReportType::ActionPlan,
+                                ],
+                            )
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ReportTemplates::Version)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ReportTemplates::Content).text().not_null())
+                    .col(
+                        ColumnDef::new(ReportTemplates::Branding)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ReportTemplates::IsActive)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(ColumnDef::new(ReportTemplates::CreatedBy).string().null())
+                    .col(
+                        ColumnDef::new(ReportTemplates::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ReportTemplates::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_report_templates_org_type_version")
+                    .table(ReportTemplates::Table)
+                    .col(ReportTemplates::OrganizationId)
+                    .col(ReportTemplates::ReportType)
+                    .col(ReportTemplates::Version)
+                    .unique()
+                    .nulls_not_distinct()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ReportTemplates::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ReportTemplates {
+    Table,
+    TemplateId,
+    OrganizationId,
+    ReportType,
+    Version,
+    Content,
+    Branding,
+    IsActive,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum ReportType {
+    Table,
+    Summary,
+    Detailed,
+    ActionPlan,
+}
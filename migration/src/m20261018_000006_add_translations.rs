@@ -0,0 +1,68 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Texts per locale, e.g. {"fr": {"name": "...", "description": "..."}}
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Dimensions::Table)
+                    .add_column(
+                        ColumnDef::new(Dimensions::Translations)
+                            .json_binary()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Recommendations::Table)
+                    .add_column(
+                        ColumnDef::new(Recommendations::Translations)
+                            .json_binary()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Recommendations::Table)
+                    .drop_column(Recommendations::Translations)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Dimensions::Table)
+                    .drop_column(Dimensions::Translations)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Dimensions {
+    Table,
+    Translations,
+}
+
+#[derive(DeriveIden)]
+enum Recommendations {
+    Table,
+    Translations,
+}
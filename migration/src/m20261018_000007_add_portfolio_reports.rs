@@ -0,0 +1,70 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TYPE report_type ADD VALUE IF NOT EXISTS 'portfolio'")
+            .await?;
+
+        // Portfolio reports aggregate a whole organization instead of a
+        // single assessment
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Reports::Table)
+                    .modify_column(ColumnDef::new(Reports::AssessmentId).uuid().null())
+                    .add_column(ColumnDef::new(Reports::OrganizationId).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_reports_organization_id")
+                    .table(Reports::Table)
+                    .col(Reports::OrganizationId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Postgres cannot drop an enum value, 'portfolio' stays unused
+        manager
+            .get_connection()
+            .execute_unprepared("DELETE FROM \"reports\" WHERE \"assessment_id\" IS NULL")
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_reports_organization_id")
+                    .table(Reports::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Reports::Table)
+                    .drop_column(Reports::OrganizationId)
+                    .modify_column(ColumnDef::new(Reports::AssessmentId).uuid().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Reports {
+    Table,
+    AssessmentId,
+    OrganizationId,
+}
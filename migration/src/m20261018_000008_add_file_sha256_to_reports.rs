@@ -0,0 +1,37 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Hex SHA-256 of the stored file, so a downloaded copy can be
+        // verified against what the platform issued
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Reports::Table)
+                    .add_column(ColumnDef::new(Reports::FileSha256).string_len(64).null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Reports::Table)
+                    .drop_column(Reports::FileSha256)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Reports {
+    Table,
+    FileSha256,
+}
@@ -0,0 +1,284 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FrameworkVersions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(FrameworkVersions::FrameworkVersionId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(FrameworkVersions::VersionNumber)
+                            .integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(FrameworkVersions::Notes).text().null())
+                    .col(ColumnDef::new(FrameworkVersions::CreatedBy).string().null())
+                    // NULL while the version is a draft
+                    .col(
+                        ColumnDef::new(FrameworkVersions::PublishedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(FrameworkVersions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FrameworkVersions::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+
+        // At most one draft at a time
+        db.execute_unprepared(
+            "CREATE UNIQUE INDEX \"idx_framework_versions_single_draft\" \
+             ON \"framework_versions\" ((\"published_at\" IS NULL)) \
+             WHERE \"published_at\" IS NULL",
+        )
+        .await?;
+
+        // The existing framework becomes the first published version
+        db.execute_unprepared(
+            "INSERT INTO \"framework_versions\" \
+             (\"framework_version_id\", \"version_number\", \"notes\", \"published_at\", \"created_at\", \"updated_at\") \
+             VALUES (gen_random_uuid(), 1, 'Framework before versioning', now(), now(), now())",
+        )
+        .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Dimensions::Table)
+                    .add_column(ColumnDef::new(Dimensions::FrameworkVersionId).uuid().null())
+                    .add_column(ColumnDef::new(Dimensions::OriginDimensionId).uuid().null())
+                    .to_owned(),
+            )
+            .await?;
+        db.execute_unprepared(
+            "UPDATE \"dimensions\" SET \
+             \"framework_version_id\" = (SELECT \"framework_version_id\" FROM \"framework_versions\" WHERE \"version_number\" = 1), \
+             \"origin_dimension_id\" = \"dimension_id\"",
+        )
+        .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Dimensions::Table)
+                    .modify_column(
+                        ColumnDef::new(Dimensions::FrameworkVersionId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .modify_column(
+                        ColumnDef::new(Dimensions::OriginDimensionId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_dimensions_framework_version")
+                    .from(Dimensions::Table, Dimensions::FrameworkVersionId)
+                    .to(
+                        FrameworkVersions::Table,
+                        FrameworkVersions::FrameworkVersionId,
+                    )
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Every version has its own copy of a dimension, under the same name
+        db.execute_unprepared(
+            "ALTER TABLE \"dimensions\" DROP CONSTRAINT IF EXISTS \"dimensions_name_key\"",
+        )
+        .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_dimensions_framework_version_name")
+                    .table(Dimensions::Table)
+                    .col(Dimensions::FrameworkVersionId)
+                    .col(Dimensions::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_dimensions_origin_dimension_id")
+                    .table(Dimensions::Table)
+                    .col(Dimensions::OriginDimensionId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Assessments::Table)
+                    .add_column(
+                        ColumnDef::new(Assessments::FrameworkVersionId)
+                            .uuid()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        db.execute_unprepared(
+            "UPDATE \"assessments\" SET \"framework_version_id\" = \
+             (SELECT \"framework_version_id\" FROM \"framework_versions\" WHERE \"version_number\" = 1)",
+        )
+        .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Assessments::Table)
+                    .modify_column(
+                        ColumnDef::new(Assessments::FrameworkVersionId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // A version assessments are pinned to cannot be deleted
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_assessments_framework_version")
+                    .from(Assessments::Table, Assessments::FrameworkVersionId)
+                    .to(
+                        FrameworkVersions::Table,
+                        FrameworkVersions::FrameworkVersionId,
+                    )
+                    .on_delete(ForeignKeyAction::Restrict)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("fk_assessments_framework_version")
+                    .table(Assessments::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Assessments::Table)
+                    .drop_column(Assessments::FrameworkVersionId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Names are unique again, so only the latest published version's
+        // dimensions can be kept
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "DELETE FROM \"dimensions\" WHERE \"framework_version_id\" IS DISTINCT FROM \
+                 (SELECT \"framework_version_id\" FROM \"framework_versions\" \
+                  WHERE \"published_at\" IS NOT NULL ORDER BY \"version_number\" DESC LIMIT 1)",
+            )
+            .await?;
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_dimensions_origin_dimension_id")
+                    .table(Dimensions::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_dimensions_framework_version_name")
+                    .table(Dimensions::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("fk_dimensions_framework_version")
+                    .table(Dimensions::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Dimensions::Table)
+                    .drop_column(Dimensions::FrameworkVersionId)
+                    .drop_column(Dimensions::OriginDimensionId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE \"dimensions\" ADD CONSTRAINT \"dimensions_name_key\" UNIQUE (\"name\")",
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(FrameworkVersions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum FrameworkVersions {
+    Table,
+    FrameworkVersionId,
+    VersionNumber,
+    Notes,
+    CreatedBy,
+    PublishedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Dimensions {
+    Table,
+    Name,
+    FrameworkVersionId,
+    OriginDimensionId,
+}
+
+#[derive(DeriveIden)]
+enum Assessments {
+    Table,
+    FrameworkVersionId,
+}
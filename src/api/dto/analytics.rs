@@ -0,0 +1,81 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::services::dimension_scoring::DimensionScore;
+
+/// Scores of one completed assessment in a maturity trend
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MaturityTrendPoint {
+    pub assessment_id: Uuid,
+    pub document_title: String,
+    pub cooperation_id: Option<String>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Digital maturity index (0-100)
+    pub maturity_index: f64,
+    pub overall_current_score: f64,
+    pub overall_desired_score: f64,
+    pub overall_gap: f64,
+    pub dimensions: Vec<DimensionScore>,
+}
+
+/// Completed assessments of an organization or cooperation, oldest first
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MaturityTrendResponse {
+    pub organization_id: Option<String>,
+    pub cooperation_id: Option<String>,
+    pub points: Vec<MaturityTrendPoint>,
+    /// Maturity index of the latest point minus the first one
+    pub maturity_index_change: Option<f64>,
+}
+
+/// Benchmark filters
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct BenchmarkQuery {
+    /// Only include dimensions of this category
+    pub category: Option<String>,
+}
+
+/// Quartiles and mean of a set of state scores
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ScoreDistribution {
+    pub lower_quartile: f64,
+    pub median: f64,
+    pub upper_quartile: f64,
+    pub mean: f64,
+}
+
+/// Share (0-1) of the cohort at each gap severity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct GapSeverityShare {
+    pub low: f64,
+    pub medium: f64,
+    pub high: f64,
+}
+
+/// Peer distribution of one dimension
+///
+/// When fewer organizations than the minimum cohort size answered the
+/// dimension, only the dimension is listed and `suppressed` is set.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DimensionBenchmark {
+    pub dimension_id: Uuid,
+    pub dimension_name: String,
+    pub category: Option<String>,
+    pub suppressed: bool,
+    /// Number of assessed cooperatives (latest completed assessment each)
+    pub cohort_size: Option<usize>,
+    pub current_score: Option<ScoreDistribution>,
+    pub desired_score: Option<ScoreDistribution>,
+    pub gap_severity_share: Option<GapSeverityShare>,
+}
+
+/// Anonymized benchmark over all completed assessments
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BenchmarkResponse {
+    pub category: Option<String>,
+    /// Distinct organizations a dimension needs to be reported
+    pub min_cohort_size: usize,
+    pub dimensions: Vec<DimensionBenchmark>,
+}
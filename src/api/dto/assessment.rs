@@ -19,9 +19,10 @@ pub struct CreateAssessmentRequest {
 pub struct UpdateAssessmentRequest {
     pub assessment_name: Option<String>,
     pub dimensions_id: Option<Vec<String>>,
+    /// Target status; must be a legal lifecycle transition other than
+    /// completion, which goes through submission. `started_at` and
+    /// `completed_at` are set by the server.
     pub status: Option<AssessmentStatus>,
-    pub started_at: Option<DateTime<Utc>>,
-    pub completed_at: Option<DateTime<Utc>>,
 }
 
 /// Assessment response
@@ -37,6 +38,9 @@ pub struct AssessmentResponse {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub dimensions_id: Option<serde_json::Value>,
+    pub previous_assessment_id: Option<Uuid>,
+    /// Framework version the assessment is answered against
+    pub framework_version_id: Uuid,
 }
 
 /// Assessment status enumeration
@@ -74,21 +78,24 @@ impl FromStr for AssessmentStatus {
 }
 
 /// Dimension assessment creation request
+///
+/// The organization is always the assessment's.
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateDimensionAssessmentRequest {
     pub dimension_id: Uuid,
     pub current_state_id: Uuid,
     pub desired_state_id: Uuid,
-    pub gap_score: i32,
-    pub organization_id: String,
     pub cooperation_id: Option<String>,
 }
 
 /// Dimension assessment update request
+///
+/// The gap is recomputed from the resulting current and desired states.
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UpdateDimensionAssessmentRequest {
     pub dimension_id: Uuid,
-    pub gap_score: Option<i32>,
+    pub current_state_id: Option<Uuid>,
+    pub desired_state_id: Option<Uuid>,
 }
 
 /// Dimension assessment response
@@ -99,6 +106,7 @@ pub struct DimensionAssessmentResponse {
     pub dimension_id: Uuid,
     pub current_state_id: Uuid,
     pub desired_state_id: Uuid,
+    /// Difference between the desired and the current state score
     pub gap_score: i32,
     pub gap_id: Uuid,
     pub organization_id: String,
@@ -114,7 +122,9 @@ pub struct AssessmentSummaryResponse {
     pub dimension_assessments: Vec<DimensionAssessmentResponse>,
     pub gaps_count: u32,
     pub recommendations_count: u32,
-    pub overall_score: Option<i32>,
+    /// Digital maturity index (0-100). Frozen at submission, live before that.
+    pub overall_score: Option<f64>,
+    pub scores: Option<crate::services::dimension_scoring::AssessmentScore>,
 }
 
 /// Assessment list response with pagination
@@ -138,3 +148,41 @@ pub struct AssessmentsResponse {
 pub struct DimensionAssessmentsResponse {
     pub dimension_assessments: Vec<DimensionAssessmentResponse>,
 }
+
+/// Re-assessment request
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReassessRequest {
+    /// Name of the new assessment; defaults to the predecessor's name
+    pub assessment_name: Option<String>,
+}
+
+/// Change of one dimension between two assessments
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DimensionComparison {
+    /// Origin dimension, the same across framework versions
+    pub dimension_id: Uuid,
+    pub dimension_name: String,
+    pub base_current_score: Option<i32>,
+    pub target_current_score: Option<i32>,
+    pub current_score_delta: Option<i32>,
+    pub base_desired_score: Option<i32>,
+    pub target_desired_score: Option<i32>,
+    pub desired_score_delta: Option<i32>,
+    pub base_gap_severity: Option<crate::api::dto::gap::GapSeverity>,
+    pub target_gap_severity: Option<crate::api::dto::gap::GapSeverity>,
+    /// Change in severity level (LOW = 1, MEDIUM = 2, HIGH = 3); negative means the gap shrank
+    pub gap_severity_delta: Option<i32>,
+}
+
+/// Comparison of two assessments of the same organization
+///
+/// Deltas are `target - base` and only set when both assessments cover the dimension.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AssessmentComparisonResponse {
+    pub base_assessment_id: Uuid,
+    pub target_assessment_id: Uuid,
+    pub base_maturity_index: Option<f64>,
+    pub target_maturity_index: Option<f64>,
+    pub maturity_index_delta: Option<f64>,
+    pub dimensions: Vec<DimensionComparison>,
+}
@@ -14,6 +14,9 @@ use uuid::Uuid;
     ApiResponsePaginatedActionPlanResponse = ApiResponse<PaginatedResponse<crate::api::dto::action_plan::ActionPlanResponse>>,
     ApiResponseAssessmentResponse = ApiResponse<crate::api::dto::assessment::AssessmentResponse>,
     ApiResponseAssessmentSummaryResponse = ApiResponse<crate::api::dto::assessment::AssessmentSummaryResponse>,
+    ApiResponseAssessmentComparisonResponse = ApiResponse<crate::api::dto::assessment::AssessmentComparisonResponse>,
+    ApiResponseMaturityTrendResponse = ApiResponse<crate::api::dto::analytics::MaturityTrendResponse>,
+    ApiResponseBenchmarkResponse = ApiResponse<crate::api::dto::analytics::BenchmarkResponse>,
     ApiResponsePaginatedAssessmentResponse = ApiResponse<PaginatedResponse<crate::api::dto::assessment::AssessmentResponse>>,
     ApiResponseDimensionAssessmentResponse = ApiResponse<crate::api::dto::assessment::DimensionAssessmentResponse>,
     ApiResponseAssessmentsResponse = ApiResponse<crate::api::dto::assessment::AssessmentsResponse>,
@@ -25,8 +28,14 @@ use uuid::Uuid;
     ApiResponseDimensionWithStatesResponse = ApiResponse<crate::api::dto::dimension::DimensionWithStatesResponse>,
     ApiResponseReportResponse = ApiResponse<crate::api::dto::report::ReportResponse>,
     ApiResponseReportDownloadResponse = ApiResponse<crate::api::dto::report::ReportDownloadResponse>,
+    ApiResponseReportDownloadLinkResponse = ApiResponse<crate::api::dto::report::ReportDownloadLinkResponse>,
+    ApiResponseReportVerificationResponse = ApiResponse<crate::api::dto::report::ReportVerificationResponse>,
     ApiResponseReportStatusResponse = ApiResponse<crate::api::dto::report::ReportStatusResponse>,
     ApiResponsePaginatedReportResponse = ApiResponse<PaginatedResponse<crate::api::dto::report::ReportResponse>>,
+    ApiResponseReportTemplateResponse = ApiResponse<crate::api::dto::report_template::ReportTemplateResponse>,
+    ApiResponseReportTemplateListResponse = ApiResponse<crate::api::dto::report_template::ReportTemplateListResponse>,
+    ApiResponseFrameworkVersionResponse = ApiResponse<crate::api::dto::framework_version::FrameworkVersionResponse>,
+    ApiResponseFrameworkVersionListResponse = ApiResponse<crate::api::dto::framework_version::FrameworkVersionListResponse>,
     ApiResponseGapResponse = ApiResponse<crate::api::dto::gap::GapResponse>,
     ApiResponsePaginatedGapResponse = ApiResponse<PaginatedResponse<crate::api::dto::gap::GapResponse>>,
     ApiResponseEmpty = ApiResponse<EmptyResponse>,
@@ -1,5 +1,7 @@
+use crate::services::i18n::DimensionTranslation;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -11,6 +13,13 @@ pub struct CreateDimensionRequest {
     pub weight: Option<i32>,
     pub category: Option<String>,
     pub is_active: Option<bool>,
+    /// Smallest gap rated MEDIUM (default 2)
+    pub gap_medium_threshold: Option<i32>,
+    /// Smallest gap rated HIGH (default 4)
+    pub gap_high_threshold: Option<i32>,
+    /// Name and description per locale code (`fr`, `de`, `pt`), used in
+    /// reports written in that locale
+    pub translations: Option<HashMap<String, DimensionTranslation>>,
 }
 
 /// Dimension update request
@@ -21,6 +30,13 @@ pub struct UpdateDimensionRequest {
     pub weight: Option<i32>,
     pub category: Option<String>,
     pub is_active: Option<bool>,
+    /// Smallest gap rated MEDIUM (default 2)
+    pub gap_medium_threshold: Option<i32>,
+    /// Smallest gap rated HIGH (default 4)
+    pub gap_high_threshold: Option<i32>,
+    /// Name and description per locale code (`fr`, `de`, `pt`), used in
+    /// reports written in that locale
+    pub translations: Option<HashMap<String, DimensionTranslation>>,
 }
 
 /// Dimension response
@@ -32,6 +48,11 @@ pub struct DimensionResponse {
     pub weight: Option<i32>,
     pub category: Option<String>,
     pub is_active: Option<bool>,
+    pub gap_medium_threshold: Option<i32>,
+    pub gap_high_threshold: Option<i32>,
+    pub translations: Option<HashMap<String, DimensionTranslation>>,
+    /// Framework version the dimension belongs to
+    pub framework_version_id: Uuid,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
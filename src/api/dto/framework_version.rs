@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+/// Start of a new draft framework version
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateFrameworkVersionRequest {
+    /// What the version changes
+    pub notes: Option<String>,
+}
+
+/// Framework version to read the framework of
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct FrameworkVersionQuery {
+    /// Defaults to the current published version
+    pub framework_version_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FrameworkVersionStatus {
+    Draft,
+    Published,
+}
+
+/// Framework version
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FrameworkVersionResponse {
+    pub framework_version_id: Uuid,
+    pub version_number: i32,
+    pub status: FrameworkVersionStatus,
+    /// Whether new assessments are started with this version
+    pub is_current: bool,
+    pub notes: Option<String>,
+    pub created_by: Option<String>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FrameworkVersionListResponse {
+    pub versions: Vec<FrameworkVersionResponse>,
+}
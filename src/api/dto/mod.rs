@@ -1,7 +1,9 @@
 pub mod action_plan;
+pub mod analytics;
 pub mod assessment;
 pub mod common;
 pub mod dimension;
+pub mod framework_version;
 pub mod gap;
 pub mod group;
 pub mod invitation;
@@ -10,3 +12,4 @@ pub mod organization;
 pub mod organization_dimension;
 pub mod recommendation;
 pub mod report;
+pub mod report_template;
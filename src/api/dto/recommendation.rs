@@ -1,5 +1,7 @@
 use crate::entities::recommendations::RecommendationPriority as EntityPriority;
+use crate::services::i18n::RecommendationTranslation;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -21,6 +23,10 @@ pub struct CreateRecommendationRequest {
     /// Detailed description of the recommendation
     #[schema(example = "Implement automated testing for critical components")]
     pub description: String,
+
+    /// Description per locale code (`fr`, `de`, `pt`), used in reports
+    /// written in that locale
+    pub translations: Option<HashMap<String, RecommendationTranslation>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -48,6 +54,9 @@ pub struct RecommendationResponse {
     #[schema(example = "Implement automated testing for critical components")]
     pub description: String,
 
+    /// Description per locale code
+    pub translations: Option<HashMap<String, RecommendationTranslation>>,
+
     /// When the recommendation was created
     #[schema(example = "2023-01-01T00:00:00Z")]
     pub created_at: chrono::DateTime<chrono::Utc>,
@@ -98,4 +107,7 @@ pub struct UpdateRecommendationRequest {
     /// New description (optional)
     #[schema(example = "Updated recommendation with more details")]
     pub description: Option<String>,
+
+    /// Replaces the translated descriptions (optional)
+    pub translations: Option<HashMap<String, RecommendationTranslation>>,
 }
@@ -1,9 +1,10 @@
 use crate::entities::reports::ReportFormat;
+use crate::services::i18n::Locale;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 /// Report generation request
@@ -13,9 +14,29 @@ pub struct GenerateReportRequest {
     pub report_type: ReportType,
     pub format: ReportFormat,
     pub title: String,
+    /// Defaults to `false` for summaries and `true` otherwise
     pub include_recommendations: Option<bool>,
+    /// Defaults to `false` for summaries and `true` otherwise
     pub include_action_plans: Option<bool>,
+    /// Only render these sections: `overview`, `chart`, `dimensions`,
+    /// `recommendations`, `action_plan`
     pub custom_sections: Option<Vec<String>>,
+    /// Language of the report: `en` (default), `fr`, `de` or `pt`
+    pub locale: Option<Locale>,
+}
+
+/// Portfolio report generation request
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GeneratePortfolioReportRequest {
+    /// Defaults to the caller's organization; required for `dgrv_admin`
+    pub organization_id: Option<String>,
+    /// Only aggregate these cooperations of the organization
+    pub cooperation_ids: Option<Vec<String>>,
+    /// `Pdf` or `Excel`
+    pub format: ReportFormat,
+    pub title: String,
+    /// Language of the report: `en` (default), `fr`, `de` or `pt`
+    pub locale: Option<Locale>,
 }
 
 /// Report update request
@@ -30,13 +51,19 @@ pub struct UpdateReportRequest {
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ReportResponse {
     pub report_id: Uuid,
-    pub assessment_id: Uuid,
+    /// Unset for portfolio reports
+    pub assessment_id: Option<Uuid>,
+    /// Organization aggregated by a portfolio report
+    pub organization_id: Option<String>,
     pub report_type: ReportType,
     pub title: String,
     pub format: ReportFormat,
     pub summary: Option<String>,
+    /// Data the file was rendered from, frozen at generation
     pub report_data: Option<serde_json::Value>,
     pub file_path: Option<String>,
+    /// Hex SHA-256 of the generated file
+    pub file_sha256: Option<String>,
     pub status: ReportStatus,
     pub generated_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
@@ -49,6 +76,7 @@ pub enum ReportType {
     Summary,
     Detailed,
     ActionPlan,
+    Portfolio,
 }
 
 impl fmt::Display for ReportType {
@@ -57,6 +85,7 @@ impl fmt::Display for ReportType {
             ReportType::Summary => write!(f, "summary"),
             ReportType::Detailed => write!(f, "detailed"),
             ReportType::ActionPlan => write!(f, "action_plan"),
+            ReportType::Portfolio => write!(f, "portfolio"),
         }
     }
 }
@@ -69,6 +98,7 @@ impl FromStr for ReportType {
             "summary" => Ok(ReportType::Summary),
             "detailed" => Ok(ReportType::Detailed),
             "action_plan" => Ok(ReportType::ActionPlan),
+            "portfolio" => Ok(ReportType::Portfolio),
             _ => Err(format!("Invalid report type: {s}")),
         }
     }
@@ -120,6 +150,40 @@ pub struct ReportDownloadResponse {
     pub content_type: Option<String>,
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct DownloadLinkQuery {
+    /// Lifetime of the link in seconds, capped by the server
+    pub expires_in_secs: Option<u64>,
+}
+
+/// Expiring link to a report file, usable without authentication
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReportDownloadLinkResponse {
+    pub report_id: Uuid,
+    pub url: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Query of a link signed by the API
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct SignedDownloadQuery {
+    /// Expiry time as a Unix timestamp
+    pub expires: i64,
+    pub signature: String,
+}
+
+/// Result of checking a file against the hash recorded at generation
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReportVerificationResponse {
+    pub report_id: Uuid,
+    /// Whether the file is byte for byte the one the platform issued
+    pub verified: bool,
+    /// Unset for reports generated before hashes were recorded
+    pub expected_sha256: Option<String>,
+    pub actual_sha256: String,
+    pub generated_at: DateTime<Utc>,
+}
+
 /// Report list response with pagination
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ReportListResponse {
@@ -137,5 +201,91 @@ pub struct ReportStatusResponse {
     pub status: ReportStatus,
     pub progress: Option<u8>,
     pub message: Option<String>,
+    /// Earliest start of the next attempt of a pending report
     pub estimated_completion: Option<DateTime<Utc>>,
+    /// Generation attempts made so far
+    pub attempts: i32,
+    /// Error of the last failed attempt
+    pub failure_reason: Option<String>,
+}
+
+/// Version of the [`JsonReport`] layout. Bumped on breaking changes so that
+/// consumers can tell report generations apart.
+pub const JSON_REPORT_SCHEMA_VERSION: &str = "1.0";
+
+/// Machine-readable assessment report, stored like the PDF and Excel files
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct JsonReport {
+    /// See [`JSON_REPORT_SCHEMA_VERSION`]
+    pub schema_version: String,
+    pub generated_at: DateTime<Utc>,
+    /// Sections the report was requested with: `dimensions`,
+    /// `recommendations` and `action_plan`. Left out sections are empty.
+    #[serde(default)]
+    pub sections: Vec<String>,
+    pub assessment: JsonReportAssessment,
+    /// Weighted scores, frozen at submission for submitted assessments
+    pub scores: crate::services::dimension_scoring::AssessmentScore,
+    pub dimensions: Vec<JsonReportDimension>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct JsonReportAssessment {
+    pub assessment_id: Uuid,
+    pub title: String,
+    pub organization_id: String,
+    pub cooperation_id: Option<String>,
+    /// `draft`, `in_progress`, `completed` or `archived`
+    pub status: String,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub previous_assessment_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct JsonReportDimension {
+    pub dimension_assessment_id: Uuid,
+    pub dimension_id: Uuid,
+    pub name: String,
+    pub category: Option<String>,
+    pub weight: Option<i32>,
+    pub current_state: JsonReportState,
+    pub desired_state: JsonReportState,
+    pub gap: JsonReportGap,
+    pub recommendations: Vec<JsonReportRecommendation>,
+    pub action_items: Vec<JsonReportActionItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct JsonReportState {
+    pub state_id: Uuid,
+    pub score: i32,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct JsonReportGap {
+    pub gap_id: Uuid,
+    /// `desired_state.score - current_state.score`
+    pub size: i32,
+    pub severity: crate::api::dto::gap::GapSeverity,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct JsonReportRecommendation {
+    pub recommendation_id: Uuid,
+    /// `LOW`, `MEDIUM` or `HIGH`
+    pub priority: String,
+    pub description: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct JsonReportActionItem {
+    pub action_item_id: Uuid,
+    pub recommendation_id: Uuid,
+    /// `todo`, `in_progress`, `done` or `approved`
+    pub status: String,
+    /// `low`, `medium` or `high`
+    pub priority: String,
 }
@@ -0,0 +1,47 @@
+use crate::api::dto::report::ReportType;
+use crate::services::report_templates::ReportBranding;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+/// Upload of a new report template version
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateReportTemplateRequest {
+    /// Organization the template applies to, or none for the default of
+    /// all organizations
+    pub organization_id: Option<String>,
+    pub report_type: ReportType,
+    /// Tera template. It may extend `report_base.html` and include
+    /// `partials/overview.html`, `partials/chart.html` and
+    /// `partials/action_plan.html`.
+    pub content: String,
+    /// Defaults to the DGRV branding
+    pub branding: Option<ReportBranding>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ReportTemplateQuery {
+    pub report_type: Option<ReportType>,
+}
+
+/// Report template version
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReportTemplateResponse {
+    pub template_id: Uuid,
+    pub organization_id: Option<String>,
+    pub report_type: ReportType,
+    pub version: i32,
+    pub content: String,
+    pub branding: ReportBranding,
+    /// Whether this version is used to render reports
+    pub is_active: bool,
+    pub created_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReportTemplateListResponse {
+    pub templates: Vec<ReportTemplateResponse>,
+}
@@ -15,8 +15,10 @@ use crate::api::dto::{
     common::{ApiResponse, PaginatedResponse, PaginationParams},
 };
 use crate::api::handlers::common::{extract_pagination, handle_error, success_response};
+use crate::auth::tenant::TenantContext;
 use crate::error::AppError;
 use crate::repositories::action_plans::ActionPlansRepository;
+use crate::repositories::dimension_assessments::DimensionAssessmentsRepository;
 use crate::services::action_plan_service::{ActionPlanService, UpdateActionItemParams};
 
 #[derive(Debug, serde::Deserialize, IntoParams)]
@@ -41,11 +43,13 @@ pub struct ActionItemId {
 )]
 pub async fn get_action_plan_by_assessment_id(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(assessment_id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<ActionPlanResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
     let action_plan = ActionPlansRepository::find_action_plan_with_items_by_assessment_id(
         db.as_ref(),
+        &tenant,
         assessment_id,
     )
     .await
@@ -77,6 +81,7 @@ pub async fn get_action_plan_by_assessment_id(
 )]
 pub async fn create_action_item(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(action_plan_id): Path<Uuid>,
     Json(body): Json<CreateActionItemRequest>,
 ) -> Result<Json<ApiResponse<ActionItemResponse>>, (StatusCode, Json<serde_json::Value>)> {
@@ -87,11 +92,24 @@ pub async fn create_action_item(
     let action_plan_service = ActionPlanService::new(db.clone());
 
     // Check if the action plan exists
-    ActionPlansRepository::find_by_id(db.as_ref(), action_plan_id)
+    ActionPlansRepository::find_by_id_for_tenant(db.as_ref(), &tenant, action_plan_id)
         .await
         .map_err(handle_error)?
         .ok_or_else(|| handle_error(AppError::NotFound("Action plan not found".to_string())))?;
 
+    DimensionAssessmentsRepository::find_by_id_for_tenant(
+        db.as_ref(),
+        &tenant,
+        body.dimension_assessment_id,
+    )
+    .await
+    .map_err(handle_error)?
+    .ok_or_else(|| {
+        handle_error(AppError::NotFound(
+            "Dimension assessment not found".to_string(),
+        ))
+    })?;
+
     let action_item = action_plan_service
         .create_action_item(
             action_plan_id,
@@ -136,6 +154,7 @@ pub async fn create_action_item(
 )]
 pub async fn update_action_item(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path((action_plan_id, action_item_id)): Path<(Uuid, Uuid)>,
     Json(body): Json<UpdateActionItemRequest>,
 ) -> Result<Json<ApiResponse<ActionItemResponse>>, (StatusCode, Json<serde_json::Value>)> {
@@ -146,7 +165,7 @@ pub async fn update_action_item(
     let action_plan_service = ActionPlanService::new(db.clone());
 
     // Check if the action plan exists
-    ActionPlansRepository::find_by_id(db.as_ref(), action_plan_id)
+    ActionPlansRepository::find_by_id_for_tenant(db.as_ref(), &tenant, action_plan_id)
         .await
         .map_err(handle_error)?
         .ok_or_else(|| handle_error(AppError::NotFound("Action plan not found".to_string())))?;
@@ -195,13 +214,14 @@ pub async fn update_action_item(
 )]
 pub async fn delete_action_item(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path((action_plan_id, action_item_id)): Path<(Uuid, Uuid)>,
 ) -> Result<Json<ApiResponse<()>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
     let action_plan_service = ActionPlanService::new(db.clone());
 
     // Check if the action plan exists
-    ActionPlansRepository::find_by_id(db.as_ref(), action_plan_id)
+    ActionPlansRepository::find_by_id_for_tenant(db.as_ref(), &tenant, action_plan_id)
         .await
         .map_err(handle_error)?
         .ok_or_else(|| handle_error(AppError::NotFound("Action plan not found".to_string())))?;
@@ -227,6 +247,7 @@ pub async fn delete_action_item(
 )]
 pub async fn list_action_plans(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Query(params): Query<PaginationParams>,
 ) -> Result<
     Json<ApiResponse<PaginatedResponse<ActionPlanResponse>>>,
@@ -235,7 +256,7 @@ pub async fn list_action_plans(
     let db = &state.db;
     let (page, limit, _sort_by, _sort_order) = extract_pagination(Query(params));
 
-    let action_plans = ActionPlansRepository::find_all(db.as_ref())
+    let action_plans = ActionPlansRepository::find_all(db.as_ref(), &tenant)
         .await
         .map_err(handle_error)?;
 
@@ -244,6 +265,7 @@ pub async fn list_action_plans(
         if let Some(plan_with_items) =
             ActionPlansRepository::find_action_plan_with_items_by_assessment_id(
                 db.as_ref(),
+                &tenant,
                 plan.assessment_id,
             )
             .await
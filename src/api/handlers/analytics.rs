@@ -0,0 +1,126 @@
+use crate::AppState;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+};
+
+use crate::api::dto::{
+    analytics::{BenchmarkQuery, BenchmarkResponse, MaturityTrendResponse},
+    common::ApiResponse,
+};
+use crate::api::handlers::common::success_response;
+use crate::auth::cooperation::CooperationScope;
+use crate::auth::tenant::TenantContext;
+use crate::repositories::assessments::AssessmentsRepository;
+use crate::services::benchmark::BenchmarkService;
+use crate::services::maturity_trend::MaturityTrendService;
+
+#[utoipa::path(
+    get,
+    path = "/analytics/organizations/{organization_id}/maturity-trend",
+    params(("organization_id" = String, Path, description = "Organization ID")),
+    responses(
+        (status = 200, description = "Maturity trend of the organization", body = ApiResponseMaturityTrendResponse),
+        (status = 403, description = "Organization belongs to another tenant")
+    )
+)]
+/// Maturity trend of an organization
+///
+/// Every completed assessment of the organization, including its cooperations,
+/// with per-dimension scores and the overall maturity index.
+pub async fn get_organization_maturity_trend(
+    State(state): State<AppState>,
+    tenant: TenantContext,
+    Path(organization_id): Path<String>,
+) -> Result<Json<ApiResponse<MaturityTrendResponse>>, (StatusCode, Json<serde_json::Value>)> {
+    let db = &state.db;
+    tenant
+        .ensure_organization(&organization_id)
+        .map_err(crate::api::handlers::common::handle_error)?;
+
+    let assessments = AssessmentsRepository::find_all_completed_by_organization_id(
+        db.as_ref(),
+        &tenant,
+        organization_id.clone(),
+    )
+    .await
+    .map_err(crate::api::handlers::common::handle_error)?;
+
+    let points = MaturityTrendService::build_points(db.as_ref(), assessments)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
+
+    Ok(success_response(MaturityTrendResponse {
+        organization_id: Some(organization_id),
+        cooperation_id: None,
+        maturity_index_change: MaturityTrendService::maturity_index_change(&points),
+        points,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/analytics/cooperations/{cooperation_id}/maturity-trend",
+    params(("cooperation_id" = String, Path, description = "Cooperation ID")),
+    responses(
+        (status = 200, description = "Maturity trend of the cooperation", body = ApiResponseMaturityTrendResponse),
+        (status = 403, description = "Cooperation is not accessible")
+    )
+)]
+/// Maturity trend of a cooperation
+pub async fn get_cooperation_maturity_trend(
+    State(state): State<AppState>,
+    tenant: TenantContext,
+    scope: CooperationScope,
+    Path(cooperation_id): Path<String>,
+) -> Result<Json<ApiResponse<MaturityTrendResponse>>, (StatusCode, Json<serde_json::Value>)> {
+    let db = &state.db;
+    scope
+        .ensure(Some(&cooperation_id))
+        .map_err(crate::api::handlers::common::handle_error)?;
+
+    let assessments = AssessmentsRepository::find_all_completed_by_cooperation_id(
+        db.as_ref(),
+        &tenant,
+        cooperation_id.clone(),
+    )
+    .await
+    .map_err(crate::api::handlers::common::handle_error)?;
+
+    let points = MaturityTrendService::build_points(db.as_ref(), assessments)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
+
+    Ok(success_response(MaturityTrendResponse {
+        organization_id: tenant.organization_id().map(str::to_string),
+        cooperation_id: Some(cooperation_id),
+        maturity_index_change: MaturityTrendService::maturity_index_change(&points),
+        points,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/analytics/benchmarks",
+    params(BenchmarkQuery),
+    responses(
+        (status = 200, description = "Anonymized peer distributions per dimension", body = ApiResponseBenchmarkResponse)
+    )
+)]
+/// Anonymized benchmark across organizations
+///
+/// Per dimension, the distribution of current and desired state scores and the
+/// share at each gap severity over the latest completed assessment of every
+/// cooperative. No organization is identified and dimensions answered by too
+/// few organizations are suppressed.
+pub async fn get_benchmarks(
+    State(state): State<AppState>,
+    Query(query): Query<BenchmarkQuery>,
+) -> Result<Json<ApiResponse<BenchmarkResponse>>, (StatusCode, Json<serde_json::Value>)> {
+    let benchmark = BenchmarkService::benchmark(state.db.as_ref(), query.category)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
+
+    Ok(success_response(benchmark))
+}
@@ -13,6 +13,9 @@ use crate::api::dto::{
 use crate::api::handlers::common::{
     extract_pagination, success_response, success_response_with_message,
 };
+use crate::auth::claims::Claims;
+use crate::auth::cooperation::CooperationScope;
+use crate::auth::tenant::TenantContext;
 use crate::error::AppError;
 use crate::repositories::{
     action_items::ActionItemsRepository, action_plans::ActionPlansRepository,
@@ -20,8 +23,12 @@ use crate::repositories::{
 };
 use crate::repositories::{
     assessments::AssessmentsRepository, dimension_assessments::DimensionAssessmentsRepository,
-    gaps::GapsRepository,
 };
+use crate::services::assessment_comparison::AssessmentComparisonService;
+use crate::services::assessment_lifecycle::AssessmentLifecycleService;
+use crate::services::dimension_scoring::DimensionScoringService;
+use crate::services::framework_versioning::FrameworkVersioningService;
+use crate::services::gap_severity::GapSeverityService;
 
 // Conversion functions between entity and DTO types
 fn convert_entity_assessment_status_to_dto(
@@ -46,28 +53,71 @@ fn convert_dto_assessment_status_to_entity(
     }
 }
 
+/// Dimension selection of an assessment as stored in `dimensions_id`, with
+/// every dimension mapped into the assessment's framework version
+async fn dimension_selection(
+    db: &sea_orm::DbConn,
+    dimensions_id: &[String],
+    framework_version_id: Uuid,
+) -> Result<serde_json::Value, AppError> {
+    let dimension_ids = dimensions_id
+        .iter()
+        .map(|id| {
+            Uuid::parse_str(id)
+                .map_err(|_| AppError::ValidationError(format!("Invalid dimension ID {}", id)))
+        })
+        .collect::<Result<Vec<Uuid>, AppError>>()?;
+    let dimension_ids = FrameworkVersioningService::require_dimensions_in_version(
+        db,
+        &dimension_ids,
+        framework_version_id,
+    )
+    .await?;
+    Ok(serde_json::json!(dimension_ids
+        .iter()
+        .map(Uuid::to_string)
+        .collect::<Vec<_>>()))
+}
+
 #[utoipa::path(
     post,
     path = "/assessments",
     request_body = CreateAssessmentRequest,
     responses(
-        (status = 200, description = "Assessment created", body = ApiResponseAssessmentResponse)
+        (status = 200, description = "Assessment created", body = ApiResponseAssessmentResponse),
+        (status = 400, description = "Dimensions are not part of the current framework version")
     )
 )]
 /// Create a new assessment
 pub async fn create_assessment(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Json(request): Json<CreateAssessmentRequest>,
 ) -> Result<Json<ApiResponse<AssessmentResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
+    tenant
+        .ensure_organization(&request.organization_id)
+        .map_err(crate::api::handlers::common::handle_error)?;
+    let framework_version = FrameworkVersioningService::current_version(db.as_ref())
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
+    let dimensions_id = dimension_selection(
+        db.as_ref(),
+        &request.dimensions_id,
+        framework_version.framework_version_id,
+    )
+    .await
+    .map_err(crate::api::handlers::common::handle_error)?;
+
     // Convert request to active model
     let active_model = crate::entities::assessments::ActiveModel {
         assessment_id: sea_orm::Set(Uuid::new_v4()),
         organization_id: sea_orm::Set(request.organization_id),
         document_title: sea_orm::Set(request.assessment_name),
-        dimensions_id: sea_orm::Set(Some(serde_json::json!(request.dimensions_id))),
+        dimensions_id: sea_orm::Set(Some(dimensions_id)),
         cooperation_id: sea_orm::Set(request.cooperation_id),
         status: sea_orm::Set(crate::entities::assessments::AssessmentStatus::Draft),
+        framework_version_id: sea_orm::Set(framework_version.framework_version_id),
         ..Default::default()
     };
 
@@ -86,6 +136,8 @@ pub async fn create_assessment(
         created_at: assessment.created_at,
         updated_at: assessment.updated_at,
         dimensions_id: assessment.dimensions_id,
+        previous_assessment_id: assessment.previous_assessment_id,
+        framework_version_id: assessment.framework_version_id,
     };
 
     Ok(success_response_with_message(
@@ -106,17 +158,19 @@ pub async fn create_assessment(
 /// Get assessment by ID
 pub async fn get_assessment(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(assessment_id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<AssessmentResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
-    let assessment = AssessmentsRepository::find_by_id(db.as_ref(), assessment_id)
-        .await
-        .map_err(crate::api::handlers::common::handle_error)?
-        .ok_or_else(|| {
-            crate::api::handlers::common::handle_error(AppError::NotFound(
-                "Assessment not found".to_string(),
-            ))
-        })?;
+    let assessment =
+        AssessmentsRepository::find_by_id_for_tenant(db.as_ref(), &tenant, assessment_id)
+            .await
+            .map_err(crate::api::handlers::common::handle_error)?
+            .ok_or_else(|| {
+                crate::api::handlers::common::handle_error(AppError::NotFound(
+                    "Assessment not found".to_string(),
+                ))
+            })?;
 
     let response = AssessmentResponse {
         assessment_id: assessment.assessment_id,
@@ -129,6 +183,8 @@ pub async fn get_assessment(
         created_at: assessment.created_at,
         updated_at: assessment.updated_at,
         dimensions_id: assessment.dimensions_id,
+        previous_assessment_id: assessment.previous_assessment_id,
+        framework_version_id: assessment.framework_version_id,
     };
 
     Ok(success_response(response))
@@ -146,18 +202,20 @@ pub async fn get_assessment(
 /// Get assessment summary with related data
 pub async fn get_assessment_summary(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(assessment_id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<AssessmentSummaryResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
     // Get assessment
-    let assessment = AssessmentsRepository::find_by_id(db.as_ref(), assessment_id)
-        .await
-        .map_err(crate::api::handlers::common::handle_error)?
-        .ok_or_else(|| {
-            crate::api::handlers::common::handle_error(AppError::NotFound(
-                "Assessment not found".to_string(),
-            ))
-        })?;
+    let assessment =
+        AssessmentsRepository::find_by_id_for_tenant(db.as_ref(), &tenant, assessment_id)
+            .await
+            .map_err(crate::api::handlers::common::handle_error)?
+            .ok_or_else(|| {
+                crate::api::handlers::common::handle_error(AppError::NotFound(
+                    "Assessment not found".to_string(),
+                ))
+            })?;
 
     // Get dimension assessments
     let dimension_assessments =
@@ -166,10 +224,13 @@ pub async fn get_assessment_summary(
             .map_err(crate::api::handlers::common::handle_error)?;
 
     // Get gaps count
-    let gaps =
-        crate::repositories::gaps::GapsRepository::find_by_assessment(db.as_ref(), assessment_id)
-            .await
-            .map_err(crate::api::handlers::common::handle_error)?;
+    let gaps = crate::repositories::gaps::GapsRepository::find_by_assessment(
+        db.as_ref(),
+        &tenant,
+        assessment_id,
+    )
+    .await
+    .map_err(crate::api::handlers::common::handle_error)?;
 
     // Get recommendations count - TODO: Implement when recommendations have assessment_id
     // let recommendations = RecommendationsRepository::find_by_assessment_id(&db, assessment_id)
@@ -177,6 +238,20 @@ pub async fn get_assessment_summary(
     //     .map_err(crate::api::handlers::common::handle_error)?;
     let recommendations: Vec<()> = vec![]; // Placeholder
 
+    // Submitted assessments keep the scores computed at submission
+    let scores = if assessment.scores.is_some() || !dimension_assessments.is_empty() {
+        Some(
+            DimensionScoringService::assessment_score(db.as_ref(), &assessment)
+                .await
+                .map_err(crate::api::handlers::common::handle_error)?,
+        )
+    } else {
+        None
+    };
+    let overall_score = assessment
+        .overall_score
+        .or_else(|| scores.as_ref().map(|s| s.maturity_index));
+
     let assessment_response = AssessmentResponse {
         assessment_id: assessment.assessment_id,
         organization_id: assessment.organization_id,
@@ -188,6 +263,8 @@ pub async fn get_assessment_summary(
         created_at: assessment.created_at,
         updated_at: assessment.updated_at,
         dimensions_id: assessment.dimensions_id,
+        previous_assessment_id: assessment.previous_assessment_id,
+        framework_version_id: assessment.framework_version_id,
     };
 
     let dimension_assessments_response: Vec<DimensionAssessmentResponse> = dimension_assessments
@@ -212,12 +289,153 @@ pub async fn get_assessment_summary(
         dimension_assessments: dimension_assessments_response,
         gaps_count: gaps.len() as u32,
         recommendations_count: recommendations.len() as u32,
-        overall_score: None,
+        overall_score,
+        scores,
     };
 
     Ok(success_response(summary))
 }
 
+#[utoipa::path(
+    post,
+    path = "/assessments/{id}/reassess",
+    params(("id" = Uuid, Path, description = "ID of the assessment to re-assess")),
+    request_body = ReassessRequest,
+    responses(
+        (status = 200, description = "Re-assessment created", body = ApiResponseAssessmentResponse),
+        (status = 404, description = "Assessment not found")
+    )
+)]
+/// Start a re-assessment
+///
+/// Creates a new draft with the same organization, cooperation and dimension
+/// set as the given assessment, linked to it as its predecessor. The draft is
+/// started with the current framework version; dimensions that version no
+/// longer has are left out.
+pub async fn reassess_assessment(
+    State(state): State<AppState>,
+    tenant: TenantContext,
+    Path(assessment_id): Path<Uuid>,
+    Json(request): Json<ReassessRequest>,
+) -> Result<Json<ApiResponse<AssessmentResponse>>, (StatusCode, Json<serde_json::Value>)> {
+    let db = &state.db;
+    let previous =
+        AssessmentsRepository::find_by_id_for_tenant(db.as_ref(), &tenant, assessment_id)
+            .await
+            .map_err(crate::api::handlers::common::handle_error)?
+            .ok_or_else(|| {
+                crate::api::handlers::common::handle_error(AppError::NotFound(
+                    "Assessment not found".to_string(),
+                ))
+            })?;
+
+    let framework_version = FrameworkVersioningService::current_version(db.as_ref())
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
+    let dimensions_id = match previous.dimensions_id {
+        Some(value) => {
+            let previous_dimensions: Vec<Uuid> = serde_json::from_value::<Vec<String>>(value)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|id| Uuid::parse_str(id).ok())
+                .collect();
+            let dimensions = FrameworkVersioningService::dimensions_in_version(
+                db.as_ref(),
+                &previous_dimensions,
+                framework_version.framework_version_id,
+            )
+            .await
+            .map_err(crate::api::handlers::common::handle_error)?;
+            Some(serde_json::json!(dimensions
+                .iter()
+                .map(Uuid::to_string)
+                .collect::<Vec<_>>()))
+        }
+        None => None,
+    };
+
+    let active_model = crate::entities::assessments::ActiveModel {
+        assessment_id: sea_orm::Set(Uuid::new_v4()),
+        organization_id: sea_orm::Set(previous.organization_id),
+        document_title: sea_orm::Set(request.assessment_name.unwrap_or(previous.document_title)),
+        dimensions_id: sea_orm::Set(dimensions_id),
+        cooperation_id: sea_orm::Set(previous.cooperation_id),
+        status: sea_orm::Set(crate::entities::assessments::AssessmentStatus::Draft),
+        previous_assessment_id: sea_orm::Set(Some(previous.assessment_id)),
+        framework_version_id: sea_orm::Set(framework_version.framework_version_id),
+        ..Default::default()
+    };
+
+    let assessment = AssessmentsRepository::create(db.as_ref(), active_model)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
+
+    let response = AssessmentResponse {
+        assessment_id: assessment.assessment_id,
+        organization_id: assessment.organization_id,
+        cooperation_id: assessment.cooperation_id,
+        document_title: assessment.document_title,
+        status: convert_entity_assessment_status_to_dto(assessment.status),
+        started_at: assessment.started_at,
+        completed_at: assessment.completed_at,
+        created_at: assessment.created_at,
+        updated_at: assessment.updated_at,
+        dimensions_id: assessment.dimensions_id,
+        previous_assessment_id: assessment.previous_assessment_id,
+        framework_version_id: assessment.framework_version_id,
+    };
+
+    Ok(success_response_with_message(
+        response,
+        "Re-assessment created successfully".to_string(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/assessments/{id}/compare/{other_id}",
+    params(
+        ("id" = Uuid, Path, description = "Base assessment ID"),
+        ("other_id" = Uuid, Path, description = "Assessment to compare against the base")
+    ),
+    responses(
+        (status = 200, description = "Per-dimension comparison", body = ApiResponseAssessmentComparisonResponse),
+        (status = 400, description = "Assessments belong to different organizations"),
+        (status = 404, description = "Assessment not found")
+    )
+)]
+/// Compare two assessments of the same organization
+pub async fn compare_assessments(
+    State(state): State<AppState>,
+    tenant: TenantContext,
+    Path((assessment_id, other_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<AssessmentComparisonResponse>>, (StatusCode, Json<serde_json::Value>)>
+{
+    let db = &state.db;
+    let mut assessments = Vec::with_capacity(2);
+    for id in [assessment_id, other_id] {
+        let assessment = AssessmentsRepository::find_by_id_for_tenant(db.as_ref(), &tenant, id)
+            .await
+            .map_err(crate::api::handlers::common::handle_error)?
+            .ok_or_else(|| {
+                crate::api::handlers::common::handle_error(AppError::NotFound(
+                    "Assessment not found".to_string(),
+                ))
+            })?;
+        assessments.push(assessment);
+    }
+
+    let comparison = AssessmentComparisonService::compare_assessments(
+        db.as_ref(),
+        &assessments[0],
+        &assessments[1],
+    )
+    .await
+    .map_err(crate::api::handlers::common::handle_error)?;
+
+    Ok(success_response(comparison))
+}
+
 #[utoipa::path(
     get,
     path = "/assessments",
@@ -232,6 +450,7 @@ pub async fn get_assessment_summary(
 /// List assessments with pagination
 pub async fn list_assessments(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Query(params): Query<PaginationParams>,
 ) -> Result<
     Json<ApiResponse<PaginatedResponse<AssessmentResponse>>>,
@@ -241,7 +460,7 @@ pub async fn list_assessments(
     let offset = ((page - 1) * limit) as u64;
 
     let db = &state.db;
-    let assessments = AssessmentsRepository::find_all(db.as_ref())
+    let assessments = AssessmentsRepository::find_all(db.as_ref(), &tenant)
         .await
         .map_err(crate::api::handlers::common::handle_error)?;
 
@@ -261,6 +480,8 @@ pub async fn list_assessments(
             created_at: assessment.created_at,
             updated_at: assessment.updated_at,
             dimensions_id: assessment.dimensions_id,
+            previous_assessment_id: assessment.previous_assessment_id,
+            framework_version_id: assessment.framework_version_id,
         })
         .collect();
 
@@ -275,42 +496,58 @@ pub async fn list_assessments(
     request_body = UpdateAssessmentRequest,
     responses(
         (status = 200, description = "Assessment updated", body = ApiResponseAssessmentResponse),
-        (status = 404, description = "Assessment not found")
+        (status = 400, description = "Dimensions are not part of the assessment's framework version"),
+        (status = 404, description = "Assessment not found"),
+        (status = 409, description = "Illegal status transition, completion outside of submission or dimension change of a closed assessment")
     )
 )]
 /// Update assessment
 pub async fn update_assessment(
     State(state): State<AppState>,
+    tenant: TenantContext,
+    claims: Claims,
     Path(assessment_id): Path<Uuid>,
     Json(request): Json<UpdateAssessmentRequest>,
 ) -> Result<Json<ApiResponse<AssessmentResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
-    let assessment = AssessmentsRepository::find_by_id(db.as_ref(), assessment_id)
-        .await
-        .map_err(crate::api::handlers::common::handle_error)?
-        .ok_or_else(|| {
-            crate::api::handlers::common::handle_error(AppError::NotFound(
-                "Assessment not found".to_string(),
-            ))
-        })?;
+    let assessment =
+        AssessmentsRepository::find_by_id_for_tenant(db.as_ref(), &tenant, assessment_id)
+            .await
+            .map_err(crate::api::handlers::common::handle_error)?
+            .ok_or_else(|| {
+                crate::api::handlers::common::handle_error(AppError::NotFound(
+                    "Assessment not found".to_string(),
+                ))
+            })?;
 
-    let mut active_model: crate::entities::assessments::ActiveModel = assessment.into();
+    let mut active_model: crate::entities::assessments::ActiveModel = assessment.clone().into();
 
     // Update fields if provided
     if let Some(assessment_name) = request.assessment_name {
         active_model.document_title = sea_orm::Set(assessment_name);
     }
     if let Some(dimensions_id) = request.dimensions_id {
-        active_model.dimensions_id = sea_orm::Set(Some(serde_json::json!(dimensions_id)));
+        // The selection of a completed assessment is part of its frozen scores
+        AssessmentLifecycleService::ensure_editable(&assessment)
+            .map_err(crate::api::handlers::common::handle_error)?;
+        let dimensions_id =
+            dimension_selection(db.as_ref(), &dimensions_id, assessment.framework_version_id)
+                .await
+                .map_err(crate::api::handlers::common::handle_error)?;
+        active_model.dimensions_id = sea_orm::Set(Some(dimensions_id));
     }
     if let Some(status) = request.status {
-        active_model.status = sea_orm::Set(convert_dto_assessment_status_to_entity(status));
-    }
-    if let Some(started_at) = request.started_at {
-        active_model.started_at = sea_orm::Set(Some(started_at));
-    }
-    if let Some(completed_at) = request.completed_at {
-        active_model.completed_at = sea_orm::Set(Some(completed_at));
+        let status = convert_dto_assessment_status_to_entity(status);
+        AssessmentLifecycleService::ensure_status_update(&assessment, &status)
+            .and_then(|_| {
+                AssessmentLifecycleService::apply_transition(
+                    &assessment,
+                    &mut active_model,
+                    status,
+                    claims.is_admin(),
+                )
+            })
+            .map_err(crate::api::handlers::common::handle_error)?;
     }
     let updated_assessment =
         AssessmentsRepository::update(db.as_ref(), assessment_id, active_model)
@@ -328,6 +565,8 @@ pub async fn update_assessment(
         created_at: updated_assessment.created_at,
         updated_at: updated_assessment.updated_at,
         dimensions_id: updated_assessment.dimensions_id,
+        previous_assessment_id: updated_assessment.previous_assessment_id,
+        framework_version_id: updated_assessment.framework_version_id,
     };
 
     Ok(success_response_with_message(
@@ -347,10 +586,11 @@ pub async fn update_assessment(
 /// Delete assessment
 pub async fn delete_assessment(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(assessment_id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<()>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
-    AssessmentsRepository::delete(db.as_ref(), assessment_id)
+    AssessmentsRepository::delete(db.as_ref(), &tenant, assessment_id)
         .await
         .map_err(crate::api::handlers::common::handle_error)?;
 
@@ -367,38 +607,68 @@ pub async fn delete_assessment(
     request_body = CreateDimensionAssessmentRequest,
     responses(
         (status = 200, description = "Dimension assessment created", body = ApiResponseDimensionAssessmentResponse),
-        (status = 404, description = "Assessment not found")
+        (status = 400, description = "Dimension is not part of the assessment's framework version"),
+        (status = 404, description = "Assessment not found"),
+        (status = 409, description = "Assessment is completed or archived")
     )
 )]
 /// Create dimension assessment
 pub async fn create_dimension_assessment(
     State(state): State<AppState>,
+    tenant: TenantContext,
+    scope: CooperationScope,
     Path(assessment_id): Path<Uuid>,
     Json(request): Json<CreateDimensionAssessmentRequest>,
 ) -> Result<Json<ApiResponse<DimensionAssessmentResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
+    let assessment =
+        AssessmentsRepository::find_by_id_for_tenant(db.as_ref(), &tenant, assessment_id)
+            .await
+            .map_err(crate::api::handlers::common::handle_error)?
+            .ok_or_else(|| {
+                crate::api::handlers::common::handle_error(AppError::NotFound(
+                    "Assessment not found".to_string(),
+                ))
+            })?;
+
+    // Coop members may only answer assessments assigned to their own cooperation
+    let cooperation_id = request
+        .cooperation_id
+        .clone()
+        .or_else(|| assessment.cooperation_id.clone());
+    scope
+        .ensure(cooperation_id.as_deref())
+        .and_then(|_| match assessment.cooperation_id.as_deref() {
+            Some(assigned) => scope.ensure(Some(assigned)),
+            None => Ok(()),
+        })
+        .map_err(crate::api::handlers::common::handle_error)?;
+    AssessmentLifecycleService::ensure_editable(&assessment)
+        .map_err(crate::api::handlers::common::handle_error)?;
+    let in_version = FrameworkVersioningService::dimensions_in_version(
+        db.as_ref(),
+        &[request.dimension_id],
+        assessment.framework_version_id,
+    )
+    .await
+    .map_err(crate::api::handlers::common::handle_error)?;
+    if in_version != [request.dimension_id] {
+        return Err(crate::api::handlers::common::handle_error(
+            AppError::ValidationError(
+                "Dimension does not belong to the assessment's framework version".to_string(),
+            ),
+        ));
+    }
+
     // 1. Create the Dimension Assessment
-    let gap = GapsRepository::find_by_dimension_and_severity(
+    let resolved_gap = GapSeverityService::resolve(
         db.as_ref(),
         request.dimension_id,
-        match request.gap_score {
-            1 => crate::entities::gaps::GapSeverity::Low,
-            2 => crate::entities::gaps::GapSeverity::Medium,
-            3 => crate::entities::gaps::GapSeverity::High,
-            _ => {
-                return Err(crate::api::handlers::common::handle_error(
-                    AppError::ValidationError("Invalid gap_score. Must be 1, 2, or 3.".to_string()),
-                ));
-            }
-        },
+        request.current_state_id,
+        request.desired_state_id,
     )
     .await
-    .map_err(crate::api::handlers::common::handle_error)?
-    .ok_or_else(|| {
-        crate::api::handlers::common::handle_error(AppError::NotFound(
-            "Corresponding gap not found for the given dimension and severity".to_string(),
-        ))
-    })?;
+    .map_err(crate::api::handlers::common::handle_error)?;
 
     let dimension_assessment_active_model = crate::entities::dimension_assessments::ActiveModel {
         dimension_assessment_id: sea_orm::Set(Uuid::new_v4()),
@@ -406,10 +676,10 @@ pub async fn create_dimension_assessment(
         dimension_id: sea_orm::Set(request.dimension_id),
         current_state_id: sea_orm::Set(request.current_state_id),
         desired_state_id: sea_orm::Set(request.desired_state_id),
-        gap_score: sea_orm::Set(request.gap_score),
-        gap_id: sea_orm::Set(gap.gap_id),
-        organization_id: sea_orm::Set(request.organization_id),
-        cooperation_id: sea_orm::Set(request.cooperation_id),
+        gap_score: sea_orm::Set(resolved_gap.gap_size),
+        gap_id: sea_orm::Set(resolved_gap.gap.gap_id),
+        organization_id: sea_orm::Set(assessment.organization_id.clone()),
+        cooperation_id: sea_orm::Set(cooperation_id),
         ..Default::default()
     };
 
@@ -418,6 +688,22 @@ pub async fn create_dimension_assessment(
             .await
             .map_err(crate::api::handlers::common::handle_error)?;
 
+    // The first answer starts the assessment
+    if assessment.status == crate::entities::assessments::AssessmentStatus::Draft {
+        let mut assessment_active_model: crate::entities::assessments::ActiveModel =
+            assessment.clone().into();
+        AssessmentLifecycleService::apply_transition(
+            &assessment,
+            &mut assessment_active_model,
+            crate::entities::assessments::AssessmentStatus::InProgress,
+            false,
+        )
+        .map_err(crate::api::handlers::common::handle_error)?;
+        AssessmentsRepository::update(db.as_ref(), assessment_id, assessment_active_model)
+            .await
+            .map_err(crate::api::handlers::common::handle_error)?;
+    }
+
     // 2. Find or create an Action Plan
     let action_plan =
         ActionPlansRepository::find_or_create(db.as_ref(), dimension_assessment.assessment_id)
@@ -425,11 +711,10 @@ pub async fn create_dimension_assessment(
             .map_err(crate::api::handlers::common::handle_error)?;
 
     // 3. Create an Action Item
-    let recommendation_priority = match request.gap_score {
-        1 => "Low",
-        2 => "Medium",
-        3 => "High",
-        _ => "Medium", // Should not happen
+    let recommendation_priority = match resolved_gap.severity {
+        crate::entities::gaps::GapSeverity::Low => "Low",
+        crate::entities::gaps::GapSeverity::Medium => "Medium",
+        crate::entities::gaps::GapSeverity::High => "High",
     };
     if let Some(recommendation) = RecommendationsRepository::find_by_dimension_and_priority(
         db.as_ref(),
@@ -485,13 +770,14 @@ pub async fn create_dimension_assessment(
 /// List all dimension assessments for an assessment
 pub async fn list_dimension_assessments(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(assessment_id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<DimensionAssessmentsResponse>>, (StatusCode, Json<serde_json::Value>)>
 {
     let db = &state.db;
 
     // Verify assessment exists
-    AssessmentsRepository::find_by_id(db.as_ref(), assessment_id)
+    AssessmentsRepository::find_by_id_for_tenant(db.as_ref(), &tenant, assessment_id)
         .await
         .map_err(crate::api::handlers::common::handle_error)?
         .ok_or_else(|| {
@@ -540,59 +826,73 @@ pub async fn list_dimension_assessments(
     request_body = UpdateDimensionAssessmentRequest,
     responses(
         (status = 200, description = "Dimension assessment updated", body = ApiResponseDimensionAssessmentResponse),
-        (status = 404, description = "Dimension assessment not found")
+        (status = 404, description = "Dimension assessment not found"),
+        (status = 409, description = "Assessment is completed or archived")
     )
 )]
 /// Update dimension assessment
 pub async fn update_dimension_assessment(
     State(state): State<AppState>,
-    Path((_assessment_id, dimension_assessment_id)): Path<(Uuid, Uuid)>,
+    tenant: TenantContext,
+    scope: CooperationScope,
+    Path((assessment_id, dimension_assessment_id)): Path<(Uuid, Uuid)>,
     Json(request): Json<UpdateDimensionAssessmentRequest>,
 ) -> Result<Json<ApiResponse<DimensionAssessmentResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
-    let dimension_assessment =
-        DimensionAssessmentsRepository::find_by_id(db.as_ref(), dimension_assessment_id)
+    let dimension_assessment = DimensionAssessmentsRepository::find_by_id_for_tenant(
+        db.as_ref(),
+        &tenant,
+        dimension_assessment_id,
+    )
+    .await
+    .map_err(crate::api::handlers::common::handle_error)?
+    .filter(|da| da.assessment_id == assessment_id)
+    .ok_or_else(|| {
+        crate::api::handlers::common::handle_error(AppError::NotFound(
+            "Dimension assessment not found".to_string(),
+        ))
+    })?;
+
+    scope
+        .ensure(dimension_assessment.cooperation_id.as_deref())
+        .map_err(crate::api::handlers::common::handle_error)?;
+
+    let assessment =
+        AssessmentsRepository::find_by_id_for_tenant(db.as_ref(), &tenant, assessment_id)
             .await
             .map_err(crate::api::handlers::common::handle_error)?
             .ok_or_else(|| {
                 crate::api::handlers::common::handle_error(AppError::NotFound(
-                    "Dimension assessment not found".to_string(),
+                    "Assessment not found".to_string(),
                 ))
             })?;
+    AssessmentLifecycleService::ensure_editable(&assessment)
+        .map_err(crate::api::handlers::common::handle_error)?;
 
     let mut active_model: crate::entities::dimension_assessments::ActiveModel =
         dimension_assessment.clone().into();
 
-    // If gap_score is being updated, also update the gap_id
-    if let Some(gap_score) = request.gap_score {
-        active_model.gap_score = sea_orm::Set(gap_score);
-
-        // Look up the corresponding gap by dimension and severity
-        let gap = GapsRepository::find_by_dimension_and_severity(
+    // If a state changes, recompute the gap and its severity
+    if request.current_state_id.is_some() || request.desired_state_id.is_some() {
+        let current_state_id = request
+            .current_state_id
+            .unwrap_or(dimension_assessment.current_state_id);
+        let desired_state_id = request
+            .desired_state_id
+            .unwrap_or(dimension_assessment.desired_state_id);
+        let resolved_gap = GapSeverityService::resolve(
             db.as_ref(),
             dimension_assessment.dimension_id,
-            match gap_score {
-                1 => crate::entities::gaps::GapSeverity::Low,
-                2 => crate::entities::gaps::GapSeverity::Medium,
-                3 => crate::entities::gaps::GapSeverity::High,
-                _ => {
-                    return Err(crate::api::handlers::common::handle_error(
-                        AppError::ValidationError(
-                            "Invalid gap_score. Must be 1, 2, or 3.".to_string(),
-                        ),
-                    ));
-                }
-            },
+            current_state_id,
+            desired_state_id,
         )
         .await
-        .map_err(crate::api::handlers::common::handle_error)?
-        .ok_or_else(|| {
-            crate::api::handlers::common::handle_error(AppError::NotFound(
-                "Corresponding gap not found for the given dimension and severity".to_string(),
-            ))
-        })?;
+        .map_err(crate::api::handlers::common::handle_error)?;
 
-        active_model.gap_id = sea_orm::Set(gap.gap_id);
+        active_model.current_state_id = sea_orm::Set(current_state_id);
+        active_model.desired_state_id = sea_orm::Set(desired_state_id);
+        active_model.gap_score = sea_orm::Set(resolved_gap.gap_size);
+        active_model.gap_id = sea_orm::Set(resolved_gap.gap.gap_id);
     }
 
     let updated_dimension_assessment =
@@ -631,13 +931,19 @@ pub async fn update_dimension_assessment(
 /// List assessments by organization
 pub async fn list_assessments_by_organization(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(organization_id): Path<String>,
 ) -> Result<Json<ApiResponse<AssessmentsResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
-    let assessments = AssessmentsRepository::find_by_organization_id(db.as_ref(), organization_id)
-        .await
+    tenant
+        .ensure_organization(&organization_id)
         .map_err(crate::api::handlers::common::handle_error)?;
 
+    let assessments =
+        AssessmentsRepository::find_by_organization_id(db.as_ref(), &tenant, organization_id)
+            .await
+            .map_err(crate::api::handlers::common::handle_error)?;
+
     let response = AssessmentsResponse {
         assessments: assessments
             .into_iter()
@@ -652,6 +958,8 @@ pub async fn list_assessments_by_organization(
                 created_at: assessment.created_at,
                 updated_at: assessment.updated_at,
                 dimensions_id: assessment.dimensions_id,
+                previous_assessment_id: assessment.previous_assessment_id,
+                framework_version_id: assessment.framework_version_id,
             })
             .collect(),
     };
@@ -670,13 +978,20 @@ pub async fn list_assessments_by_organization(
 /// List assessments by cooperation
 pub async fn list_assessments_by_cooperation(
     State(state): State<AppState>,
+    tenant: TenantContext,
+    scope: CooperationScope,
     Path(cooperation_id): Path<String>,
 ) -> Result<Json<ApiResponse<AssessmentsResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
-    let assessments = AssessmentsRepository::find_by_cooperation_id(db.as_ref(), cooperation_id)
-        .await
+    scope
+        .ensure(Some(&cooperation_id))
         .map_err(crate::api::handlers::common::handle_error)?;
 
+    let assessments =
+        AssessmentsRepository::find_by_cooperation_id(db.as_ref(), &tenant, cooperation_id)
+            .await
+            .map_err(crate::api::handlers::common::handle_error)?;
+
     let response = AssessmentsResponse {
         assessments: assessments
             .into_iter()
@@ -691,6 +1006,8 @@ pub async fn list_assessments_by_cooperation(
                 created_at: assessment.created_at,
                 updated_at: assessment.updated_at,
                 dimensions_id: assessment.dimensions_id,
+                previous_assessment_id: assessment.previous_assessment_id,
+                framework_version_id: assessment.framework_version_id,
             })
             .collect(),
     };
@@ -709,13 +1026,21 @@ pub async fn list_assessments_by_cooperation(
 /// List submissions by organization
 pub async fn list_submissions_by_organization(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(organization_id): Path<String>,
 ) -> Result<Json<ApiResponse<AssessmentsResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
-    let assessments =
-        AssessmentsRepository::find_all_completed_by_organization_id(db.as_ref(), organization_id)
-            .await
-            .map_err(crate::api::handlers::common::handle_error)?;
+    tenant
+        .ensure_organization(&organization_id)
+        .map_err(crate::api::handlers::common::handle_error)?;
+
+    let assessments = AssessmentsRepository::find_all_completed_by_organization_id(
+        db.as_ref(),
+        &tenant,
+        organization_id,
+    )
+    .await
+    .map_err(crate::api::handlers::common::handle_error)?;
 
     let response = AssessmentsResponse {
         assessments: assessments
@@ -731,6 +1056,8 @@ pub async fn list_submissions_by_organization(
                 created_at: assessment.created_at,
                 updated_at: assessment.updated_at,
                 dimensions_id: assessment.dimensions_id,
+                previous_assessment_id: assessment.previous_assessment_id,
+                framework_version_id: assessment.framework_version_id,
             })
             .collect(),
     };
@@ -749,13 +1076,22 @@ pub async fn list_submissions_by_organization(
 /// List submissions by cooperation
 pub async fn list_submissions_by_cooperation(
     State(state): State<AppState>,
+    tenant: TenantContext,
+    scope: CooperationScope,
     Path(cooperation_id): Path<String>,
 ) -> Result<Json<ApiResponse<AssessmentsResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
-    let assessments =
-        AssessmentsRepository::find_all_completed_by_cooperation_id(db.as_ref(), cooperation_id)
-            .await
-            .map_err(crate::api::handlers::common::handle_error)?;
+    scope
+        .ensure(Some(&cooperation_id))
+        .map_err(crate::api::handlers::common::handle_error)?;
+
+    let assessments = AssessmentsRepository::find_all_completed_by_cooperation_id(
+        db.as_ref(),
+        &tenant,
+        cooperation_id,
+    )
+    .await
+    .map_err(crate::api::handlers::common::handle_error)?;
 
     let response = AssessmentsResponse {
         assessments: assessments
@@ -771,6 +1107,8 @@ pub async fn list_submissions_by_cooperation(
                 created_at: assessment.created_at,
                 updated_at: assessment.updated_at,
                 dimensions_id: assessment.dimensions_id,
+                previous_assessment_id: assessment.previous_assessment_id,
+                framework_version_id: assessment.framework_version_id,
             })
             .collect(),
     };
@@ -793,9 +1131,14 @@ pub async fn list_submissions_by_cooperation(
 /// Delete an assessment for a specific organization
 pub async fn delete_organization_assessment(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path((organization_id, assessment_id)): Path<(String, Uuid)>,
 ) -> Result<Json<ApiResponse<()>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
+    tenant
+        .ensure_organization(&organization_id)
+        .map_err(crate::api::handlers::common::handle_error)?;
+
     let deleted = AssessmentsRepository::delete_by_organization_and_id(
         db.as_ref(),
         organization_id,
@@ -14,12 +14,22 @@ pub async fn health_check() -> Json<serde_json::Value> {
 
 /// Handle application errors and convert them to appropriate HTTP responses
 pub fn handle_error(error: AppError) -> (StatusCode, Json<serde_json::Value>) {
+    let mut details = None;
     let (status_code, message) = match error {
         AppError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
         AppError::ValidationError(msg) => (StatusCode::BAD_REQUEST, msg),
+        AppError::ValidationFailed {
+            message,
+            details: error_details,
+        } => {
+            details = Some(error_details);
+            (StatusCode::UNPROCESSABLE_ENTITY, message)
+        }
         AppError::DatabaseError(db_err) => (StatusCode::INTERNAL_SERVER_ERROR, db_err.to_string()),
         AppError::FileStorageError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
         AppError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg),
+        AppError::Forbidden(msg) => (StatusCode::FORBIDDEN, msg),
+        AppError::Conflict(msg) => (StatusCode::CONFLICT, msg),
         AppError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
         AppError::InternalServerError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
         AppError::AuthError(msg) => (StatusCode::UNAUTHORIZED, msg),
@@ -27,7 +37,11 @@ pub fn handle_error(error: AppError) -> (StatusCode, Json<serde_json::Value>) {
     };
 
     let response = ApiResponse::<()>::error(message);
-    (status_code, Json(serde_json::to_value(response).unwrap()))
+    let mut body = serde_json::to_value(response).unwrap();
+    if let Some(details) = details {
+        body["details"] = details;
+    }
+    (status_code, Json(body))
 }
 
 /// Extract pagination parameters from query string
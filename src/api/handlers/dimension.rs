@@ -10,6 +10,7 @@ use uuid::Uuid;
 use crate::api::dto::{
     common::{ApiResponse, PaginatedResponse, PaginationParams},
     dimension::*,
+    framework_version::FrameworkVersionQuery,
 };
 use crate::api::handlers::common::{
     extract_pagination, success_response, success_response_with_message,
@@ -19,14 +20,18 @@ use crate::repositories::{
     current_states::CurrentStatesRepository, desired_states::DesiredStatesRepository,
     dimensions::DimensionsRepository,
 };
+use crate::services::framework_versioning::FrameworkVersioningService;
+use crate::services::gap_severity::GapThresholds;
+use crate::services::i18n::{translations_from_json, translations_to_json};
 
-/// Create a new dimension
+/// Create a new dimension in the draft framework version
 #[utoipa::path(
     post,
     path = "/dimensions",
     request_body = CreateDimensionRequest,
     responses(
-        (status = 200, description = "Dimension created", body = ApiResponseDimensionResponse)
+        (status = 200, description = "Dimension created", body = ApiResponseDimensionResponse),
+        (status = 409, description = "No draft framework version exists")
     )
 )]
 pub async fn create_dimension(
@@ -34,13 +39,36 @@ pub async fn create_dimension(
     Json(request): Json<CreateDimensionRequest>,
 ) -> Result<Json<ApiResponse<DimensionResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
+    let draft = FrameworkVersioningService::draft_version(db.as_ref())
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
+    if request.gap_medium_threshold.is_some() || request.gap_high_threshold.is_some() {
+        let defaults = GapThresholds::default();
+        GapThresholds::new(
+            request.gap_medium_threshold.unwrap_or(defaults.medium),
+            request.gap_high_threshold.unwrap_or(defaults.high),
+        )
+        .map_err(crate::api::handlers::common::handle_error)?;
+    }
+    let translations = request
+        .translations
+        .as_ref()
+        .map(translations_to_json)
+        .transpose()
+        .map_err(crate::api::handlers::common::handle_error)?;
+    let dimension_id = Uuid::new_v4();
     let active_model = crate::entities::dimensions::ActiveModel {
-        dimension_id: sea_orm::Set(Uuid::new_v4()),
+        dimension_id: sea_orm::Set(dimension_id),
         name: sea_orm::Set(request.name),
         description: sea_orm::Set(request.description),
         weight: sea_orm::Set(Some(request.weight.unwrap_or(1))),
         category: sea_orm::Set(request.category),
         is_active: sea_orm::Set(Some(request.is_active.unwrap_or(true))),
+        gap_medium_threshold: sea_orm::Set(request.gap_medium_threshold),
+        gap_high_threshold: sea_orm::Set(request.gap_high_threshold),
+        translations: sea_orm::Set(translations),
+        framework_version_id: sea_orm::Set(draft.framework_version_id),
+        origin_dimension_id: sea_orm::Set(dimension_id),
         ..Default::default()
     };
 
@@ -55,6 +83,10 @@ pub async fn create_dimension(
         weight: dimension.weight,
         category: dimension.category,
         is_active: dimension.is_active,
+        gap_medium_threshold: dimension.gap_medium_threshold,
+        gap_high_threshold: dimension.gap_high_threshold,
+        translations: translations_from_json(dimension.translations),
+        framework_version_id: dimension.framework_version_id,
         created_at: DateTime::from_naive_utc_and_offset(dimension.created_at, Utc),
         updated_at: DateTime::from_naive_utc_and_offset(dimension.updated_at, Utc),
     };
@@ -96,6 +128,10 @@ pub async fn get_dimension(
         weight: dimension.weight,
         category: dimension.category,
         is_active: dimension.is_active,
+        gap_medium_threshold: dimension.gap_medium_threshold,
+        gap_high_threshold: dimension.gap_high_threshold,
+        translations: translations_from_json(dimension.translations),
+        framework_version_id: dimension.framework_version_id,
         created_at: DateTime::from_naive_utc_and_offset(dimension.created_at, Utc),
         updated_at: DateTime::from_naive_utc_and_offset(dimension.updated_at, Utc),
     };
@@ -145,6 +181,10 @@ pub async fn get_dimension_with_states(
         weight: dimension.weight,
         category: dimension.category,
         is_active: dimension.is_active,
+        gap_medium_threshold: dimension.gap_medium_threshold,
+        gap_high_threshold: dimension.gap_high_threshold,
+        translations: translations_from_json(dimension.translations),
+        framework_version_id: dimension.framework_version_id,
         created_at: DateTime::from_naive_utc_and_offset(dimension.created_at, Utc),
         updated_at: DateTime::from_naive_utc_and_offset(dimension.updated_at, Utc),
     };
@@ -182,13 +222,14 @@ pub async fn get_dimension_with_states(
     Ok(success_response(response))
 }
 
-/// List dimensions with pagination
+/// List the dimensions of a framework version with pagination
 #[utoipa::path(
     get,
     path = "/dimensions",
     params(
         ("page" = Option<u32>, Query, description = "Page number (default 1)"),
-        ("limit" = Option<u32>, Query, description = "Page size (default 20)")
+        ("limit" = Option<u32>, Query, description = "Page size (default 20)"),
+        ("framework_version_id" = Option<Uuid>, Query, description = "Framework version (default the current one)")
     ),
     responses(
         (status = 200, description = "Dimensions list", body = ApiResponsePaginatedDimensionResponse)
@@ -197,6 +238,7 @@ pub async fn get_dimension_with_states(
 pub async fn list_dimensions(
     State(state): State<AppState>,
     Query(params): Query<PaginationParams>,
+    Query(version): Query<FrameworkVersionQuery>,
 ) -> Result<
     Json<ApiResponse<PaginatedResponse<DimensionResponse>>>,
     (StatusCode, Json<serde_json::Value>),
@@ -205,9 +247,14 @@ pub async fn list_dimensions(
     let offset = ((page - 1) * limit) as u64;
 
     let db = &state.db;
-    let dimensions = DimensionsRepository::find_all(db.as_ref())
-        .await
-        .map_err(crate::api::handlers::common::handle_error)?;
+    let version =
+        FrameworkVersioningService::resolve_version(db.as_ref(), version.framework_version_id)
+            .await
+            .map_err(crate::api::handlers::common::handle_error)?;
+    let dimensions =
+        DimensionsRepository::find_by_framework_version(db.as_ref(), version.framework_version_id)
+            .await
+            .map_err(crate::api::handlers::common::handle_error)?;
 
     let total = dimensions.len() as u64;
     let paginated_dimensions: Vec<DimensionResponse> = dimensions
@@ -221,6 +268,10 @@ pub async fn list_dimensions(
             weight: dimension.weight,
             category: dimension.category,
             is_active: dimension.is_active,
+            gap_medium_threshold: dimension.gap_medium_threshold,
+            gap_high_threshold: dimension.gap_high_threshold,
+            translations: translations_from_json(dimension.translations),
+            framework_version_id: dimension.framework_version_id,
             created_at: DateTime::from_naive_utc_and_offset(dimension.created_at, Utc),
             updated_at: DateTime::from_naive_utc_and_offset(dimension.updated_at, Utc),
         })
@@ -238,7 +289,8 @@ pub async fn list_dimensions(
     request_body = UpdateDimensionRequest,
     responses(
         (status = 200, description = "Dimension updated", body = ApiResponseDimensionResponse),
-        (status = 404, description = "Dimension not found")
+        (status = 404, description = "Dimension not found"),
+        (status = 409, description = "Dimension belongs to a published framework version")
     )
 )]
 pub async fn update_dimension(
@@ -247,14 +299,9 @@ pub async fn update_dimension(
     Json(request): Json<UpdateDimensionRequest>,
 ) -> Result<Json<ApiResponse<DimensionResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
-    let _existing = DimensionsRepository::find_by_id(db.as_ref(), dimension_id)
+    let existing = FrameworkVersioningService::editable_dimension(db.as_ref(), dimension_id)
         .await
-        .map_err(crate::api::handlers::common::handle_error)?
-        .ok_or_else(|| {
-            crate::api::handlers::common::handle_error(AppError::NotFound(
-                "Dimension not found".to_string(),
-            ))
-        })?;
+        .map_err(crate::api::handlers::common::handle_error)?;
     // Build a partial ActiveModel with only provided fields set
     let mut active_model = crate::entities::dimensions::ActiveModel {
         dimension_id: sea_orm::Set(dimension_id),
@@ -275,6 +322,21 @@ pub async fn update_dimension(
     if let Some(is_active) = request.is_active {
         active_model.is_active = sea_orm::Set(Some(is_active));
     }
+    if request.gap_medium_threshold.is_some() || request.gap_high_threshold.is_some() {
+        let current = GapThresholds::for_dimension(&existing);
+        let thresholds = GapThresholds::new(
+            request.gap_medium_threshold.unwrap_or(current.medium),
+            request.gap_high_threshold.unwrap_or(current.high),
+        )
+        .map_err(crate::api::handlers::common::handle_error)?;
+        active_model.gap_medium_threshold = sea_orm::Set(Some(thresholds.medium));
+        active_model.gap_high_threshold = sea_orm::Set(Some(thresholds.high));
+    }
+    if let Some(translations) = &request.translations {
+        let translations = translations_to_json(translations)
+            .map_err(crate::api::handlers::common::handle_error)?;
+        active_model.translations = sea_orm::Set(Some(translations));
+    }
 
     let updated_dimension = DimensionsRepository::update(db.as_ref(), dimension_id, active_model)
         .await
@@ -287,6 +349,10 @@ pub async fn update_dimension(
         weight: updated_dimension.weight,
         category: updated_dimension.category,
         is_active: updated_dimension.is_active,
+        gap_medium_threshold: updated_dimension.gap_medium_threshold,
+        gap_high_threshold: updated_dimension.gap_high_threshold,
+        translations: translations_from_json(updated_dimension.translations),
+        framework_version_id: updated_dimension.framework_version_id,
         created_at: DateTime::from_naive_utc_and_offset(updated_dimension.created_at, Utc),
         updated_at: DateTime::from_naive_utc_and_offset(updated_dimension.updated_at, Utc),
     };
@@ -303,7 +369,8 @@ pub async fn update_dimension(
     path = "/dimensions/{id}",
     params(("id" = Uuid, Path, description = "Dimension ID")),
     responses(
-        (status = 200, description = "Dimension deleted")
+        (status = 200, description = "Dimension deleted"),
+        (status = 409, description = "Dimension belongs to a published framework version")
     )
 )]
 pub async fn delete_dimension(
@@ -311,6 +378,9 @@ pub async fn delete_dimension(
     Path(dimension_id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<()>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
+    FrameworkVersioningService::editable_dimension(db.as_ref(), dimension_id)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
     DimensionsRepository::delete(db.as_ref(), dimension_id)
         .await
         .map_err(crate::api::handlers::common::handle_error)?;
@@ -329,7 +399,8 @@ pub async fn delete_dimension(
     request_body = CreateCurrentStateRequest,
     responses(
         (status = 200, description = "Current state created", body = ApiResponseCurrentStateResponse),
-        (status = 404, description = "Dimension not found")
+        (status = 404, description = "Dimension not found"),
+        (status = 409, description = "Dimension belongs to a published framework version")
     )
 )]
 pub async fn create_current_state(
@@ -338,15 +409,9 @@ pub async fn create_current_state(
     Json(request): Json<CreateCurrentStateRequest>,
 ) -> Result<Json<ApiResponse<CurrentStateResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
-    // Verify dimension exists
-    DimensionsRepository::find_by_id(db.as_ref(), dimension_id)
+    FrameworkVersioningService::editable_dimension(db.as_ref(), dimension_id)
         .await
-        .map_err(crate::api::handlers::common::handle_error)?
-        .ok_or_else(|| {
-            crate::api::handlers::common::handle_error(AppError::NotFound(
-                "Dimension not found".to_string(),
-            ))
-        })?;
+        .map_err(crate::api::handlers::common::handle_error)?;
 
     let active_model = crate::entities::current_states::ActiveModel {
         current_state_id: sea_orm::Set(Uuid::new_v4()),
@@ -386,7 +451,8 @@ pub async fn create_current_state(
     request_body = UpdateCurrentStateRequest,
     responses(
         (status = 200, description = "Current state updated", body = ApiResponseCurrentStateResponse),
-        (status = 404, description = "Current state not found")
+        (status = 404, description = "Current state not found"),
+        (status = 409, description = "Dimension belongs to a published framework version")
     )
 )]
 pub async fn update_current_state(
@@ -412,6 +478,9 @@ pub async fn update_current_state(
             ),
         ));
     }
+    FrameworkVersioningService::editable_dimension(db.as_ref(), dimension_id)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
 
     // Update fields if provided
     if let Some(description) = request.description {
@@ -452,7 +521,8 @@ pub async fn update_current_state(
     request_body = CreateDesiredStateRequest,
     responses(
         (status = 200, description = "Desired state created", body = ApiResponseDesiredStateResponse),
-        (status = 404, description = "Dimension not found")
+        (status = 404, description = "Dimension not found"),
+        (status = 409, description = "Dimension belongs to a published framework version")
     )
 )]
 pub async fn create_desired_state(
@@ -461,15 +531,9 @@ pub async fn create_desired_state(
     Json(request): Json<CreateDesiredStateRequest>,
 ) -> Result<Json<ApiResponse<DesiredStateResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
-    // Verify dimension exists
-    DimensionsRepository::find_by_id(db.as_ref(), dimension_id)
+    FrameworkVersioningService::editable_dimension(db.as_ref(), dimension_id)
         .await
-        .map_err(crate::api::handlers::common::handle_error)?
-        .ok_or_else(|| {
-            crate::api::handlers::common::handle_error(AppError::NotFound(
-                "Dimension not found".to_string(),
-            ))
-        })?;
+        .map_err(crate::api::handlers::common::handle_error)?;
 
     let active_model = crate::entities::desired_states::ActiveModel {
         desired_state_id: sea_orm::Set(Uuid::new_v4()),
@@ -509,7 +573,8 @@ pub async fn create_desired_state(
     request_body = UpdateDesiredStateRequest,
     responses(
         (status = 200, description = "Desired state updated", body = ApiResponseDesiredStateResponse),
-        (status = 404, description = "Desired state not found")
+        (status = 404, description = "Desired state not found"),
+        (status = 409, description = "Dimension belongs to a published framework version")
     )
 )]
 pub async fn update_desired_state(
@@ -535,6 +600,9 @@ pub async fn update_desired_state(
             ),
         ));
     }
+    FrameworkVersioningService::editable_dimension(db.as_ref(), dimension_id)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
 
     // Update fields if provided
     if let Some(description) = request.description {
@@ -576,7 +644,8 @@ pub async fn update_desired_state(
         ("current_state_id" = Uuid, Path, description = "Current state ID")
     ),
     responses(
-        (status = 200, description = "Current state deleted")
+        (status = 200, description = "Current state deleted"),
+        (status = 409, description = "Dimension belongs to a published framework version")
     )
 )]
 pub async fn delete_current_state(
@@ -601,6 +670,9 @@ pub async fn delete_current_state(
             ),
         ));
     }
+    FrameworkVersioningService::editable_dimension(db.as_ref(), dimension_id)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
 
     CurrentStatesRepository::delete(db.as_ref(), current_state_id)
         .await
@@ -621,7 +693,8 @@ pub async fn delete_current_state(
         ("desired_state_id" = Uuid, Path, description = "Desired state ID")
     ),
     responses(
-        (status = 200, description = "Desired state deleted")
+        (status = 200, description = "Desired state deleted"),
+        (status = 409, description = "Dimension belongs to a published framework version")
     )
 )]
 pub async fn delete_desired_state(
@@ -646,6 +719,9 @@ pub async fn delete_desired_state(
             ),
         ));
     }
+    FrameworkVersioningService::editable_dimension(db.as_ref(), dimension_id)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
 
     DesiredStatesRepository::delete(db.as_ref(), desired_state_id)
         .await
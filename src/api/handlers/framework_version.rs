@@ -0,0 +1,190 @@
+use crate::AppState;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+};
+use uuid::Uuid;
+
+use crate::api::dto::{
+    common::{ApiResponse, EmptyResponse},
+    framework_version::{
+        CreateFrameworkVersionRequest, FrameworkVersionListResponse, FrameworkVersionResponse,
+        FrameworkVersionStatus,
+    },
+};
+use crate::api::handlers::common::{handle_error, success_response, success_response_with_message};
+use crate::auth::claims::Claims;
+use crate::entities::framework_versions;
+use crate::error::AppError;
+use crate::repositories::framework_versions::FrameworkVersionsRepository;
+use crate::services::framework_versioning::FrameworkVersioningService;
+
+fn to_response(
+    version: framework_versions::Model,
+    current: Option<&framework_versions::Model>,
+) -> FrameworkVersionResponse {
+    FrameworkVersionResponse {
+        framework_version_id: version.framework_version_id,
+        version_number: version.version_number,
+        status: if version.is_draft() {
+            FrameworkVersionStatus::Draft
+        } else {
+            FrameworkVersionStatus::Published
+        },
+        is_current: current
+            .is_some_and(|current| current.framework_version_id == version.framework_version_id),
+        notes: version.notes,
+        created_by: version.created_by,
+        published_at: version.published_at,
+        created_at: version.created_at,
+        updated_at: version.updated_at,
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/framework-versions",
+    tag = "Admin",
+    request_body = CreateFrameworkVersionRequest,
+    responses(
+        (status = 200, description = "Draft created from the current version", body = ApiResponseFrameworkVersionResponse),
+        (status = 409, description = "A draft already exists")
+    )
+)]
+/// Create a draft framework version
+///
+/// Copies the dimensions, states, gaps and recommendations of the current
+/// version into a new draft. Only the draft can be edited.
+pub async fn create_framework_version(
+    State(state): State<AppState>,
+    claims: Claims,
+    Json(request): Json<CreateFrameworkVersionRequest>,
+) -> Result<Json<ApiResponse<FrameworkVersionResponse>>, (StatusCode, Json<serde_json::Value>)> {
+    let draft = FrameworkVersioningService::create_draft(
+        state.db.as_ref(),
+        request.notes,
+        Some(claims.subject),
+    )
+    .await
+    .map_err(handle_error)?;
+
+    Ok(success_response_with_message(
+        to_response(draft, None),
+        "Draft framework version created".to_string(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/framework-versions",
+    tag = "Admin",
+    responses(
+        (status = 200, description = "Framework versions, newest first", body = ApiResponseFrameworkVersionListResponse)
+    )
+)]
+/// List framework versions
+pub async fn list_framework_versions(
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<FrameworkVersionListResponse>>, (StatusCode, Json<serde_json::Value>)>
+{
+    let db = state.db.as_ref();
+    let current = FrameworkVersionsRepository::find_current(db)
+        .await
+        .map_err(handle_error)?;
+    let versions = FrameworkVersionsRepository::find_all(db)
+        .await
+        .map_err(handle_error)?
+        .into_iter()
+        .map(|version| to_response(version, current.as_ref()))
+        .collect();
+
+    Ok(success_response(FrameworkVersionListResponse { versions }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/framework-versions/{id}",
+    tag = "Admin",
+    params(("id" = Uuid, Path, description = "Framework version ID")),
+    responses(
+        (status = 200, description = "Framework version", body = ApiResponseFrameworkVersionResponse),
+        (status = 404, description = "Framework version not found")
+    )
+)]
+/// Get a framework version
+pub async fn get_framework_version(
+    State(state): State<AppState>,
+    Path(framework_version_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<FrameworkVersionResponse>>, (StatusCode, Json<serde_json::Value>)> {
+    let db = state.db.as_ref();
+    let version = FrameworkVersionsRepository::find_by_id(db, framework_version_id)
+        .await
+        .map_err(handle_error)?
+        .ok_or_else(|| {
+            handle_error(AppError::NotFound(
+                "Framework version not found".to_string(),
+            ))
+        })?;
+    let current = FrameworkVersionsRepository::find_current(db)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(success_response(to_response(version, current.as_ref())))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/framework-versions/{id}/publish",
+    tag = "Admin",
+    params(("id" = Uuid, Path, description = "Framework version ID")),
+    responses(
+        (status = 200, description = "Version published and made current", body = ApiResponseFrameworkVersionResponse),
+        (status = 400, description = "Version has no dimensions"),
+        (status = 404, description = "Framework version not found"),
+        (status = 409, description = "Version is already published")
+    )
+)]
+/// Publish a draft framework version
+///
+/// New assessments are started with the published version. Assessments
+/// started earlier stay on the version they were started with.
+pub async fn publish_framework_version(
+    State(state): State<AppState>,
+    Path(framework_version_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<FrameworkVersionResponse>>, (StatusCode, Json<serde_json::Value>)> {
+    let version = FrameworkVersioningService::publish(state.db.as_ref(), framework_version_id)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(success_response_with_message(
+        to_response(version.clone(), Some(&version)),
+        "Framework version published".to_string(),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/framework-versions/{id}",
+    tag = "Admin",
+    params(("id" = Uuid, Path, description = "Framework version ID")),
+    responses(
+        (status = 200, description = "Draft discarded", body = ApiResponseEmpty),
+        (status = 404, description = "Framework version not found"),
+        (status = 409, description = "Version is published")
+    )
+)]
+/// Discard a draft framework version
+pub async fn discard_framework_version(
+    State(state): State<AppState>,
+    Path(framework_version_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<EmptyResponse>>, (StatusCode, Json<serde_json::Value>)> {
+    FrameworkVersioningService::discard_draft(state.db.as_ref(), framework_version_id)
+        .await
+        .map_err(handle_error)?;
+
+    Ok(success_response_with_message(
+        EmptyResponse {},
+        "Draft framework version discarded".to_string(),
+    ))
+}
@@ -9,14 +9,18 @@ use uuid::Uuid;
 
 use crate::api::dto::{
     common::{ApiResponse, EmptyResponse, PaginatedResponse, PaginationParams},
+    framework_version::FrameworkVersionQuery,
     gap::{AdminCreateGapRequest, GapResponse, GapSeverity, UpdateGapRequest},
 };
 use crate::api::handlers::common::{
     extract_pagination, success_response, success_response_with_message,
 };
+use crate::auth::tenant::TenantContext;
 use crate::entities::gaps;
 use crate::error::AppError;
 use crate::repositories::gaps::GapsRepository;
+use crate::services::framework_versioning::FrameworkVersioningService;
+use crate::services::gap_severity::GapThresholds;
 
 fn to_gap_response(model: gaps::Model) -> GapResponse {
     GapResponse {
@@ -30,6 +34,12 @@ fn to_gap_response(model: gaps::Model) -> GapResponse {
         updated_at: model.updated_at,
     }
 }
+
+async fn find_gap(db: &sea_orm::DbConn, gap_id: Uuid) -> Result<gaps::Model, AppError> {
+    GapsRepository::find_by_id(db, gap_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Gap not found".to_string()))
+}
 /// Create a new gap (admin)
 ///
 /// Creates a new gap with a specified severity and description.
@@ -55,7 +65,8 @@ fn to_gap_response(model: gaps::Model) -> GapResponse {
         (status = 200, description = "Gap created", body = ApiResponseGapResponse),
         (status = 400, description = "Invalid input data"),
         (status = 401, description = "Unauthorized"),
-        (status = 404, description = "Dimension assessment not found")
+        (status = 404, description = "Dimension assessment not found"),
+        (status = 409, description = "Dimension belongs to a published framework version")
     ),
     security(("jwt" = []))
 )]
@@ -67,6 +78,9 @@ pub async fn admin_create_gap(
     let dimension_id = request.dimension_id;
     let severity = request.gap_severity.into();
     let description = request.gap_description;
+    FrameworkVersioningService::editable_dimension(db.as_ref(), dimension_id)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
 
     let active = gaps::ActiveModel {
         gap_id: Set(Uuid::new_v4()),
@@ -112,14 +126,9 @@ pub async fn get_gap(
     Path(gap_id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<GapResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
-    let gap = GapsRepository::find_by_id(db.as_ref(), gap_id)
+    let gap = find_gap(db.as_ref(), gap_id)
         .await
-        .map_err(crate::api::handlers::common::handle_error)?
-        .ok_or_else(|| {
-            crate::api::handlers::common::handle_error(AppError::NotFound(
-                "Gap not found".to_string(),
-            ))
-        })?;
+        .map_err(crate::api::handlers::common::handle_error)?;
 
     Ok(success_response(to_gap_response(gap)))
 }
@@ -136,7 +145,8 @@ pub async fn get_gap(
     responses(
         (status = 200, description = "Gap updated successfully", body = ApiResponseGapResponse),
         (status = 401, description = "Unauthorized"),
-        (status = 404, description = "Gap not found")
+        (status = 404, description = "Gap not found"),
+        (status = 409, description = "Gap belongs to a published framework version")
     ),
     security(("jwt" = []))
 )]
@@ -146,6 +156,12 @@ pub async fn update_gap(
     Json(req): Json<UpdateGapRequest>,
 ) -> Result<Json<ApiResponse<GapResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
+    let gap = find_gap(db.as_ref(), gap_id)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
+    let dimension = FrameworkVersioningService::editable_dimension(db.as_ref(), gap.dimension_id)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
     // Build partial ActiveModel
     let mut active = gaps::ActiveModel {
         gap_id: Set(gap_id),
@@ -153,13 +169,10 @@ pub async fn update_gap(
     };
     if let Some(size) = req.gap_size {
         active.gap_size = Set(size);
-        // If gap_size is updated, recalculate severity unless a specific one is provided
+        // If gap_size is updated, recalculate severity with the dimension's
+        // thresholds unless a specific one is provided
         if req.gap_severity.is_none() {
-            let new_severity = match size.abs() {
-                0..=1 => crate::entities::gaps::GapSeverity::Low,
-                2..=3 => crate::entities::gaps::GapSeverity::Medium,
-                _ => crate::entities::gaps::GapSeverity::High,
-            };
+            let new_severity = GapThresholds::for_dimension(&dimension).severity(size.abs());
             active.gap_severity = Set(new_severity);
         }
     }
@@ -189,7 +202,8 @@ pub async fn update_gap(
     responses(
         (status = 200, description = "Gap deleted successfully", body = ApiResponseEmpty),
         (status = 401, description = "Unauthorized"),
-        (status = 404, description = "Gap not found")
+        (status = 404, description = "Gap not found"),
+        (status = 409, description = "Gap belongs to a published framework version")
     ),
     security(("jwt" = []))
 )]
@@ -198,6 +212,12 @@ pub async fn delete_gap(
     Path(gap_id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<EmptyResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
+    let gap = find_gap(db.as_ref(), gap_id)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
+    FrameworkVersioningService::editable_dimension(db.as_ref(), gap.dimension_id)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
     let deleted = GapsRepository::delete(db.as_ref(), gap_id)
         .await
         .map_err(crate::api::handlers::common::handle_error)?;
@@ -210,14 +230,15 @@ pub async fn delete_gap(
 }
 /// List all gaps with pagination
 ///
-/// Retrieves a paginated list of all gaps in the system.
+/// Retrieves a paginated list of the gaps of a framework version.
 #[utoipa::path(
     get,
     path = "/gaps",
     tag = "Gaps",
     params(
         ("page" = Option<i64>, Query, description = "Page number (1-based)"),
-        ("limit" = Option<i64>, Query, description = "Number of items per page")
+        ("limit" = Option<i64>, Query, description = "Number of items per page"),
+        ("framework_version_id" = Option<Uuid>, Query, description = "Framework version (default the current one)")
     ),
     responses(
         (status = 200, description = "Gaps retrieved successfully", body = ApiResponsePaginatedGapResponse),
@@ -230,13 +251,18 @@ pub async fn delete_gap(
 pub async fn list_gaps(
     State(state): State<AppState>,
     Query(params): Query<PaginationParams>,
+    Query(version): Query<FrameworkVersionQuery>,
 ) -> Result<Json<ApiResponse<PaginatedResponse<GapResponse>>>, (StatusCode, Json<serde_json::Value>)>
 {
     let (page, limit, _sort_by, _sort_order) = extract_pagination(Query(params));
     let offset = ((page - 1) * limit) as u64;
 
     let db = &state.db;
-    let gaps = GapsRepository::find_all(db.as_ref())
+    let version =
+        FrameworkVersioningService::resolve_version(db.as_ref(), version.framework_version_id)
+            .await
+            .map_err(crate::api::handlers::common::handle_error)?;
+    let gaps = GapsRepository::find_by_framework_version(db.as_ref(), version.framework_version_id)
         .await
         .map_err(crate::api::handlers::common::handle_error)?;
 
@@ -276,6 +302,7 @@ pub async fn list_gaps(
 )]
 pub async fn list_gaps_by_dimension_assessment(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(dimension_assessment_id): Path<Uuid>,
     Query(params): Query<PaginationParams>,
 ) -> Result<Json<ApiResponse<PaginatedResponse<GapResponse>>>, (StatusCode, Json<serde_json::Value>)>
@@ -284,9 +311,10 @@ pub async fn list_gaps_by_dimension_assessment(
     let (page, limit, _sort_by, _sort_order) = extract_pagination(Query(params));
     let offset = ((page - 1) * limit) as u64;
 
-    let gaps = GapsRepository::find_by_dimension_assessment(db.as_ref(), dimension_assessment_id)
-        .await
-        .map_err(crate::api::handlers::common::handle_error)?;
+    let gaps =
+        GapsRepository::find_by_dimension_assessment(db.as_ref(), &tenant, dimension_assessment_id)
+            .await
+            .map_err(crate::api::handlers::common::handle_error)?;
 
     let total = gaps.len() as u64;
     let items: Vec<GapResponse> = gaps
@@ -324,6 +352,7 @@ pub async fn list_gaps_by_dimension_assessment(
 )]
 pub async fn list_gaps_by_assessment(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(assessment_id): Path<Uuid>,
     Query(params): Query<PaginationParams>,
 ) -> Result<Json<ApiResponse<PaginatedResponse<GapResponse>>>, (StatusCode, Json<serde_json::Value>)>
@@ -332,7 +361,7 @@ pub async fn list_gaps_by_assessment(
     let (page, limit, _sort_by, _sort_order) = extract_pagination(Query(params));
     let offset = ((page - 1) * limit) as u64;
 
-    let gaps = GapsRepository::find_by_assessment(db.as_ref(), assessment_id)
+    let gaps = GapsRepository::find_by_assessment(db.as_ref(), &tenant, assessment_id)
         .await
         .map_err(crate::api::handlers::common::handle_error)?;
 
@@ -1,5 +1,8 @@
 use crate::{
-    api::dto::group::{GetGroupByPathParams, GroupCreateRequest, GroupUpdateRequest},
+    api::dto::group::{
+        GetGroupByPathParams, GroupCreateRequest, GroupUpdateRequest, KeycloakGroup,
+    },
+    auth::tenant::TenantContext,
     error::{AppError, AppResult},
     AppState,
 };
@@ -10,6 +13,25 @@ use axum::{
     Json,
 };
 
+/// Load a group, rejecting groups of other organizations
+pub(crate) async fn find_organization_group(
+    state: &AppState,
+    token: &str,
+    tenant: &TenantContext,
+    group_id: &str,
+) -> AppResult<KeycloakGroup> {
+    let group = state
+        .keycloak_service
+        .get_group_by_id(token, group_id)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get group: {}", e);
+            AppError::InternalServerError("Failed to get group".to_string())
+        })?;
+    tenant.ensure_group(&group.path)?;
+    Ok(group)
+}
+
 // Create a new group for an organization
 #[utoipa::path(
     post,
@@ -22,9 +44,11 @@ use axum::{
 pub async fn create_group(
     State(state): State<AppState>,
     axum::Extension(token): axum::Extension<String>,
+    tenant: TenantContext,
     Path(org_id): Path<String>,
     Json(request): Json<GroupCreateRequest>,
 ) -> AppResult<impl IntoResponse> {
+    tenant.ensure_organization(&org_id)?;
     let keycloak_service = state.keycloak_service;
     let group_name = format!("{}-{}", org_id, request.name);
     tracing::info!(
@@ -58,8 +82,10 @@ pub async fn create_group(
 pub async fn get_groups_by_organization(
     State(state): State<AppState>,
     axum::Extension(token): axum::Extension<String>,
+    tenant: TenantContext,
     Path(org_id): Path<String>,
 ) -> AppResult<impl IntoResponse> {
+    tenant.ensure_organization(&org_id)?;
     let keycloak_service = state.keycloak_service;
     tracing::info!("Received get groups request for organization {}", org_id);
     let search_prefix = format!("{}-", org_id);
@@ -68,7 +94,16 @@ pub async fn get_groups_by_organization(
         .get_groups(&token, Some(&search_prefix))
         .await
     {
-        Ok(groups) => Ok((StatusCode::OK, Json(groups))),
+        // The search matches anywhere in the name, so other organizations'
+        // groups may be among the results
+        Ok(groups) => {
+            let organization = TenantContext::for_organization(org_id);
+            let groups: Vec<KeycloakGroup> = groups
+                .into_iter()
+                .filter(|group| organization.owns_group(&group.path))
+                .collect();
+            Ok((StatusCode::OK, Json(groups)))
+        }
         Err(e) => {
             tracing::error!("Failed to get groups: {}", e);
             Err(AppError::InternalServerError(
@@ -89,20 +124,13 @@ pub async fn get_groups_by_organization(
 pub async fn get_group(
     State(state): State<AppState>,
     axum::Extension(token): axum::Extension<String>,
+    tenant: TenantContext,
     Path(group_id): Path<String>,
 ) -> AppResult<impl IntoResponse> {
-    let keycloak_service = state.keycloak_service;
     tracing::info!("Received get group request for id {}", group_id);
 
-    match keycloak_service.get_group_by_id(&token, &group_id).await {
-        Ok(group) => Ok((StatusCode::OK, Json(group))),
-        Err(e) => {
-            tracing::error!("Failed to get group: {}", e);
-            Err(AppError::InternalServerError(
-                "Failed to get group".to_string(),
-            ))
-        }
-    }
+    let group = find_organization_group(&state, &token, &tenant, &group_id).await?;
+    Ok((StatusCode::OK, Json(group)))
 }
 
 // Get a specific group by path
@@ -118,8 +146,10 @@ pub async fn get_group(
 pub async fn get_group_by_path(
     State(state): State<AppState>,
     axum::Extension(token): axum::Extension<String>,
+    tenant: TenantContext,
     Query(params): Query<GetGroupByPathParams>,
 ) -> AppResult<impl IntoResponse> {
+    tenant.ensure_group(&params.path)?;
     let keycloak_service = state.keycloak_service;
     tracing::info!("Received get group request for path {}", params.path);
 
@@ -150,9 +180,11 @@ pub async fn get_group_by_path(
 pub async fn update_group(
     State(state): State<AppState>,
     axum::Extension(token): axum::Extension<String>,
+    tenant: TenantContext,
     Path(group_id): Path<String>,
     Json(request): Json<GroupUpdateRequest>,
 ) -> AppResult<impl IntoResponse> {
+    find_organization_group(&state, &token, &tenant, &group_id).await?;
     let keycloak_service = state.keycloak_service;
     tracing::info!(
         ?request,
@@ -185,8 +217,10 @@ pub async fn update_group(
 pub async fn delete_group(
     State(state): State<AppState>,
     axum::Extension(token): axum::Extension<String>,
+    tenant: TenantContext,
     Path(group_id): Path<String>,
 ) -> AppResult<impl IntoResponse> {
+    find_organization_group(&state, &token, &tenant, &group_id).await?;
     let keycloak_service = state.keycloak_service;
     tracing::info!("Received delete group request for id {}", group_id);
 
@@ -7,6 +7,7 @@ use axum::{
 
 use crate::api::dto::invitation::{UserInvitationRequest, UserInvitationResponse};
 use crate::auth::claims::Claims;
+use crate::auth::permissions::Permission;
 use crate::error::AppError;
 use crate::models::keycloak::CreateUserRequest;
 use crate::AppState;
@@ -39,9 +40,7 @@ pub async fn invite_user_to_organization(
     );
     let token = get_token_from_extensions(&token)?;
 
-    if !claims.is_application_admin() {
-        return Err(AppError::BadRequest("Insufficient permissions".to_string()));
-    }
+    claims.require_permission(Permission::ManageOrganizations)?;
 
     // Check if user already exists
     let existing_user = app_state
@@ -1,12 +1,15 @@
 pub mod action_plan;
+pub mod analytics;
 pub mod assessment;
 pub mod common;
 pub mod dimension;
+pub mod framework_version;
 pub mod gap;
 pub mod invitation;
 pub mod organization;
 pub mod recommendation;
 pub mod report;
+pub mod report_template;
 pub mod submission;
 pub mod user;
 
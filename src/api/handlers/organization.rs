@@ -14,8 +14,10 @@ use crate::{
             UpdateOrganisationDimensionsRequest,
         },
     },
+    auth::tenant::TenantContext,
     error::{AppError, AppResult},
     repositories::organisation_dimension::OrganisationDimensionRepository,
+    services::framework_versioning::FrameworkVersioningService,
 };
 use uuid::Uuid;
 
@@ -71,12 +73,16 @@ pub async fn create_organization(
 pub async fn get_organizations(
     State(state): State<AppState>,
     axum::Extension(token): axum::Extension<String>,
+    tenant: TenantContext,
 ) -> AppResult<impl IntoResponse> {
     let keycloak_service = state.keycloak_service;
     tracing::info!("Received get organizations request");
 
     match keycloak_service.get_organizations(&token).await {
-        Ok(organizations) => Ok((StatusCode::OK, Json(organizations))),
+        Ok(mut organizations) => {
+            organizations.retain(|organization| tenant.can_access_organization(&organization.id));
+            Ok((StatusCode::OK, Json(organizations)))
+        }
         Err(e) => {
             tracing::error!("Failed to get organizations: {}", e);
             Err(AppError::InternalServerError(
@@ -98,8 +104,10 @@ pub async fn get_organizations(
 pub async fn get_organization(
     State(state): State<AppState>,
     axum::Extension(token): axum::Extension<String>,
+    tenant: TenantContext,
     Path(org_id): Path<String>,
 ) -> AppResult<impl IntoResponse> {
+    tenant.ensure_organization(&org_id)?;
     let keycloak_service = state.keycloak_service;
     tracing::info!("Received get organization request for id {}", org_id);
 
@@ -196,8 +204,10 @@ pub async fn delete_organization(
 pub async fn get_organization_members(
     Extension(token): Extension<String>,
     State(app_state): State<AppState>,
+    tenant: TenantContext,
     Path(org_id): Path<String>,
 ) -> AppResult<impl IntoResponse> {
+    tenant.ensure_organization(&org_id)?;
     let members = app_state
         .keycloak_service
         .get_organization_members(&token, &org_id)
@@ -206,6 +216,20 @@ pub async fn get_organization_members(
     Ok((StatusCode::OK, Json(members)))
 }
 
+/// Assignments follow the current framework version: dimensions of older
+/// versions are mapped to their current copies, drafts and dimensions without
+/// a copy are rejected.
+async fn current_dimension_ids(state: &AppState, dimension_ids: &[Uuid]) -> AppResult<Vec<Uuid>> {
+    let db = state.db.as_ref();
+    let current = FrameworkVersioningService::current_version(db).await?;
+    FrameworkVersioningService::require_dimensions_in_version(
+        db,
+        dimension_ids,
+        current.framework_version_id,
+    )
+    .await
+}
+
 /// Assign a dimension to an organization
 #[utoipa::path(
     post,
@@ -213,15 +237,21 @@ pub async fn get_organization_members(
     tag = "Organization",
     params(("org_id" = String, Path, description = "Organization ID")),
     request_body = AssignDimensionRequest,
-    responses((status = 201, description = "Created"))
+    responses(
+        (status = 201, description = "Created"),
+        (status = 400, description = "Dimensions are not part of the current framework version")
+    )
 )]
 pub async fn assign_dimension_to_organization(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(org_id): Path<String>,
     Json(request): Json<AssignDimensionRequest>,
 ) -> AppResult<impl IntoResponse> {
+    tenant.ensure_organization(&org_id)?;
+    let dimension_ids = current_dimension_ids(&state, &request.dimension_ids).await?;
     let assigned_dimensions =
-        OrganisationDimensionRepository::assign(&state.db, &org_id, request.dimension_ids).await?;
+        OrganisationDimensionRepository::assign(&state.db, &org_id, dimension_ids).await?;
     let response: Vec<OrganisationDimensionResponse> =
         assigned_dimensions.into_iter().map(Into::into).collect();
     Ok((StatusCode::CREATED, Json(response)))
@@ -237,8 +267,10 @@ pub async fn assign_dimension_to_organization(
 )]
 pub async fn get_organization_dimensions(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(org_id): Path<String>,
 ) -> AppResult<impl IntoResponse> {
+    tenant.ensure_organization(&org_id)?;
     let dimensions =
         OrganisationDimensionRepository::list_by_organisation(&state.db, &org_id).await?;
     let response: Vec<Uuid> = dimensions.into_iter().map(|d| d.dimension_id).collect();
@@ -258,8 +290,10 @@ pub async fn get_organization_dimensions(
 )]
 pub async fn remove_dimension_from_organization(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path((org_id, dimension_id)): Path<(String, Uuid)>,
 ) -> AppResult<impl IntoResponse> {
+    tenant.ensure_organization(&org_id)?;
     OrganisationDimensionRepository::remove(&state.db, &org_id, dimension_id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
@@ -271,14 +305,19 @@ pub async fn remove_dimension_from_organization(
     tag = "Organization",
     params(("org_id" = String, Path, description = "Organization ID")),
     request_body = UpdateOrganisationDimensionsRequest,
-    responses((status = 204, description = "No Content"))
+    responses(
+        (status = 204, description = "No Content"),
+        (status = 400, description = "Dimensions are not part of the current framework version")
+    )
 )]
 pub async fn update_organization_dimensions(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(org_id): Path<String>,
     Json(request): Json<UpdateOrganisationDimensionsRequest>,
 ) -> AppResult<impl IntoResponse> {
-    OrganisationDimensionRepository::update_assignments(&state.db, &org_id, request.dimension_ids)
-        .await?;
+    tenant.ensure_organization(&org_id)?;
+    let dimension_ids = current_dimension_ids(&state, &request.dimension_ids).await?;
+    OrganisationDimensionRepository::update_assignments(&state.db, &org_id, dimension_ids).await?;
     Ok(StatusCode::NO_CONTENT)
 }
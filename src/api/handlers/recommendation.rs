@@ -9,13 +9,16 @@ use uuid::Uuid;
 
 use crate::api::dto::{
     common::{ApiResponse, EmptyResponse, PaginatedResponse, PaginationParams},
+    framework_version::FrameworkVersionQuery,
     recommendation::{
         CreateRecommendationRequest, RecommendationResponse, UpdateRecommendationRequest,
     },
 };
-use crate::api::handlers::common::{extract_pagination, success_response};
+use crate::api::handlers::common::{extract_pagination, handle_error, success_response};
 use crate::entities::recommendations;
 use crate::repositories::recommendations::RecommendationsRepository;
+use crate::services::framework_versioning::FrameworkVersioningService;
+use crate::services::i18n::{translations_from_json, translations_to_json};
 
 fn to_recommendation_response(model: recommendations::Model) -> RecommendationResponse {
     RecommendationResponse {
@@ -23,6 +26,7 @@ fn to_recommendation_response(model: recommendations::Model) -> RecommendationRe
         dimension_id: model.dimension_id,
         priority: model.priority.into(),
         description: model.description,
+        translations: translations_from_json(model.translations),
         created_at: model.created_at,
         updated_at: model.updated_at,
     }
@@ -38,6 +42,7 @@ fn to_recommendation_response(model: recommendations::Model) -> RecommendationRe
     responses(
         (status = 201, description = "Recommendation created successfully", body = ApiResponseRecommendationResponse),
         (status = 400, description = "Invalid input data"),
+        (status = 409, description = "Dimension belongs to a published framework version"),
         (status = 500, description = "Internal server error")
     ),
     tag = "recommendations"
@@ -47,11 +52,21 @@ pub async fn create_recommendation(
     Json(payload): Json<CreateRecommendationRequest>,
 ) -> Result<Json<ApiResponse<RecommendationResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
+    FrameworkVersioningService::editable_dimension(db, payload.dimension_id)
+        .await
+        .map_err(handle_error)?;
+    let translations = payload
+        .translations
+        .as_ref()
+        .map(translations_to_json)
+        .transpose()
+        .map_err(handle_error)?;
     let recommendation = recommendations::ActiveModel {
         recommendation_id: Set(Uuid::new_v4()),
         dimension_id: Set(payload.dimension_id),
         priority: Set(payload.priority.into()),
         description: Set(payload.description),
+        translations: Set(translations),
         created_at: Set(chrono::Utc::now()),
         updated_at: Set(chrono::Utc::now()),
     };
@@ -132,6 +147,7 @@ pub async fn get_recommendation(
         (status = 200, description = "Recommendation updated successfully", body = ApiResponseRecommendationResponse),
         (status = 400, description = "Invalid input data"),
         (status = 404, description = "Recommendation not found"),
+        (status = 409, description = "Recommendation belongs to a published framework version"),
         (status = 500, description = "Internal server error")
     ),
     tag = "recommendations"
@@ -159,6 +175,9 @@ pub async fn update_recommendation(
             });
             (StatusCode::NOT_FOUND, Json(error_response))
         })?;
+    FrameworkVersioningService::editable_dimension(db, existing.dimension_id)
+        .await
+        .map_err(handle_error)?;
 
     // Create an active model from the existing recommendation
     let mut recommendation: recommendations::ActiveModel = existing.into();
@@ -170,6 +189,10 @@ pub async fn update_recommendation(
     if let Some(description) = payload.description {
         recommendation.description = Set(description);
     }
+    if let Some(translations) = &payload.translations {
+        let translations = translations_to_json(translations).map_err(handle_error)?;
+        recommendation.translations = Set(Some(translations));
+    }
 
     // Update the updated_at timestamp
     recommendation.updated_at = Set(chrono::Utc::now());
@@ -203,6 +226,7 @@ pub async fn update_recommendation(
     responses(
         (status = 200, description = "Recommendation deleted successfully", body = ApiResponseEmpty),
         (status = 404, description = "Recommendation not found"),
+        (status = 409, description = "Recommendation belongs to a published framework version"),
         (status = 500, description = "Internal server error")
     ),
     tag = "recommendations"
@@ -212,6 +236,14 @@ pub async fn delete_recommendation(
     Path(recommendation_id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<EmptyResponse>>, (StatusCode, Json<serde_json::Value>)> {
     let db = &state.db;
+    if let Some(existing) = RecommendationsRepository::find_by_id(db, recommendation_id)
+        .await
+        .map_err(handle_error)?
+    {
+        FrameworkVersioningService::editable_dimension(db, existing.dimension_id)
+            .await
+            .map_err(handle_error)?;
+    }
     let deleted = RecommendationsRepository::delete(db, recommendation_id)
         .await
         .map_err(|e| {
@@ -237,13 +269,14 @@ pub async fn delete_recommendation(
 
 /// List all recommendations with pagination
 ///
-/// Retrieves a paginated list of all recommendations in the system.
+/// Retrieves a paginated list of the recommendations of a framework version.
 #[utoipa::path(
     get,
     path = "/recommendations",
     params(
         ("page" = Option<i64>, Query, description = "Page number (starts from 1)"),
-        ("page_size" = Option<i64>, Query, description = "Number of items per page (default: 10, max: 100)")
+        ("page_size" = Option<i64>, Query, description = "Number of items per page (default: 10, max: 100)"),
+        ("framework_version_id" = Option<Uuid>, Query, description = "Framework version (default the current one)")
     ),
     responses(
         (status = 200, description = "List of recommendations", body = ApiResponsePaginatedRecommendationResponse),
@@ -254,23 +287,31 @@ pub async fn delete_recommendation(
 pub async fn list_recommendations(
     State(state): State<AppState>,
     Query(params): Query<PaginationParams>,
+    Query(version): Query<FrameworkVersionQuery>,
 ) -> Result<
     Json<ApiResponse<PaginatedResponse<RecommendationResponse>>>,
     (StatusCode, Json<serde_json::Value>),
 > {
     let db = &state.db;
     let (page, limit, _sort_by, _sort_order) = extract_pagination(Query(params));
+    let version = FrameworkVersioningService::resolve_version(db, version.framework_version_id)
+        .await
+        .map_err(handle_error)?;
 
-    let (recommendations, total) =
-        RecommendationsRepository::find_all_paginated(db, page as u64, limit as u64)
-            .await
-            .map_err(|e| {
-                let error_response = serde_json::json!({
-                    "status": "error",
-                    "message": format!("Failed to fetch recommendations: {}", e)
-                });
-                (StatusCode::INTERNAL_SERVER_ERROR, Json(error_response))
-            })?;
+    let (recommendations, total) = RecommendationsRepository::find_all_paginated(
+        db,
+        version.framework_version_id,
+        page as u64,
+        limit as u64,
+    )
+    .await
+    .map_err(|e| {
+        let error_response = serde_json::json!({
+            "status": "error",
+            "message": format!("Failed to fetch recommendations: {}", e)
+        });
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(error_response))
+    })?;
 
     let response = success_response(PaginatedResponse {
         items: recommendations
@@ -15,17 +15,23 @@ use crate::api::dto::{
 use crate::api::handlers::common::{
     extract_pagination, success_response, success_response_with_message,
 };
+use crate::auth::tenant::TenantContext;
 use crate::error::AppError;
+use crate::repositories::assessments::AssessmentsRepository;
 use crate::repositories::reports::ReportsRepository;
+use crate::services::pdf_generator::ReportOptions;
+use crate::services::portfolio_report::PortfolioOptions;
+use crate::services::report_service::{content_disposition, download_file_name, sha256_hex};
 
 // Conversion functions for report enums
-fn convert_entity_report_type_to_dto(
+pub(crate) fn convert_entity_report_type_to_dto(
     entity_type: crate::entities::reports::ReportType,
 ) -> ReportType {
     match entity_type {
         crate::entities::reports::ReportType::Summary => ReportType::Summary,
         crate::entities::reports::ReportType::Detailed => ReportType::Detailed,
         crate::entities::reports::ReportType::ActionPlan => ReportType::ActionPlan,
+        crate::entities::reports::ReportType::Portfolio => ReportType::Portfolio,
     }
 }
 
@@ -50,11 +56,14 @@ fn convert_entity_report_status_to_dto(
     }
 }
 
-fn convert_dto_report_type_to_entity(dto_type: ReportType) -> crate::entities::reports::ReportType {
+pub(crate) fn convert_dto_report_type_to_entity(
+    dto_type: ReportType,
+) -> crate::entities::reports::ReportType {
     match dto_type {
         ReportType::Summary => crate::entities::reports::ReportType::Summary,
         ReportType::Detailed => crate::entities::reports::ReportType::Detailed,
         ReportType::ActionPlan => crate::entities::reports::ReportType::ActionPlan,
+        ReportType::Portfolio => crate::entities::reports::ReportType::Portfolio,
     }
 }
 
@@ -74,11 +83,13 @@ fn convert_dto_report_format_to_entity(
     path = "/reports",
     request_body = GenerateReportRequest,
     responses(
-        (status = 200, description = "Report generation started", body = ApiResponseReportResponse)
+        (status = 200, description = "Report queued for generation", body = ApiResponseReportResponse),
+        (status = 400, description = "Unknown custom section")
     )
 )]
 pub async fn generate_report(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Json(request): Json<GenerateReportRequest>,
 ) -> Result<Json<ApiResponse<ReportResponse>>, (StatusCode, Json<serde_json::Value>)> {
     tracing::info!(
@@ -88,55 +99,132 @@ pub async fn generate_report(
         "Received request to generate new report"
     );
 
-    let pdf_bytes_result =
-        crate::services::pdf_generator::PdfGeneratorService::generate_assessment_pdf(
-            &state.db,
-            request.assessment_id,
-        )
-        .await;
+    AssessmentsRepository::find_by_id_for_tenant(&state.db, &tenant, request.assessment_id)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?
+        .ok_or_else(|| {
+            crate::api::handlers::common::handle_error(AppError::NotFound(
+                "Assessment not found".to_string(),
+            ))
+        })?;
 
-    let pdf_bytes = match pdf_bytes_result {
-        Ok(bytes) => {
-            tracing::info!("PDF bytes generated successfully.");
-            bytes
-        }
-        Err(e) => {
-            tracing::error!(error = %e, "Failed to generate PDF bytes");
-            return Err(crate::api::handlers::common::handle_error(e));
-        }
-    };
+    if matches!(request.report_type, ReportType::Portfolio) {
+        return Err(crate::api::handlers::common::handle_error(
+            AppError::ValidationError(
+                "Portfolio reports are generated through /reports/portfolio".to_string(),
+            ),
+        ));
+    }
 
-    let report_result = state
-        .report_service
-        .generate_and_store_report(
+    let format = convert_dto_report_format_to_entity(request.format);
+    let report_type = convert_dto_report_type_to_entity(request.report_type);
+    let options = ReportOptions {
+        locale: request.locale.unwrap_or_default(),
+        ..ReportOptions::new(
+            report_type.clone(),
+            request.include_recommendations,
+            request.include_action_plans,
+            request.custom_sections,
+        )
+        .map_err(crate::api::handlers::common::handle_error)?
+    };
+    let report = state
+        .report_queue
+        .enqueue(
             request.assessment_id,
-            convert_dto_report_type_to_entity(request.report_type),
+            report_type,
             request.title,
-            convert_dto_report_format_to_entity(request.format),
-            pdf_bytes,
+            format,
+            &options,
         )
-        .await;
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
 
-    let report = match report_result {
-        Ok(r) => {
-            tracing::info!(report_id = %r.report_id, "Report generation and storage successful");
-            r
-        }
-        Err(e) => {
-            tracing::error!(error = %e, "Failed to generate and store report");
-            return Err(crate::api::handlers::common::handle_error(e));
-        }
+    let response = ReportResponse {
+        report_id: report.report_id,
+        assessment_id: report.assessment_id,
+        organization_id: report.organization_id,
+        report_type: convert_entity_report_type_to_dto(report.report_type),
+        title: report.title,
+        format: convert_entity_report_format_to_dto(report.format),
+        summary: report.summary,
+        report_data: report.report_data,
+        file_path: report.file_path,
+        file_sha256: report.file_sha256,
+        status: convert_entity_report_status_to_dto(report.status),
+        generated_at: report.generated_at,
+        created_at: report.created_at,
+        updated_at: report.updated_at,
+    };
+
+    Ok(success_response_with_message(
+        response,
+        "Report queued for generation".to_string(),
+    ))
+}
+
+/// Generate a portfolio report
+///
+/// Aggregates the latest completed assessment of every cooperation of an
+/// organization into a heatmap, the weakest dimensions and the most common
+/// high gaps and recommendations.
+#[utoipa::path(
+    post,
+    path = "/reports/portfolio",
+    request_body = GeneratePortfolioReportRequest,
+    responses(
+        (status = 200, description = "Portfolio report queued for generation", body = ApiResponseReportResponse),
+        (status = 400, description = "Missing organization or unsupported format"),
+        (status = 403, description = "Organization of another tenant")
+    )
+)]
+pub async fn generate_portfolio_report(
+    State(state): State<AppState>,
+    tenant: TenantContext,
+    Json(request): Json<GeneratePortfolioReportRequest>,
+) -> Result<Json<ApiResponse<ReportResponse>>, (StatusCode, Json<serde_json::Value>)> {
+    let organization_id = request
+        .organization_id
+        .or_else(|| tenant.organization_id().map(str::to_string))
+        .ok_or_else(|| AppError::ValidationError("organization_id is required".to_string()))
+        .map_err(crate::api::handlers::common::handle_error)?;
+    tenant
+        .ensure_organization(&organization_id)
+        .map_err(crate::api::handlers::common::handle_error)?;
+    if request.format == ReportFormat::Json {
+        return Err(crate::api::handlers::common::handle_error(
+            AppError::ValidationError(
+                "Portfolio reports are available as Pdf or Excel".to_string(),
+            ),
+        ));
+    }
+
+    tracing::info!(
+        organization_id = %organization_id,
+        format = ?request.format,
+        "Received request to generate portfolio report"
+    );
+    let options = PortfolioOptions {
+        cooperation_ids: request.cooperation_ids,
+        locale: request.locale.unwrap_or_default(),
     };
+    let report = state
+        .report_queue
+        .enqueue_portfolio(organization_id, request.title, request.format, &options)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
 
     let response = ReportResponse {
         report_id: report.report_id,
         assessment_id: report.assessment_id,
+        organization_id: report.organization_id,
         report_type: convert_entity_report_type_to_dto(report.report_type),
         title: report.title,
         format: convert_entity_report_format_to_dto(report.format),
         summary: report.summary,
         report_data: report.report_data,
         file_path: report.file_path,
+        file_sha256: report.file_sha256,
         status: convert_entity_report_status_to_dto(report.status),
         generated_at: report.generated_at,
         created_at: report.created_at,
@@ -145,7 +233,7 @@ pub async fn generate_report(
 
     Ok(success_response_with_message(
         response,
-        "Report generated and stored successfully".to_string(),
+        "Portfolio report queued for generation".to_string(),
     ))
 }
 
@@ -161,9 +249,10 @@ pub async fn generate_report(
 )]
 pub async fn get_report(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(report_id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<ReportResponse>>, (StatusCode, Json<serde_json::Value>)> {
-    let report = ReportsRepository::find_by_id(&state.db, report_id)
+    let report = ReportsRepository::find_by_id_for_tenant(&state.db, &tenant, report_id)
         .await
         .map_err(crate::api::handlers::common::handle_error)?
         .ok_or_else(|| {
@@ -175,12 +264,14 @@ pub async fn get_report(
     let response = ReportResponse {
         report_id: report.report_id,
         assessment_id: report.assessment_id,
+        organization_id: report.organization_id,
         report_type: convert_entity_report_type_to_dto(report.report_type),
         title: report.title,
         format: convert_entity_report_format_to_dto(report.format),
         summary: report.summary,
         report_data: report.report_data,
         file_path: report.file_path,
+        file_sha256: report.file_sha256,
         status: convert_entity_report_status_to_dto(report.status),
         generated_at: report.generated_at,
         created_at: report.created_at,
@@ -202,9 +293,10 @@ pub async fn get_report(
 )]
 pub async fn get_report_status(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(report_id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<ReportStatusResponse>>, (StatusCode, Json<serde_json::Value>)> {
-    let report = ReportsRepository::find_by_id(&state.db, report_id)
+    let report = ReportsRepository::find_by_id_for_tenant(&state.db, &tenant, report_id)
         .await
         .map_err(crate::api::handlers::common::handle_error)?
         .ok_or_else(|| {
@@ -214,29 +306,36 @@ pub async fn get_report_status(
         })?;
 
     let status = convert_entity_report_status_to_dto(report.status);
-    let progress = match status {
-        ReportStatus::Pending => Some(0),
-        ReportStatus::Generating => Some(50),
-        ReportStatus::Completed => Some(100),
-        ReportStatus::Failed => Some(0),
-        ReportStatus::Archived => Some(100),
+    let message = match status {
+        ReportStatus::Pending if report.attempts > 0 => format!(
+            "Attempt {} failed, report will be retried: {}",
+            report.attempts,
+            report.failure_reason.as_deref().unwrap_or("unknown error")
+        ),
+        ReportStatus::Pending => "Report is queued for generation".to_string(),
+        ReportStatus::Generating => {
+            format!("Report is being generated (attempt {})", report.attempts)
+        }
+        ReportStatus::Completed => "Report generation completed".to_string(),
+        ReportStatus::Failed => format!(
+            "Report generation failed after {} attempt(s): {}",
+            report.attempts,
+            report.failure_reason.as_deref().unwrap_or("unknown error")
+        ),
+        ReportStatus::Archived => "Report has been archived".to_string(),
     };
 
     let response = ReportStatusResponse {
         report_id: report.report_id,
         status: status.clone(),
-        progress,
-        message: match status.clone() {
-            ReportStatus::Pending => Some("Report is queued for generation".to_string()),
-            ReportStatus::Generating => Some("Report is being generated".to_string()),
-            ReportStatus::Completed => Some("Report generation completed".to_string()),
-            ReportStatus::Failed => Some("Report generation failed".to_string()),
-            ReportStatus::Archived => Some("Report has been archived".to_string()),
-        },
+        progress: Some(report.progress.clamp(0, 100) as u8),
+        message: Some(message),
         estimated_completion: match status {
-            ReportStatus::Generating => Some(chrono::Utc::now() + chrono::Duration::minutes(5)),
+            ReportStatus::Pending => report.next_attempt_at,
             _ => None,
         },
+        attempts: report.attempts,
+        failure_reason: report.failure_reason,
     };
 
     Ok(success_response(response))
@@ -254,9 +353,10 @@ pub async fn get_report_status(
 )]
 pub async fn download_report(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(report_id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<ReportDownloadResponse>>, (StatusCode, Json<serde_json::Value>)> {
-    let report = ReportsRepository::find_by_id(&state.db, report_id)
+    let report = ReportsRepository::find_by_id_for_tenant(&state.db, &tenant, report_id)
         .await
         .map_err(crate::api::handlers::common::handle_error)?
         .ok_or_else(|| {
@@ -269,12 +369,14 @@ pub async fn download_report(
     let report_response = ReportResponse {
         report_id: report.report_id,
         assessment_id: report.assessment_id,
+        organization_id: report.organization_id,
         report_type: convert_entity_report_type_to_dto(report.report_type),
         title: report.title,
         format: convert_entity_report_format_to_dto(format.clone()),
         summary: report.summary,
         report_data: report.report_data,
         file_path: report.file_path.clone(),
+        file_sha256: report.file_sha256,
         status: convert_entity_report_status_to_dto(report.status),
         generated_at: report.generated_at,
         created_at: report.created_at,
@@ -315,41 +417,153 @@ pub async fn download_report(
 )]
 pub async fn serve_report_file(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(report_id): Path<Uuid>,
 ) -> Result<impl axum::response::IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    ReportsRepository::find_by_id_for_tenant(&state.db, &tenant, report_id)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?
+        .ok_or_else(|| {
+            crate::api::handlers::common::handle_error(AppError::NotFound(
+                "Report not found".to_string(),
+            ))
+        })?;
+
     let (report, file_bytes) = state
         .report_service
         .get_report_file(report_id)
         .await
         .map_err(crate::api::handlers::common::handle_error)?;
 
-    let content_type = match report.format {
-        crate::entities::reports::ReportFormat::Pdf => "application/pdf",
-        crate::entities::reports::ReportFormat::Excel => {
-            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
-        }
-        crate::entities::reports::ReportFormat::Json => "application/json",
-    };
+    file_response(&report, file_bytes)
+}
 
-    let filename = format!(
-        "{}.{}",
-        report.title.replace(" ", "_"),
-        match report.format {
-            crate::entities::reports::ReportFormat::Pdf => "pdf",
-            crate::entities::reports::ReportFormat::Excel => "xlsx",
-            crate::entities::reports::ReportFormat::Json => "json",
-        }
-    );
+/// Issue an expiring download link to a generated report
+///
+/// The link works without authentication, e.g. to share a report with a
+/// board. It is a presigned storage URL, or a link to
+/// `/public/reports/{id}/file` signed by the API.
+#[utoipa::path(
+    get,
+    path = "/reports/{id}/download-link",
+    params(("id" = Uuid, Path, description = "Report ID"), DownloadLinkQuery),
+    responses(
+        (status = 200, description = "Download link", body = ApiResponseReportDownloadLinkResponse),
+        (status = 400, description = "Report is not completed"),
+        (status = 404, description = "Report not found")
+    )
+)]
+pub async fn create_download_link(
+    State(state): State<AppState>,
+    tenant: TenantContext,
+    Path(report_id): Path<Uuid>,
+    Query(query): Query<DownloadLinkQuery>,
+) -> Result<Json<ApiResponse<ReportDownloadLinkResponse>>, (StatusCode, Json<serde_json::Value>)> {
+    let report = ReportsRepository::find_by_id_for_tenant(&state.db, &tenant, report_id)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?
+        .ok_or_else(|| {
+            crate::api::handlers::common::handle_error(AppError::NotFound(
+                "Report not found".to_string(),
+            ))
+        })?;
+
+    if report.status != crate::entities::reports::ReportStatus::Completed {
+        return Err(crate::api::handlers::common::handle_error(
+            AppError::ValidationError("Report has not been generated yet".to_string()),
+        ));
+    }
+
+    let link = state
+        .report_service
+        .download_link(&report, query.expires_in_secs)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
+
+    Ok(success_response(ReportDownloadLinkResponse {
+        report_id,
+        url: link.url,
+        expires_at: link.expires_at,
+    }))
+}
+
+/// Verify a downloaded report file
+///
+/// The request body is the file. It is verified when its SHA-256 matches
+/// the hash recorded when the platform generated the report.
+#[utoipa::path(
+    post,
+    path = "/reports/{id}/verify",
+    params(("id" = Uuid, Path, description = "Report ID")),
+    request_body(content = Vec<u8>, content_type = "application/octet-stream"),
+    responses(
+        (status = 200, description = "Verification result", body = ApiResponseReportVerificationResponse),
+        (status = 404, description = "Report not found")
+    )
+)]
+pub async fn verify_report_file(
+    State(state): State<AppState>,
+    tenant: TenantContext,
+    Path(report_id): Path<Uuid>,
+    file: bytes::Bytes,
+) -> Result<Json<ApiResponse<ReportVerificationResponse>>, (StatusCode, Json<serde_json::Value>)> {
+    let report = ReportsRepository::find_by_id_for_tenant(&state.db, &tenant, report_id)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?
+        .ok_or_else(|| {
+            crate::api::handlers::common::handle_error(AppError::NotFound(
+                "Report not found".to_string(),
+            ))
+        })?;
+
+    let actual_sha256 = sha256_hex(&file);
+    Ok(success_response(ReportVerificationResponse {
+        report_id,
+        verified: report.file_sha256.as_deref() == Some(actual_sha256.as_str()),
+        expected_sha256: report.file_sha256,
+        actual_sha256,
+        generated_at: report.generated_at,
+    }))
+}
+
+/// Serve a report file behind a link signed by `create_download_link`.
+/// The signature replaces authentication.
+pub async fn serve_signed_report_file(
+    State(state): State<AppState>,
+    Path(report_id): Path<Uuid>,
+    Query(query): Query<SignedDownloadQuery>,
+) -> Result<impl axum::response::IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    let (report, file_bytes) = state
+        .report_service
+        .get_signed_report_file(report_id, query.expires, &query.signature)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?;
+
+    file_response(&report, file_bytes)
+}
+
+/// Report file as an attachment
+fn file_response(
+    report: &crate::entities::reports::Model,
+    file_bytes: bytes::Bytes,
+) -> Result<(http::HeaderMap, bytes::Bytes), (StatusCode, Json<serde_json::Value>)> {
+    let content_type = report.format.content_type();
+    let disposition = http::HeaderValue::from_str(&content_disposition(&download_file_name(
+        report,
+    )))
+    .map_err(|e| {
+        crate::api::handlers::common::handle_error(AppError::InternalServerError(format!(
+            "Invalid download file name: {}",
+            e
+        )))
+    })?;
 
     let mut headers = http::HeaderMap::new();
     headers.insert(
         http::header::CONTENT_TYPE,
         http::HeaderValue::from_static(content_type),
     );
-    headers.insert(
-        http::header::CONTENT_DISPOSITION,
-        http::HeaderValue::from_str(&format!("attachment; filename=\"{}\"", filename)).unwrap(),
-    );
+    headers.insert(http::header::CONTENT_DISPOSITION, disposition);
 
     Ok((headers, file_bytes))
 }
@@ -368,6 +582,7 @@ pub async fn serve_report_file(
 )]
 pub async fn list_reports(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Query(params): Query<PaginationParams>,
 ) -> Result<
     Json<ApiResponse<PaginatedResponse<ReportResponse>>>,
@@ -376,7 +591,7 @@ pub async fn list_reports(
     let (page, limit, _sort_by, _sort_order) = extract_pagination(Query(params));
     let offset = ((page - 1) * limit) as u64;
 
-    let reports = ReportsRepository::find_all(&state.db)
+    let reports = ReportsRepository::find_all(&state.db, &tenant)
         .await
         .map_err(crate::api::handlers::common::handle_error)?;
 
@@ -388,12 +603,14 @@ pub async fn list_reports(
         .map(|report| ReportResponse {
             report_id: report.report_id,
             assessment_id: report.assessment_id,
+            organization_id: report.organization_id,
             report_type: convert_entity_report_type_to_dto(report.report_type),
             title: report.title,
             format: convert_entity_report_format_to_dto(report.format),
             summary: report.summary,
             report_data: report.report_data,
             file_path: report.file_path,
+            file_sha256: report.file_sha256,
             status: convert_entity_report_status_to_dto(report.status),
             generated_at: report.generated_at,
             created_at: report.created_at,
@@ -416,6 +633,7 @@ pub async fn list_reports(
 )]
 pub async fn list_reports_by_assessment(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(assessment_id): Path<Uuid>,
     Query(params): Query<PaginationParams>,
 ) -> Result<
@@ -425,7 +643,7 @@ pub async fn list_reports_by_assessment(
     let (page, limit, _sort_by, _sort_order) = extract_pagination(Query(params));
     let offset = ((page - 1) * limit) as u64;
 
-    let reports = ReportsRepository::find_by_assessment(&state.db, assessment_id)
+    let reports = ReportsRepository::find_by_assessment(&state.db, &tenant, assessment_id)
         .await
         .map_err(crate::api::handlers::common::handle_error)?;
 
@@ -437,12 +655,14 @@ pub async fn list_reports_by_assessment(
         .map(|report| ReportResponse {
             report_id: report.report_id,
             assessment_id: report.assessment_id,
+            organization_id: report.organization_id,
             report_type: convert_entity_report_type_to_dto(report.report_type),
             title: report.title,
             format: convert_entity_report_format_to_dto(report.format),
             summary: report.summary,
             report_data: report.report_data,
             file_path: report.file_path,
+            file_sha256: report.file_sha256,
             status: convert_entity_report_status_to_dto(report.status),
             generated_at: report.generated_at,
             created_at: report.created_at,
@@ -467,10 +687,11 @@ pub async fn list_reports_by_assessment(
 )]
 pub async fn update_report(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(report_id): Path<Uuid>,
     Json(request): Json<UpdateReportRequest>,
 ) -> Result<Json<ApiResponse<ReportResponse>>, (StatusCode, Json<serde_json::Value>)> {
-    let mut report = ReportsRepository::find_by_id(&state.db, report_id)
+    let mut report = ReportsRepository::find_by_id_for_tenant(&state.db, &tenant, report_id)
         .await
         .map_err(crate::api::handlers::common::handle_error)?
         .ok_or_else(|| {
@@ -487,6 +708,15 @@ pub async fn update_report(
         report.summary = Some(summary);
     }
     if let Some(report_data) = request.report_data {
+        // The snapshot of a generated report is what its file was rendered
+        // from, and stays as it was
+        if report.file_sha256.is_some() {
+            return Err(crate::api::handlers::common::handle_error(
+                AppError::ValidationError(
+                    "The data snapshot of a generated report cannot be changed".to_string(),
+                ),
+            ));
+        }
         report.report_data = Some(report_data);
     }
 
@@ -500,12 +730,14 @@ pub async fn update_report(
     let response = ReportResponse {
         report_id: updated_report.report_id,
         assessment_id: updated_report.assessment_id,
+        organization_id: updated_report.organization_id,
         report_type: convert_entity_report_type_to_dto(updated_report.report_type),
         title: updated_report.title,
         format: convert_entity_report_format_to_dto(updated_report.format),
         summary: updated_report.summary,
         report_data: updated_report.report_data,
         file_path: updated_report.file_path,
+        file_sha256: updated_report.file_sha256,
         status: convert_entity_report_status_to_dto(updated_report.status),
         generated_at: updated_report.generated_at,
         created_at: updated_report.created_at,
@@ -529,9 +761,22 @@ pub async fn update_report(
 )]
 pub async fn delete_report(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(report_id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<()>>, (StatusCode, Json<serde_json::Value>)> {
-    ReportsRepository::delete(&state.db, report_id)
+    ReportsRepository::find_by_id_for_tenant(&state.db, &tenant, report_id)
+        .await
+        .map_err(crate::api::handlers::common::handle_error)?
+        .ok_or_else(|| {
+            crate::api::handlers::common::handle_error(AppError::NotFound(
+                "Report not found".to_string(),
+            ))
+        })?;
+
+    // Removes the stored file along with the row
+    state
+        .report_service
+        .delete_report(report_id)
         .await
         .map_err(crate::api::handlers::common::handle_error)?;
 
@@ -553,14 +798,16 @@ pub async fn delete_report(
 )]
 pub async fn download_latest_report_by_assessment(
     State(state): State<AppState>,
+    tenant: TenantContext,
     Path(assessment_id): Path<Uuid>,
 ) -> Result<impl axum::response::IntoResponse, (StatusCode, Json<serde_json::Value>)> {
     tracing::info!(assessment_id = %assessment_id, "Request to download latest report");
 
     // Use the optimized repository method to find the latest completed PDF report
-    let report_option = ReportsRepository::find_latest_pdf_by_assessment(&state.db, assessment_id)
-        .await
-        .map_err(crate::api::handlers::common::handle_error)?;
+    let report_option =
+        ReportsRepository::find_latest_pdf_by_assessment(&state.db, &tenant, assessment_id)
+            .await
+            .map_err(crate::api::handlers::common::handle_error)?;
 
     let report = match report_option {
         Some(r) => {
@@ -569,9 +816,10 @@ pub async fn download_latest_report_by_assessment(
         }
         None => {
             // Fallback: Check if ANY reports exist for this assessment to provide better logging
-            let all_reports = ReportsRepository::find_by_assessment(&state.db, assessment_id)
-                .await
-                .unwrap_or_default();
+            let all_reports =
+                ReportsRepository::find_by_assessment(&state.db, &tenant, assessment_id)
+                    .await
+                    .unwrap_or_default();
 
             tracing::warn!(
                 assessment_id = %assessment_id,
@@ -600,18 +848,5 @@ pub async fn download_latest_report_by_assessment(
         "Sending PDF file to client"
     );
 
-    let content_type = "application/pdf";
-    let filename = format!("{}.pdf", report.title.replace(" ", "_"));
-
-    let mut headers = http::HeaderMap::new();
-    headers.insert(
-        http::header::CONTENT_TYPE,
-        http::HeaderValue::from_static(content_type),
-    );
-    headers.insert(
-        http::header::CONTENT_DISPOSITION,
-        http::HeaderValue::from_str(&format!("attachment; filename=\"{}\"", filename)).unwrap(),
-    );
-
-    Ok((headers, file_bytes))
+    file_response(&report, file_bytes)
 }
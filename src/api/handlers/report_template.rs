@@ -0,0 +1,200 @@
+use crate::AppState;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+};
+use uuid::Uuid;
+
+use crate::api::dto::{
+    common::ApiResponse,
+    report_template::{
+        CreateReportTemplateRequest, ReportTemplateListResponse, ReportTemplateQuery,
+        ReportTemplateResponse,
+    },
+};
+use crate::api::handlers::common::{handle_error, success_response, success_response_with_message};
+use crate::api::handlers::report::{
+    convert_dto_report_type_to_entity, convert_entity_report_type_to_dto,
+};
+use crate::auth::claims::Claims;
+use crate::auth::tenant::TenantContext;
+use crate::entities::report_templates;
+use crate::error::AppError;
+use crate::repositories::report_templates::ReportTemplatesRepository;
+
+/// Default templates are shared by every organization and managed by DGRV
+fn ensure_can_manage(
+    tenant: &TenantContext,
+    organization_id: Option<&str>,
+) -> Result<(), AppError> {
+    match organization_id {
+        Some(organization_id) => tenant.ensure_organization(organization_id),
+        None if tenant.is_unrestricted() => Ok(()),
+        None => Err(AppError::Forbidden(
+            "Only DGRV administrators can manage the default templates".to_string(),
+        )),
+    }
+}
+
+fn to_response(template: report_templates::Model) -> Result<ReportTemplateResponse, AppError> {
+    let branding = serde_json::from_value(template.branding)
+        .map_err(|e| AppError::InternalServerError(format!("Invalid stored branding: {}", e)))?;
+    Ok(ReportTemplateResponse {
+        template_id: template.template_id,
+        organization_id: template.organization_id,
+        report_type: convert_entity_report_type_to_dto(template.report_type),
+        version: template.version,
+        content: template.content,
+        branding,
+        is_active: template.is_active,
+        created_by: template.created_by,
+        created_at: template.created_at,
+        updated_at: template.updated_at,
+    })
+}
+
+async fn find_visible(
+    state: &AppState,
+    tenant: &TenantContext,
+    template_id: Uuid,
+) -> Result<report_templates::Model, AppError> {
+    ReportTemplatesRepository::find_by_id(state.db.as_ref(), template_id)
+        .await?
+        .filter(|template| {
+            template
+                .organization_id
+                .as_deref()
+                .is_none_or(|organization_id| tenant.can_access_organization(organization_id))
+        })
+        .ok_or_else(|| AppError::NotFound("Report template not found".to_string()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/report-templates",
+    tag = "Admin",
+    request_body = CreateReportTemplateRequest,
+    responses(
+        (status = 200, description = "Template stored as the active version", body = ApiResponseReportTemplateResponse),
+        (status = 400, description = "Template or branding is invalid"),
+        (status = 403, description = "Organization is not accessible")
+    )
+)]
+/// Upload a report template
+///
+/// Stores a new version of the template of the organization and report type
+/// and makes it the active one. The template is compiled and rendered with
+/// sample data before it is accepted.
+pub async fn create_report_template(
+    State(state): State<AppState>,
+    tenant: TenantContext,
+    claims: Claims,
+    Json(request): Json<CreateReportTemplateRequest>,
+) -> Result<Json<ApiResponse<ReportTemplateResponse>>, (StatusCode, Json<serde_json::Value>)> {
+    ensure_can_manage(&tenant, request.organization_id.as_deref()).map_err(handle_error)?;
+
+    let template = state
+        .report_templates
+        .create(
+            request.organization_id,
+            convert_dto_report_type_to_entity(request.report_type),
+            request.content,
+            request.branding.unwrap_or_default(),
+            Some(claims.subject),
+        )
+        .await
+        .map_err(handle_error)?;
+
+    Ok(success_response_with_message(
+        to_response(template).map_err(handle_error)?,
+        "Report template stored".to_string(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/report-templates",
+    tag = "Admin",
+    params(ReportTemplateQuery),
+    responses(
+        (status = 200, description = "Template versions of the organization and the defaults", body = ApiResponseReportTemplateListResponse)
+    )
+)]
+/// List report templates
+pub async fn list_report_templates(
+    State(state): State<AppState>,
+    tenant: TenantContext,
+    Query(query): Query<ReportTemplateQuery>,
+) -> Result<Json<ApiResponse<ReportTemplateListResponse>>, (StatusCode, Json<serde_json::Value>)> {
+    let templates = ReportTemplatesRepository::find_all(
+        state.db.as_ref(),
+        &tenant,
+        query.report_type.map(convert_dto_report_type_to_entity),
+    )
+    .await
+    .map_err(handle_error)?
+    .into_iter()
+    .map(to_response)
+    .collect::<Result<Vec<_>, _>>()
+    .map_err(handle_error)?;
+
+    Ok(success_response(ReportTemplateListResponse { templates }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/report-templates/{id}",
+    tag = "Admin",
+    params(("id" = Uuid, Path, description = "Template ID")),
+    responses(
+        (status = 200, description = "Template version", body = ApiResponseReportTemplateResponse),
+        (status = 404, description = "Template not found")
+    )
+)]
+/// Get a report template version
+pub async fn get_report_template(
+    State(state): State<AppState>,
+    tenant: TenantContext,
+    Path(template_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ReportTemplateResponse>>, (StatusCode, Json<serde_json::Value>)> {
+    let template = find_visible(&state, &tenant, template_id)
+        .await
+        .map_err(handle_error)?;
+    Ok(success_response(
+        to_response(template).map_err(handle_error)?,
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/report-templates/{id}/activate",
+    tag = "Admin",
+    params(("id" = Uuid, Path, description = "Template ID")),
+    responses(
+        (status = 200, description = "Template version activated", body = ApiResponseReportTemplateResponse),
+        (status = 403, description = "Organization is not accessible"),
+        (status = 404, description = "Template not found")
+    )
+)]
+/// Activate a report template version
+///
+/// Makes an earlier version the one used to render reports again.
+pub async fn activate_report_template(
+    State(state): State<AppState>,
+    tenant: TenantContext,
+    Path(template_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ReportTemplateResponse>>, (StatusCode, Json<serde_json::Value>)> {
+    let template = find_visible(&state, &tenant, template_id)
+        .await
+        .map_err(handle_error)?;
+    ensure_can_manage(&tenant, template.organization_id.as_deref()).map_err(handle_error)?;
+
+    let template = ReportTemplatesRepository::activate(state.db.as_ref(), template_id)
+        .await
+        .map_err(handle_error)?;
+    Ok(success_response_with_message(
+        to_response(template).map_err(handle_error)?,
+        "Report template activated".to_string(),
+    ))
+}
@@ -1,4 +1,6 @@
+use crate::auth::tenant::TenantContext;
 use crate::error::AppError;
+use crate::repositories::assessments::AssessmentsRepository;
 use crate::{api::dto::report::ReportResponse, entities::reports::Model as ReportModel};
 use crate::{auth::claims::Claims, services::submission_service::SubmissionService};
 use axum::{extract::State, Json};
@@ -14,6 +16,9 @@ pub struct SubmitAssessmentRequest {
 /// Submit an assessment
 ///
 /// This endpoint submits an assessment and triggers the generation of a report.
+/// Every assigned dimension needs a dimension assessment with a current and a
+/// desired state of that dimension; otherwise the response lists the
+/// `missing_dimensions` under `details`.
 #[utoipa::path(
     post,
     path = "/api/submissions/submit",
@@ -22,6 +27,8 @@ pub struct SubmitAssessmentRequest {
         (status = 200, description = "Assessment submitted successfully", body = ReportResponse),
         (status = 400, description = "Bad request"),
         (status = 404, description = "Assessment not found"),
+        (status = 409, description = "Assessment is not in progress or was already submitted"),
+        (status = 422, description = "Assessment is incomplete, `details.missing_dimensions` lists the dimensions to complete"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Submissions"
@@ -30,9 +37,14 @@ pub struct SubmitAssessmentRequest {
 pub async fn submit_assessment(
     State(state): State<crate::AppState>,
     claims: Claims,
+    tenant: TenantContext,
     Json(body): Json<SubmitAssessmentRequest>,
 ) -> Result<Json<ReportResponse>, AppError> {
-    let submission_service = SubmissionService::new(state.db.clone(), state.report_service.clone());
+    AssessmentsRepository::find_by_id_for_tenant(&state.db, &tenant, body.assessment_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Assessment not found".to_string()))?;
+
+    let submission_service = SubmissionService::new(state.db.clone(), state.report_queue.clone());
     let report = submission_service
         .submit_assessment(body.assessment_id, claims.subject)
         .await?;
@@ -44,12 +56,14 @@ impl From<ReportModel> for ReportResponse {
         Self {
             report_id: model.report_id,
             assessment_id: model.assessment_id,
+            organization_id: model.organization_id,
             report_type: model.report_type.into(),
             title: model.title,
             format: model.format,
             summary: model.summary,
             report_data: model.report_data,
             file_path: model.file_path,
+            file_sha256: model.file_sha256,
             status: model.status.into(),
             generated_at: model.generated_at,
             created_at: model.created_at,
@@ -70,6 +84,9 @@ impl From<crate::entities::reports::ReportType> for crate::api::dto::report::Rep
             crate::entities::reports::ReportType::ActionPlan => {
                 crate::api::dto::report::ReportType::ActionPlan
             }
+            crate::entities::reports::ReportType::Portfolio => {
+                crate::api::dto::report::ReportType::Portfolio
+            }
         }
     }
 }
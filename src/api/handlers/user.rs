@@ -1,5 +1,10 @@
 use crate::{
-    api::dto::member::AddMemberRequest, error::AppResult, models::keycloak::CreateUserRequest,
+    api::{dto::member::AddMemberRequest, handlers::group::find_organization_group},
+    auth::{
+        claims::Claims, cooperation::CooperationScope, permissions::Role, tenant::TenantContext,
+    },
+    error::{AppError, AppResult},
+    models::keycloak::CreateUserRequest,
     AppState,
 };
 use axum::{
@@ -9,6 +14,27 @@ use axum::{
     Json,
 };
 
+/// Reject groups outside the caller's reach: coop admins may only manage
+/// their own cooperations, organization admins their organization's groups.
+async fn ensure_group_access(
+    state: &AppState,
+    token: &str,
+    claims: &Claims,
+    scope: &CooperationScope,
+    group_id: &str,
+) -> AppResult<()> {
+    if claims.has_role(Role::DgrvAdmin) {
+        return Ok(());
+    }
+    if scope.is_restricted() {
+        return scope.ensure(Some(group_id));
+    }
+
+    let tenant = TenantContext::from_claims(claims)?;
+    find_organization_group(state, token, &tenant, group_id).await?;
+    Ok(())
+}
+
 /// Add a member to a group (cooperation)
 #[utoipa::path(
     post,
@@ -18,14 +44,31 @@ use axum::{
         ("group_id" = String, Path, description = "Cooperation Group ID")
     ),
     request_body = AddMemberRequest,
-    responses((status = 201, description = "Created"))
+    responses(
+        (status = 201, description = "Created"),
+        (status = 403, description = "Role or group outside the caller's reach")
+    )
 )]
 pub async fn add_member(
     State(state): State<AppState>,
     Extension(token): Extension<String>,
+    claims: Claims,
+    scope: CooperationScope,
     Path(group_id): Path<String>,
     Json(payload): Json<AddMemberRequest>,
 ) -> AppResult<impl IntoResponse> {
+    if let Some(role) = payload
+        .roles
+        .iter()
+        .find(|role| !claims.can_assign_role(role))
+    {
+        return Err(AppError::Forbidden(format!(
+            "Not allowed to assign role {}",
+            role
+        )));
+    }
+    ensure_group_access(&state, &token, &claims, &scope, &group_id).await?;
+
     // Find user by email
     let existing_user = state
         .keycloak_service
@@ -86,6 +129,7 @@ pub async fn add_member(
         .keycloak_service
         .add_user_to_group(&token, &user_id, &group_id)
         .await?;
+    state.cooperation_membership.invalidate_user(&user_id).await;
 
     Ok(StatusCode::CREATED)
 }
@@ -96,13 +140,19 @@ pub async fn add_member(
     path = "/admin/groups/{group_id}/members",
     tag = "User",
     params(("group_id" = String, Path, description = "Group ID")),
-    responses((status = 200, description = "OK", body = Vec<KeycloakUser>))
+    responses(
+        (status = 200, description = "OK", body = Vec<KeycloakUser>),
+        (status = 403, description = "Group outside the caller's reach")
+    )
 )]
 pub async fn get_group_members(
     State(state): State<AppState>,
     Extension(token): Extension<String>,
+    claims: Claims,
+    scope: CooperationScope,
     Path(group_id): Path<String>,
 ) -> AppResult<impl IntoResponse> {
+    ensure_group_access(&state, &token, &claims, &scope, &group_id).await?;
     let members = state
         .keycloak_service
         .get_group_members(&token, &group_id)
@@ -124,5 +174,6 @@ pub async fn delete_user(
     Path(user_id): Path<String>,
 ) -> AppResult<impl IntoResponse> {
     state.keycloak_service.delete_user(&token, &user_id).await?;
+    state.cooperation_membership.invalidate_user(&user_id).await;
     Ok(StatusCode::NO_CONTENT)
 }
@@ -7,6 +7,7 @@ use crate::api::dto::action_plan::*;
 use crate::api::dto::assessment::*;
 use crate::api::dto::common::*;
 use crate::api::dto::dimension::*;
+use crate::api::dto::framework_version::*;
 use crate::api::dto::gap::*;
 use crate::api::dto::group::*;
 use crate::api::dto::invitation::*;
@@ -15,6 +16,7 @@ use crate::api::dto::organization::*;
 use crate::api::dto::organization_dimension::*;
 use crate::api::dto::recommendation::*;
 use crate::api::dto::report::*;
+use crate::api::dto::report_template::*;
 use crate::models::keycloak::KeycloakUser;
 
 #[derive(OpenApi)]
@@ -43,6 +45,11 @@ use crate::models::keycloak::KeycloakUser;
         crate::api::handlers::assessment::update_assessment,
         crate::api::handlers::assessment::delete_assessment,
         crate::api::handlers::assessment::get_assessment_summary,
+        crate::api::handlers::assessment::reassess_assessment,
+        crate::api::handlers::assessment::compare_assessments,
+        crate::api::handlers::analytics::get_organization_maturity_trend,
+        crate::api::handlers::analytics::get_cooperation_maturity_trend,
+        crate::api::handlers::analytics::get_benchmarks,
         crate::api::handlers::assessment::create_dimension_assessment,
         crate::api::handlers::assessment::list_dimension_assessments,
         crate::api::handlers::assessment::update_dimension_assessment,
@@ -64,14 +71,26 @@ use crate::models::keycloak::KeycloakUser;
         crate::api::handlers::dimension::update_desired_state,
         crate::api::handlers::dimension::delete_desired_state,
         crate::api::handlers::report::generate_report,
+        crate::api::handlers::report::generate_portfolio_report,
         crate::api::handlers::report::get_report,
         crate::api::handlers::report::update_report,
         crate::api::handlers::report::delete_report,
         crate::api::handlers::report::get_report_status,
         crate::api::handlers::report::download_report,
+        crate::api::handlers::report::create_download_link,
+        crate::api::handlers::report::verify_report_file,
         crate::api::handlers::report::list_reports,
         crate::api::handlers::report::list_reports_by_assessment,
         crate::api::handlers::report::download_latest_report_by_assessment,
+        crate::api::handlers::report_template::create_report_template,
+        crate::api::handlers::report_template::list_report_templates,
+        crate::api::handlers::report_template::get_report_template,
+        crate::api::handlers::report_template::activate_report_template,
+        crate::api::handlers::framework_version::create_framework_version,
+        crate::api::handlers::framework_version::list_framework_versions,
+        crate::api::handlers::framework_version::get_framework_version,
+        crate::api::handlers::framework_version::publish_framework_version,
+        crate::api::handlers::framework_version::discard_framework_version,
         crate::api::handlers::action_plan::list_action_plans,
         crate::api::handlers::action_plan::get_action_plan_by_assessment_id,
         crate::api::handlers::action_plan::create_action_item,
@@ -102,6 +121,10 @@ use crate::models::keycloak::KeycloakUser;
         schemas(
             // Common
             crate::api::handlers::submission::SubmitAssessmentRequest,
+            crate::services::submission_service::MissingDimension,
+            crate::services::submission_service::MissingDimensionReason,
+            crate::services::dimension_scoring::DimensionScore,
+            crate::services::dimension_scoring::AssessmentScore,
             PaginationParams,
             SortOrder,
             EmptyResponse,
@@ -125,8 +148,12 @@ use crate::models::keycloak::KeycloakUser;
             // ApiResponse aliases for Reports
             ApiResponseReportResponse,
             ApiResponseReportDownloadResponse,
+            ApiResponseReportDownloadLinkResponse,
+            ApiResponseReportVerificationResponse,
             ApiResponseReportStatusResponse,
             ApiResponsePaginatedReportResponse,
+            ApiResponseReportTemplateResponse,
+            ApiResponseReportTemplateListResponse,
 
             // Report related schemas
             ReportType,
@@ -137,6 +164,9 @@ use crate::models::keycloak::KeycloakUser;
             ApiResponseAssessmentResponse,
             ApiResponsePaginatedAssessmentResponse,
             ApiResponseAssessmentSummaryResponse,
+            ApiResponseAssessmentComparisonResponse,
+            ApiResponseMaturityTrendResponse,
+            ApiResponseBenchmarkResponse,
             ApiResponseDimensionAssessmentResponse,
             ApiResponseDimensionResponse,
             ApiResponseAssessmentsResponse,
@@ -145,6 +175,8 @@ use crate::models::keycloak::KeycloakUser;
             ApiResponseCurrentStateResponse,
             ApiResponseDesiredStateResponse,
             ApiResponseDimensionWithStatesResponse,
+            ApiResponseFrameworkVersionResponse,
+            ApiResponseFrameworkVersionListResponse,
             // ApiResponse aliases for Gaps
             ApiResponseGapResponse,
             ApiResponsePaginatedGapResponse,
@@ -167,6 +199,16 @@ use crate::models::keycloak::KeycloakUser;
             UpdateDimensionAssessmentRequest,
             DimensionAssessmentResponse,
             AssessmentSummaryResponse,
+            ReassessRequest,
+            DimensionComparison,
+            AssessmentComparisonResponse,
+            // Analytics
+            crate::api::dto::analytics::MaturityTrendPoint,
+            crate::api::dto::analytics::MaturityTrendResponse,
+            crate::api::dto::analytics::ScoreDistribution,
+            crate::api::dto::analytics::GapSeverityShare,
+            crate::api::dto::analytics::DimensionBenchmark,
+            crate::api::dto::analytics::BenchmarkResponse,
             AssessmentsResponse,
             DimensionAssessmentsResponse,
             // Dimensions
@@ -181,15 +223,39 @@ use crate::models::keycloak::KeycloakUser;
             DesiredStateResponse,
             DimensionWithStatesResponse,
             DimensionListResponse,
+            // Framework versions
+            CreateFrameworkVersionRequest,
+            FrameworkVersionStatus,
+            FrameworkVersionResponse,
+            FrameworkVersionListResponse,
             // Reports
             GenerateReportRequest,
+            GeneratePortfolioReportRequest,
             ReportResponse,
+            JsonReport,
+            JsonReportAssessment,
+            JsonReportDimension,
+            JsonReportState,
+            JsonReportGap,
+            JsonReportRecommendation,
+            JsonReportActionItem,
+            // Report templates
+            CreateReportTemplateRequest,
+            ReportTemplateResponse,
+            ReportTemplateListResponse,
+            crate::services::report_templates::ReportBranding,
+            crate::services::report_templates::ReportHeader,
+            crate::services::i18n::Locale,
+            crate::services::i18n::DimensionTranslation,
+            crate::services::i18n::RecommendationTranslation,
             // Recommendations
             CreateRecommendationRequest,
             UpdateRecommendationRequest,
             RecommendationResponse,
             RecommendationPriority,
             ReportDownloadResponse,
+            ReportDownloadLinkResponse,
+            ReportVerificationResponse,
             ReportListResponse,
             ReportStatusResponse,
             // Action Plans
@@ -0,0 +1,29 @@
+use crate::api::handlers::analytics::{
+    get_benchmarks, get_cooperation_maturity_trend, get_organization_maturity_trend,
+};
+use crate::auth::permissions::{require, Permission};
+use crate::AppState;
+use axum::{routing::get, Router};
+
+/// Create analytics routes
+pub fn create_analytics_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/benchmarks",
+            require(Permission::ViewBenchmarks, get(get_benchmarks)),
+        )
+        .route(
+            "/organizations/:organization_id/maturity-trend",
+            require(
+                Permission::ViewAssessments,
+                get(get_organization_maturity_trend),
+            ),
+        )
+        .route(
+            "/cooperations/:cooperation_id/maturity-trend",
+            require(
+                Permission::ViewAssessments,
+                get(get_cooperation_maturity_trend),
+            ),
+        )
+}
@@ -6,88 +6,163 @@ use axum::{
 
 use crate::api::handlers::{assessment::*, dimension::*, gap::*};
 use crate::api::routes::{
-    action_plan::create_action_plan_routes, group::create_group_routes,
+    action_plan::create_action_plan_routes, analytics::create_analytics_routes,
+    framework_version::create_framework_version_routes, group::create_group_routes,
     organization::create_organization_routes, recommendation::create_recommendation_routes,
-    submissions::create_submission_routes, user::user_routes,
+    report_template::create_report_template_routes, submissions::create_submission_routes,
+    user::user_routes,
 };
+use crate::auth::permissions::{require, Permission};
 
 /// Create the main API routes
 pub fn create_api_routes(_app_state: AppState) -> Router<AppState> {
     Router::new()
         .nest("/action-plans", create_action_plan_routes())
+        .nest("/analytics", create_analytics_routes())
         .nest("/admin/organizations", create_organization_routes())
         .nest("/admin/groups", create_group_routes())
         .nest("/admin/users", user_routes())
+        .nest("/admin/report-templates", create_report_template_routes())
+        .nest(
+            "/admin/framework-versions",
+            create_framework_version_routes(),
+        )
         .nest("/recommendations", create_recommendation_routes())
         .nest("/api/submissions", create_submission_routes())
         // Assessment routes
-        .route("/assessments", post(create_assessment))
-        .route("/assessments", get(list_assessments))
-        .route("/assessments/:id", get(get_assessment))
-        .route("/assessments/:id", put(update_assessment))
-        .route("/assessments/:id", delete(delete_assessment))
-        .route("/assessments/:id/summary", get(get_assessment_summary))
+        .route(
+            "/assessments",
+            require(Permission::ManageAssessments, post(create_assessment)),
+        )
+        .route(
+            "/assessments",
+            require(Permission::ViewAssessments, get(list_assessments)),
+        )
+        .route(
+            "/assessments/:id",
+            require(Permission::ViewAssessments, get(get_assessment)),
+        )
+        .route(
+            "/assessments/:id",
+            require(Permission::ManageAssessments, put(update_assessment)),
+        )
+        .route(
+            "/assessments/:id",
+            require(Permission::ManageAssessments, delete(delete_assessment)),
+        )
+        .route(
+            "/assessments/:id/summary",
+            require(Permission::ViewAssessments, get(get_assessment_summary)),
+        )
+        .route(
+            "/assessments/:id/reassess",
+            require(Permission::ManageAssessments, post(reassess_assessment)),
+        )
+        .route(
+            "/assessments/:id/compare/:other_id",
+            require(Permission::ViewAssessments, get(compare_assessments)),
+        )
         .route(
             "/assessments/:id/dimension-assessments",
-            post(create_dimension_assessment).get(list_dimension_assessments),
+            require(
+                Permission::AnswerAssessments,
+                post(create_dimension_assessment),
+            )
+            .merge(require(
+                Permission::ViewAssessments,
+                get(list_dimension_assessments),
+            )),
         )
         .route(
             "/assessments/:assessment_id/dimension-assessments/:dimension_assessment_id",
-            put(update_dimension_assessment),
+            require(
+                Permission::AnswerAssessments,
+                put(update_dimension_assessment),
+            ),
         )
         .route(
             "/assessments/organizations/:organization_id",
-            get(list_assessments_by_organization),
+            require(
+                Permission::ViewAssessments,
+                get(list_assessments_by_organization),
+            ),
         )
         .route(
             "/assessments/organizations/:organization_id/:assessment_id",
-            delete(delete_organization_assessment),
+            require(
+                Permission::ManageAssessments,
+                delete(delete_organization_assessment),
+            ),
         )
         .route(
             "/assessments/cooperations/:cooperation_id",
-            get(list_assessments_by_cooperation),
+            require(
+                Permission::ViewAssessments,
+                get(list_assessments_by_cooperation),
+            ),
         )
         .route(
             "/assessments/organizations/:organization_id/submissions",
-            get(list_submissions_by_organization),
+            require(
+                Permission::ViewAssessments,
+                get(list_submissions_by_organization),
+            ),
         )
         .route(
             "/assessments/cooperations/:cooperation_id/submissions",
-            get(list_submissions_by_cooperation),
+            require(
+                Permission::ViewAssessments,
+                get(list_submissions_by_cooperation),
+            ),
         )
         // Dimension routes
-        .route("/dimensions", post(create_dimension))
-        .route("/dimensions", get(list_dimensions))
-        .route("/dimensions/:id", get(get_dimension))
-        .route("/dimensions/:id", put(update_dimension))
-        .route("/dimensions/:id", delete(delete_dimension))
+        .route(
+            "/dimensions",
+            require(Permission::ManageFramework, post(create_dimension)),
+        )
+        .route(
+            "/dimensions",
+            require(Permission::ViewFramework, get(list_dimensions)),
+        )
+        .route(
+            "/dimensions/:id",
+            require(Permission::ViewFramework, get(get_dimension)),
+        )
+        .route(
+            "/dimensions/:id",
+            require(Permission::ManageFramework, put(update_dimension)),
+        )
+        .route(
+            "/dimensions/:id",
+            require(Permission::ManageFramework, delete(delete_dimension)),
+        )
         .route(
             "/dimensions/:id/with-states",
-            get(get_dimension_with_states),
+            require(Permission::ViewFramework, get(get_dimension_with_states)),
         )
         .route(
             "/dimensions/:dimension_id/current-states",
-            post(create_current_state),
+            require(Permission::ManageFramework, post(create_current_state)),
         )
         .route(
             "/dimensions/:dimension_id/current-states/:current_state_id",
-            put(update_current_state),
+            require(Permission::ManageFramework, put(update_current_state)),
         )
         .route(
             "/dimensions/:dimension_id/current-states/:current_state_id",
-            delete(delete_current_state),
+            require(Permission::ManageFramework, delete(delete_current_state)),
         )
         .route(
             "/dimensions/:dimension_id/desired-states",
-            post(create_desired_state),
+            require(Permission::ManageFramework, post(create_desired_state)),
         )
         .route(
             "/dimensions/:dimension_id/desired-states/:desired_state_id",
-            put(update_desired_state),
+            require(Permission::ManageFramework, put(update_desired_state)),
         )
         .route(
             "/dimensions/:dimension_id/desired-states/:desired_state_id",
-            delete(delete_desired_state),
+            require(Permission::ManageFramework, delete(delete_desired_state)),
         )
         // Report routes
         .nest(
@@ -95,18 +170,33 @@ pub fn create_api_routes(_app_state: AppState) -> Router<AppState> {
             crate::api::routes::report::create_report_routes(),
         )
         // Gap routes
-        .route("/gaps", get(list_gaps))
-        .route("/gaps/:id", get(get_gap))
-        .route("/gaps/:id", put(update_gap))
-        .route("/gaps/:id", delete(delete_gap))
+        .route("/gaps", require(Permission::ViewFramework, get(list_gaps)))
+        .route(
+            "/gaps/:id",
+            require(Permission::ViewFramework, get(get_gap)),
+        )
+        .route(
+            "/gaps/:id",
+            require(Permission::ManageFramework, put(update_gap)),
+        )
+        .route(
+            "/gaps/:id",
+            require(Permission::ManageFramework, delete(delete_gap)),
+        )
         .route(
             "/dimension-assessments/:dimension_assessment_id/gaps",
-            get(list_gaps_by_dimension_assessment),
+            require(
+                Permission::ViewAssessments,
+                get(list_gaps_by_dimension_assessment),
+            ),
         )
         .route(
             "/assessments/:assessment_id/gaps",
-            get(list_gaps_by_assessment),
+            require(Permission::ViewAssessments, get(list_gaps_by_assessment)),
         )
         // Admin gap creation
-        .route("/admin/gaps", post(admin_create_gap))
+        .route(
+            "/admin/gaps",
+            require(Permission::ManageFramework, post(admin_create_gap)),
+        )
 }
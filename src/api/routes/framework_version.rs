@@ -0,0 +1,33 @@
+use crate::api::handlers::framework_version::{
+    create_framework_version, discard_framework_version, get_framework_version,
+    list_framework_versions, publish_framework_version,
+};
+use crate::auth::permissions::{require, Permission};
+use crate::AppState;
+use axum::{
+    routing::{delete, get, post},
+    Router,
+};
+
+/// Create framework version routes
+pub fn create_framework_version_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/",
+            require(Permission::ManageFramework, post(create_framework_version)).merge(require(
+                Permission::ViewFramework,
+                get(list_framework_versions),
+            )),
+        )
+        .route(
+            "/:id",
+            require(Permission::ViewFramework, get(get_framework_version)).merge(require(
+                Permission::ManageFramework,
+                delete(discard_framework_version),
+            )),
+        )
+        .route(
+            "/:id/publish",
+            require(Permission::ManageFramework, post(publish_framework_version)),
+        )
+}
@@ -1,11 +1,14 @@
 pub mod action_plan;
+pub mod analytics;
 pub mod api;
 pub mod assessment;
 pub mod common;
 pub mod dimension;
+pub mod framework_version;
 pub mod organization;
 pub mod recommendation;
 pub mod report;
+pub mod report_template;
 pub mod submissions;
 pub mod user;
 
@@ -1,35 +1,70 @@
 use axum::{
-    routing::{delete, get, post},
+    routing::{delete, get, post, put},
     Router,
 };
 
 use crate::api::handlers::invitation::*;
 use crate::api::handlers::{group::*, organization::*};
+use crate::auth::permissions::{require, Permission};
 use crate::AppState;
 
 pub fn create_organization_routes() -> Router<AppState> {
     Router::new()
-        .route("/", post(create_organization).get(get_organizations))
+        .route(
+            "/",
+            require(Permission::ManageOrganizations, post(create_organization)).merge(require(
+                Permission::ManageOrganizationStructure,
+                get(get_organizations),
+            )),
+        )
         .route(
             "/:org_id",
-            get(get_organization)
-                .put(update_organization)
-                .delete(delete_organization),
+            require(
+                Permission::ManageOrganizationStructure,
+                get(get_organization),
+            )
+            .merge(require(
+                Permission::ManageOrganizations,
+                put(update_organization).merge(delete(delete_organization)),
+            )),
+        )
+        .route(
+            "/:org_id/invitations",
+            require(
+                Permission::ManageOrganizations,
+                post(invite_user_to_organization),
+            ),
+        )
+        .route(
+            "/:org_id/members",
+            require(
+                Permission::ManageOrganizationStructure,
+                get(get_organization_members),
+            ),
         )
-        .route("/:org_id/invitations", post(invite_user_to_organization))
-        .route("/:org_id/members", get(get_organization_members))
         .route(
             "/:org_id/groups",
-            post(create_group).get(get_groups_by_organization),
+            require(
+                Permission::ManageOrganizationStructure,
+                post(create_group).merge(get(get_groups_by_organization)),
+            ),
         )
         .route(
             "/:org_id/dimensions",
-            post(assign_dimension_to_organization)
-                .get(get_organization_dimensions)
-                .put(update_organization_dimensions),
+            require(
+                Permission::ManageOrganizationStructure,
+                post(assign_dimension_to_organization).merge(put(update_organization_dimensions)),
+            )
+            .merge(require(
+                Permission::ViewFramework,
+                get(get_organization_dimensions),
+            )),
         )
         .route(
             "/:org_id/dimensions/:dimension_id",
-            delete(remove_dimension_from_organization),
+            require(
+                Permission::ManageOrganizationStructure,
+                delete(remove_dimension_from_organization),
+            ),
         )
 }
@@ -1,6 +1,6 @@
 use crate::AppState;
 use axum::{
-    routing::{get, post},
+    routing::{delete, get, post, put},
     Router,
 };
 
@@ -8,19 +8,30 @@ use crate::api::handlers::recommendation::{
     create_recommendation, delete_recommendation, get_recommendation, list_recommendations,
     list_recommendations_by_dimension, update_recommendation,
 };
+use crate::auth::permissions::{require, Permission};
 
 /// Create recommendation routes
 pub fn create_recommendation_routes() -> Router<AppState> {
     Router::new()
-        .route("/", post(create_recommendation).get(list_recommendations))
+        .route(
+            "/",
+            require(Permission::ManageFramework, post(create_recommendation)).merge(require(
+                Permission::ViewFramework,
+                get(list_recommendations),
+            )),
+        )
         .route(
             "/:id",
-            get(get_recommendation)
-                .put(update_recommendation)
-                .delete(delete_recommendation),
+            require(Permission::ViewFramework, get(get_recommendation)).merge(require(
+                Permission::ManageFramework,
+                put(update_recommendation).merge(delete(delete_recommendation)),
+            )),
         )
         .route(
             "/dimensions/:dimension_id/recommendations",
-            get(list_recommendations_by_dimension),
+            require(
+                Permission::ViewFramework,
+                get(list_recommendations_by_dimension),
+            ),
         )
 }
@@ -1,36 +1,90 @@
 use crate::AppState;
 use axum::{
-    routing::{get, post},
+    extract::DefaultBodyLimit,
+    routing::{delete, get, post, put},
     Router,
 };
 
 use crate::api::handlers::report::{
-    delete_report, download_latest_report_by_assessment, download_report, generate_report,
-    get_report, get_report_status, list_reports, list_reports_by_assessment, update_report,
+    create_download_link, delete_report, download_latest_report_by_assessment, download_report,
+    generate_portfolio_report, generate_report, get_report, get_report_status, list_reports,
+    list_reports_by_assessment, update_report, verify_report_file,
 };
+use crate::auth::permissions::{require, Permission};
+
+/// Largest report file accepted for verification
+const MAX_VERIFIED_FILE_BYTES: usize = 100 * 1024 * 1024;
 
 /// Create report routes
 pub fn create_report_routes() -> Router<AppState> {
     Router::new()
         // Report CRUD operations
-        .route("/", post(generate_report))
-        .route("/", get(list_reports))
-        .route("/:id", get(get_report))
-        .route("/:id", axum::routing::put(update_report))
-        .route("/:id", axum::routing::delete(delete_report))
-        .route("/:id/status", get(get_report_status))
-        .route("/:id/download", get(download_report))
+        .route(
+            "/",
+            require(Permission::ManageReports, post(generate_report)),
+        )
+        .route("/", require(Permission::ViewReports, get(list_reports)))
+        .route(
+            "/portfolio",
+            require(
+                Permission::ManagePortfolioReports,
+                post(generate_portfolio_report),
+            ),
+        )
+        .route("/:id", require(Permission::ViewReports, get(get_report)))
+        .route(
+            "/:id",
+            require(Permission::ManageReports, put(update_report)),
+        )
+        .route(
+            "/:id",
+            require(Permission::ManageReports, delete(delete_report)),
+        )
+        .route(
+            "/:id/status",
+            require(Permission::ViewReports, get(get_report_status)),
+        )
+        .route(
+            "/:id/download",
+            require(Permission::ViewReports, get(download_report)),
+        )
+        .route(
+            "/:id/download-link",
+            require(Permission::ViewReports, get(create_download_link)),
+        )
+        .route(
+            "/:id/verify",
+            require(
+                Permission::ViewReports,
+                post(verify_report_file).layer(DefaultBodyLimit::max(MAX_VERIFIED_FILE_BYTES)),
+            ),
+        )
         .route(
             "/:id/file",
-            get(crate::api::handlers::report::serve_report_file),
+            require(
+                Permission::ViewReports,
+                get(crate::api::handlers::report::serve_report_file),
+            ),
         )
         // Assessment-specific reports
         .route(
             "/assessment/:assessment_id",
-            get(list_reports_by_assessment),
+            require(Permission::ViewReports, get(list_reports_by_assessment)),
         )
         .route(
             "/assessment/:assessment_id/download",
-            get(download_latest_report_by_assessment),
+            require(
+                Permission::ViewReports,
+                get(download_latest_report_by_assessment),
+            ),
         )
 }
+
+/// Report routes authorized by a signed link instead of a token, mounted
+/// outside the authentication middleware
+pub fn create_public_report_routes() -> Router<AppState> {
+    Router::new().route(
+        "/public/reports/:id/file",
+        get(crate::api::handlers::report::serve_signed_report_file),
+    )
+}
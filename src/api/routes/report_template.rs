@@ -0,0 +1,36 @@
+use crate::api::handlers::report_template::{
+    activate_report_template, create_report_template, get_report_template, list_report_templates,
+};
+use crate::auth::permissions::{require, Permission};
+use crate::AppState;
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+/// Create report template routes
+pub fn create_report_template_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/",
+            require(
+                Permission::ManageReportTemplates,
+                post(create_report_template),
+            )
+            .merge(require(
+                Permission::ManageReportTemplates,
+                get(list_report_templates),
+            )),
+        )
+        .route(
+            "/:id",
+            require(Permission::ManageReportTemplates, get(get_report_template)),
+        )
+        .route(
+            "/:id/activate",
+            require(
+                Permission::ManageReportTemplates,
+                post(activate_report_template),
+            ),
+        )
+}
@@ -1,7 +1,11 @@
 use crate::api::handlers::submission::submit_assessment;
+use crate::auth::permissions::{require, Permission};
 use crate::AppState;
 use axum::{routing::post, Router};
 
 pub fn create_submission_routes() -> Router<AppState> {
-    Router::new().route("/submit", post(submit_assessment))
+    Router::new().route(
+        "/submit",
+        require(Permission::SubmitAssessments, post(submit_assessment)),
+    )
 }
@@ -1,6 +1,13 @@
-use crate::{api::handlers::user, AppState};
+use crate::{
+    api::handlers::user,
+    auth::permissions::{require, Permission},
+    AppState,
+};
 use axum::{routing::delete, Router};
 
 pub fn user_routes() -> Router<AppState> {
-    Router::new().route("/:user_id", delete(user::delete_user))
+    Router::new().route(
+        "/:user_id",
+        require(Permission::ManageOrganizations, delete(user::delete_user)),
+    )
 }
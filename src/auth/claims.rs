@@ -65,6 +65,16 @@ impl Claims {
         }
         false
     }
+
+    pub fn has_resource_role(&self, role: &str) -> bool {
+        if let Some(resource_access) = &self.resource_access {
+            return resource_access
+                .values()
+                .any(|access| access.roles.iter().any(|r| r == role));
+        }
+        false
+    }
+
     pub fn get_organization_id(&self) -> Option<String> {
         self.organization_id.clone()
     }
@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use axum::{extract::FromRequestParts, http::request::Parts};
+
+use crate::auth::claims::Claims;
+use crate::auth::permissions::Role;
+use crate::error::AppError;
+use crate::AppState;
+
+/// Cooperations the caller may see and edit.
+///
+/// Organization admins and `dgrv_admin` are not restricted at this level (the
+/// organization boundary is enforced by `TenantContext`). Coop admins and coop
+/// users are limited to the cooperation groups they are members of.
+#[derive(Debug, Clone)]
+pub enum CooperationScope {
+    Unrestricted,
+    Restricted(Vec<String>),
+}
+
+impl CooperationScope {
+    pub fn is_restricted(&self) -> bool {
+        matches!(self, CooperationScope::Restricted(_))
+    }
+
+    /// Whether data belonging to `cooperation_id` is visible. Organization-level
+    /// data (no cooperation) is only visible to unrestricted callers.
+    pub fn can_access(&self, cooperation_id: Option<&str>) -> bool {
+        match self {
+            CooperationScope::Unrestricted => true,
+            CooperationScope::Restricted(ids) => {
+                cooperation_id.is_some_and(|id| ids.iter().any(|own| own == id))
+            }
+        }
+    }
+
+    pub fn ensure(&self, cooperation_id: Option<&str>) -> Result<(), AppError> {
+        if self.can_access(cooperation_id) {
+            Ok(())
+        } else {
+            Err(AppError::Forbidden(
+                "Access to another cooperation is not allowed".to_string(),
+            ))
+        }
+    }
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for CooperationScope {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let claims = Claims::from_request_parts(parts, state).await?;
+
+        let is_coop_member = claims.has_role(Role::CoopAdmin) || claims.has_role(Role::CoopUser);
+        if !is_coop_member || claims.has_role(Role::DgrvAdmin) || claims.has_role(Role::OrgAdmin) {
+            return Ok(CooperationScope::Unrestricted);
+        }
+
+        let organization_id = claims.get_organization_id().ok_or_else(|| {
+            AppError::Forbidden("Token is not bound to an organization".to_string())
+        })?;
+        let cooperation_ids = state
+            .cooperation_membership
+            .cooperation_ids(&organization_id, &claims.subject)
+            .await?;
+
+        Ok(CooperationScope::Restricted(cooperation_ids))
+    }
+}
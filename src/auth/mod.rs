@@ -1,3 +1,6 @@
 pub mod claims;
+pub mod cooperation;
 pub mod jwt_validator;
 pub mod middleware;
+pub mod permissions;
+pub mod tenant;
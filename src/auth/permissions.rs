@@ -0,0 +1,257 @@
+use axum::{
+    extract::{Request, State},
+    middleware::{self, Next},
+    response::Response,
+    routing::MethodRouter,
+};
+
+use crate::auth::claims::Claims;
+use crate::error::AppError;
+
+/// Roles issued by Keycloak, either as realm roles or as client roles in `resource_access`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    DgrvAdmin,
+    OrgAdmin,
+    CoopAdmin,
+    CoopUser,
+}
+
+impl Role {
+    pub const ALL: [Role; 4] = [
+        Role::DgrvAdmin,
+        Role::OrgAdmin,
+        Role::CoopAdmin,
+        Role::CoopUser,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::DgrvAdmin => "dgrv_admin",
+            Role::OrgAdmin => "org_admin",
+            Role::CoopAdmin => "coop_admin",
+            Role::CoopUser => "coop_user",
+        }
+    }
+}
+
+/// Actions guarded by the authorization layer.
+///
+/// Each route declares the permission it needs; the mapping from permission
+/// to roles lives in [`Permission::allowed_roles`] so the whole matrix can be
+/// reviewed in one place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    /// Create, update and delete organizations, users and invitations
+    ManageOrganizations,
+    /// Manage groups (cooperations), their members and the dimensions assigned to an organization
+    ManageOrganizationStructure,
+    /// List and add members of a cooperation group
+    ManageCooperationMembers,
+    /// Read dimensions, states, gaps and recommendations
+    ViewFramework,
+    /// Create, update and delete dimensions, states, gaps and recommendations
+    ManageFramework,
+    /// Read assessments, their dimension assessments, gaps and summaries
+    ViewAssessments,
+    /// Create, update and delete assessments
+    ManageAssessments,
+    /// Create and update dimension assessments (answering the questionnaire)
+    AnswerAssessments,
+    /// Submit a completed assessment
+    SubmitAssessments,
+    /// Read and download reports
+    ViewReports,
+    /// Generate, update and delete reports
+    ManageReports,
+    /// Upload and activate report templates and branding
+    ManageReportTemplates,
+    /// Generate organization-wide portfolio reports
+    ManagePortfolioReports,
+    /// Read action plans
+    ViewActionPlans,
+    /// Create, update and delete action items
+    ManageActionPlans,
+    /// Read anonymized benchmarks across all organizations
+    ViewBenchmarks,
+}
+
+impl Permission {
+    pub fn allowed_roles(&self) -> &'static [Role] {
+        use Role::*;
+        match self {
+            Permission::ManageOrganizations => &[DgrvAdmin],
+            Permission::ManageOrganizationStructure => &[DgrvAdmin, OrgAdmin],
+            Permission::ManageCooperationMembers => &[DgrvAdmin, OrgAdmin, CoopAdmin],
+            Permission::ViewFramework => &Role::ALL,
+            Permission::ManageFramework => &[DgrvAdmin],
+            Permission::ViewAssessments => &Role::ALL,
+            Permission::ManageAssessments => &[DgrvAdmin, OrgAdmin],
+            Permission::AnswerAssessments => &[OrgAdmin, CoopAdmin, CoopUser],
+            Permission::SubmitAssessments => &[OrgAdmin, CoopAdmin],
+            Permission::ViewReports => &Role::ALL,
+            Permission::ManageReports => &[DgrvAdmin, OrgAdmin, CoopAdmin],
+            Permission::ManageReportTemplates => &[DgrvAdmin, OrgAdmin],
+            Permission::ManagePortfolioReports => &[DgrvAdmin, OrgAdmin],
+            Permission::ViewActionPlans => &Role::ALL,
+            Permission::ManageActionPlans => &[DgrvAdmin, OrgAdmin, CoopAdmin],
+            Permission::ViewBenchmarks => &[DgrvAdmin],
+        }
+    }
+}
+
+impl Claims {
+    pub fn has_role(&self, role: Role) -> bool {
+        self.has_realm_role(role.as_str()) || self.has_resource_role(role.as_str())
+    }
+
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        permission
+            .allowed_roles()
+            .iter()
+            .any(|role| self.has_role(*role))
+    }
+
+    /// DGRV and organization administrators, e.g. the only ones who may
+    /// reopen a completed assessment
+    pub fn is_admin(&self) -> bool {
+        self.has_role(Role::DgrvAdmin) || self.has_role(Role::OrgAdmin)
+    }
+
+    /// Whether the caller may grant the realm role `role` to another user.
+    ///
+    /// Nobody can grant a role above their own: coop admins only hand out the
+    /// cooperation roles, organization admins everything but `dgrv_admin`.
+    pub fn can_assign_role(&self, role: &str) -> bool {
+        let assignable: &[Role] = if self.has_role(Role::DgrvAdmin) {
+            &Role::ALL
+        } else if self.has_role(Role::OrgAdmin) {
+            &[Role::OrgAdmin, Role::CoopAdmin, Role::CoopUser]
+        } else if self.has_role(Role::CoopAdmin) {
+            &[Role::CoopAdmin, Role::CoopUser]
+        } else {
+            &[]
+        };
+        assignable
+            .iter()
+            .any(|assignable| assignable.as_str() == role)
+    }
+
+    pub fn require_permission(&self, permission: Permission) -> Result<(), AppError> {
+        if self.has_permission(permission) {
+            Ok(())
+        } else {
+            Err(AppError::Forbidden(format!(
+                "Missing permission {:?}",
+                permission
+            )))
+        }
+    }
+}
+
+/// Guard a method router with a permission check.
+///
+/// The check runs after `auth_middleware` (applied to the whole API router),
+/// so the claims are already in the request extensions:
+///
+/// ```ignore
+/// .route("/dimensions", require(Permission::ManageFramework, post(create_dimension)))
+/// ```
+pub fn require<S>(permission: Permission, method_router: MethodRouter<S>) -> MethodRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    method_router.route_layer(middleware::from_fn_with_state(
+        permission,
+        require_permission,
+    ))
+}
+
+/// Middleware rejecting the request with 403 unless the caller holds `permission`.
+pub async fn require_permission(
+    State(permission): State<Permission>,
+    claims: Claims,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    claims.require_permission(permission)?;
+    Ok(next.run(request).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::claims::RealmAccess;
+    use std::collections::HashMap;
+
+    fn claims_with(realm_roles: &[&str], client_roles: &[&str]) -> Claims {
+        let mut resource_access = HashMap::new();
+        resource_access.insert(
+            "dgat-client".to_string(),
+            RealmAccess {
+                roles: client_roles.iter().map(|r| r.to_string()).collect(),
+            },
+        );
+        Claims {
+            subject: "user".to_string(),
+            realm_access: Some(RealmAccess {
+                roles: realm_roles.iter().map(|r| r.to_string()).collect(),
+            }),
+            resource_access: Some(resource_access),
+            preferred_username: "user".to_string(),
+            email: "user@example.com".to_string(),
+            name: "User".to_string(),
+            organization_id: None,
+        }
+    }
+
+    #[test]
+    fn test_dgrv_admin_manages_framework() {
+        let claims = claims_with(&["dgrv_admin"], &[]);
+        assert!(claims.has_permission(Permission::ManageFramework));
+        assert!(claims.has_permission(Permission::ManageOrganizations));
+        assert!(claims.is_admin());
+    }
+
+    #[test]
+    fn test_coop_user_is_read_only_outside_answering() {
+        let claims = claims_with(&["coop_user"], &[]);
+        assert!(claims.has_permission(Permission::ViewAssessments));
+        assert!(claims.has_permission(Permission::AnswerAssessments));
+        assert!(!claims.has_permission(Permission::SubmitAssessments));
+        assert!(!claims.has_permission(Permission::ManageAssessments));
+        assert!(!claims.is_admin());
+        assert!(claims
+            .require_permission(Permission::ManageFramework)
+            .is_err());
+    }
+
+    #[test]
+    fn test_client_roles_are_honoured() {
+        let claims = claims_with(&[], &["org_admin"]);
+        assert!(claims.has_role(Role::OrgAdmin));
+        assert!(claims.has_permission(Permission::ManageAssessments));
+    }
+
+    #[test]
+    fn test_roles_cannot_be_escalated() {
+        let coop_admin = claims_with(&["coop_admin"], &[]);
+        assert!(coop_admin.can_assign_role("coop_user"));
+        assert!(coop_admin.can_assign_role("coop_admin"));
+        assert!(!coop_admin.can_assign_role("org_admin"));
+        assert!(!coop_admin.can_assign_role("dgrv_admin"));
+
+        let org_admin = claims_with(&["org_admin"], &[]);
+        assert!(org_admin.can_assign_role("org_admin"));
+        assert!(!org_admin.can_assign_role("dgrv_admin"));
+
+        assert!(claims_with(&["dgrv_admin"], &[]).can_assign_role("dgrv_admin"));
+        assert!(!claims_with(&["coop_user"], &[]).can_assign_role("coop_user"));
+    }
+
+    #[test]
+    fn test_no_roles_grants_nothing() {
+        let claims = claims_with(&[], &[]);
+        assert!(!claims.has_permission(Permission::ViewAssessments));
+    }
+}
@@ -0,0 +1,236 @@
+use async_trait::async_trait;
+use axum::{extract::FromRequestParts, http::request::Parts};
+use sea_orm::sea_query::Query;
+use sea_orm::{ColumnTrait, Condition};
+
+use crate::auth::claims::Claims;
+use crate::auth::cooperation::CooperationScope;
+use crate::auth::permissions::Role;
+use crate::entities::assessments;
+use crate::error::AppError;
+use crate::AppState;
+
+/// Organization boundary of the current request.
+///
+/// Built from the token's `organization_id` claim and passed to the
+/// repositories, which turn it into query conditions. A `dgrv_admin` gets an
+/// unrestricted context and can read across organizations. Coop admins and
+/// coop users are further limited to the assessments of their cooperations.
+#[derive(Debug, Clone)]
+pub struct TenantContext {
+    organization_id: Option<String>,
+    cooperation_ids: Option<Vec<String>>,
+}
+
+impl TenantContext {
+    pub fn unrestricted() -> Self {
+        Self {
+            organization_id: None,
+            cooperation_ids: None,
+        }
+    }
+
+    pub fn for_organization(organization_id: impl Into<String>) -> Self {
+        Self {
+            organization_id: Some(organization_id.into()),
+            cooperation_ids: None,
+        }
+    }
+
+    /// Narrow the context to the cooperations of `scope`
+    pub fn within(mut self, scope: &CooperationScope) -> Self {
+        if let CooperationScope::Restricted(cooperation_ids) = scope {
+            self.cooperation_ids = Some(cooperation_ids.clone());
+        }
+        self
+    }
+
+    pub fn from_claims(claims: &Claims) -> Result<Self, AppError> {
+        if claims.has_role(Role::DgrvAdmin) {
+            return Ok(Self::unrestricted());
+        }
+
+        claims
+            .get_organization_id()
+            .filter(|id| !id.is_empty())
+            .map(Self::for_organization)
+            .ok_or_else(|| AppError::Forbidden("Token is not bound to an organization".to_string()))
+    }
+
+    pub fn is_unrestricted(&self) -> bool {
+        self.organization_id.is_none()
+    }
+
+    pub fn organization_id(&self) -> Option<&str> {
+        self.organization_id.as_deref()
+    }
+
+    pub fn is_cooperation_restricted(&self) -> bool {
+        self.cooperation_ids.is_some()
+    }
+
+    pub fn can_access_organization(&self, organization_id: &str) -> bool {
+        match &self.organization_id {
+            Some(own) => own == organization_id,
+            None => true,
+        }
+    }
+
+    pub fn ensure_organization(&self, organization_id: &str) -> Result<(), AppError> {
+        if self.can_access_organization(organization_id) {
+            Ok(())
+        } else {
+            Err(AppError::Forbidden(
+                "Access to another organization is not allowed".to_string(),
+            ))
+        }
+    }
+
+    /// Whether the Keycloak group at `group_path` belongs to the caller's
+    /// organization. Cooperation groups are named `"{organization_id}-{name}"`.
+    pub fn owns_group(&self, group_path: &str) -> bool {
+        match &self.organization_id {
+            Some(own) => group_path
+                .trim_start_matches('/')
+                .strip_prefix(own.as_str())
+                .is_some_and(|rest| rest.starts_with('-')),
+            None => true,
+        }
+    }
+
+    pub fn ensure_group(&self, group_path: &str) -> Result<(), AppError> {
+        if self.owns_group(group_path) {
+            Ok(())
+        } else {
+            Err(AppError::Forbidden(
+                "Group does not belong to your organization".to_string(),
+            ))
+        }
+    }
+
+    /// Condition restricting `organization_column` to the caller's organization.
+    pub fn organization_condition<C: ColumnTrait>(&self, organization_column: C) -> Condition {
+        match &self.organization_id {
+            Some(organization_id) => {
+                Condition::all().add(organization_column.eq(organization_id.clone()))
+            }
+            None => Condition::all(),
+        }
+    }
+
+    /// Condition restricting the assessments table to the assessments the
+    /// caller may see: those of their organization and, for coop members,
+    /// of their cooperations.
+    pub fn assessments_condition(&self) -> Condition {
+        let condition = self.organization_condition(assessments::Column::OrganizationId);
+        match &self.cooperation_ids {
+            Some(cooperation_ids) => {
+                condition.add(assessments::Column::CooperationId.is_in(cooperation_ids.clone()))
+            }
+            None => condition,
+        }
+    }
+
+    /// Condition restricting `assessment_column` to assessments the caller may
+    /// see. Used for tables that only reference an assessment (reports, action
+    /// plans, dimension assessments).
+    pub fn assessment_condition<C: ColumnTrait>(&self, assessment_column: C) -> Condition {
+        if self.organization_id.is_none() {
+            return Condition::all();
+        }
+        Condition::all().add(
+            assessment_column.in_subquery(
+                Query::select()
+                    .column(assessments::Column::AssessmentId)
+                    .from(assessments::Entity)
+                    .cond_where(self.assessments_condition())
+                    .to_owned(),
+            ),
+        )
+    }
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for TenantContext {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let claims = Claims::from_request_parts(parts, state).await?;
+        let tenant = Self::from_claims(&claims)?;
+        let scope = CooperationScope::from_request_parts(parts, state).await?;
+        Ok(tenant.within(&scope))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::claims::RealmAccess;
+
+    fn claims(roles: &[&str], organization_id: Option<&str>) -> Claims {
+        Claims {
+            subject: "user".to_string(),
+            realm_access: Some(RealmAccess {
+                roles: roles.iter().map(|r| r.to_string()).collect(),
+            }),
+            resource_access: None,
+            preferred_username: "user".to_string(),
+            email: "user@example.com".to_string(),
+            name: "User".to_string(),
+            organization_id: organization_id.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_dgrv_admin_is_unrestricted() {
+        let tenant = TenantContext::from_claims(&claims(&["dgrv_admin"], None)).unwrap();
+        assert!(tenant.is_unrestricted());
+        assert!(tenant.ensure_organization("any-org").is_ok());
+    }
+
+    #[test]
+    fn test_org_user_is_bound_to_own_organization() {
+        let tenant = TenantContext::from_claims(&claims(&["org_admin"], Some("org-a"))).unwrap();
+        assert_eq!(tenant.organization_id(), Some("org-a"));
+        assert!(tenant.ensure_organization("org-a").is_ok());
+        assert!(matches!(
+            tenant.ensure_organization("org-b"),
+            Err(AppError::Forbidden(_))
+        ));
+    }
+
+    #[test]
+    fn test_groups_are_matched_by_exact_prefix() {
+        let tenant = TenantContext::for_organization("org");
+        assert!(tenant.owns_group("/org-coop"));
+        assert!(tenant.owns_group("/org-coop/team"));
+        assert!(!tenant.owns_group("/otherorg-coop"));
+        assert!(!tenant.owns_group("/org2-coop"));
+        assert!(!tenant.owns_group("/org"));
+        assert!(matches!(
+            tenant.ensure_group("/other-org-coop"),
+            Err(AppError::Forbidden(_))
+        ));
+        assert!(TenantContext::unrestricted().owns_group("/other-coop"));
+    }
+
+    #[test]
+    fn test_coop_member_is_limited_to_own_cooperations() {
+        let tenant = TenantContext::for_organization("org-a")
+            .within(&CooperationScope::Restricted(vec!["coop-a".to_string()]));
+        assert!(tenant.is_cooperation_restricted());
+        assert_eq!(tenant.organization_id(), Some("org-a"));
+
+        let org_admin =
+            TenantContext::for_organization("org-a").within(&CooperationScope::Unrestricted);
+        assert!(!org_admin.is_cooperation_restricted());
+    }
+
+    #[test]
+    fn test_missing_organization_claim_is_rejected() {
+        assert!(TenantContext::from_claims(&claims(&["coop_user"], None)).is_err());
+    }
+}
@@ -2,7 +2,15 @@ use dgat_backend::{
     auth::jwt_validator::JwtValidator,
     config, database,
     entities::{current_states, desired_states, dimensions, gaps, recommendations},
-    services::{keycloak::KeycloakService, report_service::ReportService},
+    repositories::{
+        dimensions::DimensionsRepository, framework_versions::FrameworkVersionsRepository,
+    },
+    services::{
+        browser_pool::BrowserPool, cooperation_membership::CooperationMembershipService,
+        framework_versioning::FrameworkVersioningService, keycloak::KeycloakService,
+        report_queue::ReportQueue, report_service::ReportService,
+        report_templates::ReportTemplateService,
+    },
     AppState,
 };
 use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, Set};
@@ -16,24 +24,65 @@ async fn main() -> anyhow::Result<()> {
     let db = Arc::new(database::init_db(&config.database_url).await?);
     let keycloak_service = Arc::new(KeycloakService::new(config.clone()));
     let jwt_validator = Arc::new(JwtValidator::new(config.keycloak.clone()));
-    let report_service = Arc::new(ReportService::new(&config.minio, db.clone()).await?);
+    let browser_pool = Arc::new(BrowserPool::new(config.browser_pool.clone()));
+    let report_templates = Arc::new(ReportTemplateService::new(db.clone())?);
+    let report_service = Arc::new(
+        ReportService::from_config(&config, db.clone())
+            .await?
+            .with_pdf_backend(config.pdf_backend)
+            .with_download_links(config.download_links.clone())
+            .with_browser_pool(browser_pool.clone())
+            .with_report_templates(report_templates.clone()),
+    );
+    let report_queue = Arc::new(ReportQueue::new(
+        db.clone(),
+        report_service.clone(),
+        config.report_queue.clone(),
+    ));
+
+    let cooperation_membership =
+        Arc::new(CooperationMembershipService::new(keycloak_service.clone()));
 
     let state = AppState {
         db: db.clone(),
         keycloak_service,
         jwt_validator,
         report_service,
+        report_queue,
+        browser_pool,
+        report_templates,
+        cooperation_membership,
     };
 
-    seed_dimensions(state.db.as_ref()).await?;
-    seed_states(state.db.as_ref()).await?;
-    seed_recommendations(state.db.as_ref()).await?;
-    seed_gaps(state.db.as_ref()).await?;
+    // The framework is seeded into a draft version, which is then published
+    let draft = match FrameworkVersionsRepository::find_draft(state.db.as_ref()).await? {
+        Some(draft) => draft,
+        None => {
+            FrameworkVersioningService::create_draft(
+                state.db.as_ref(),
+                Some("Seed data".to_string()),
+                None,
+            )
+            .await?
+        }
+    };
+    let version_id = draft.framework_version_id;
+
+    seed_dimensions(state.db.as_ref(), version_id).await?;
+    seed_states(state.db.as_ref(), version_id).await?;
+    seed_recommendations(state.db.as_ref(), version_id).await?;
+    seed_gaps(state.db.as_ref(), version_id).await?;
+
+    FrameworkVersioningService::publish(state.db.as_ref(), version_id).await?;
+    println!("Framework version {} published.", draft.version_number);
 
     Ok(())
 }
 
-async fn seed_dimensions(db: &sea_orm::DatabaseConnection) -> anyhow::Result<()> {
+async fn seed_dimensions(
+    db: &sea_orm::DatabaseConnection,
+    framework_version_id: Uuid,
+) -> anyhow::Result<()> {
     println!("Seeding dimensions...");
 
     let dimensions_to_seed = vec![
@@ -69,15 +118,19 @@ async fn seed_dimensions(db: &sea_orm::DatabaseConnection) -> anyhow::Result<()>
 
     for (name, description) in dimensions_to_seed {
         let existing_dimension = dimensions::Entity::find()
+            .filter(dimensions::Column::FrameworkVersionId.eq(framework_version_id))
             .filter(dimensions::Column::Name.eq(name))
             .one(db)
             .await?;
 
         if existing_dimension.is_none() {
+            let dimension_id = Uuid::new_v4();
             let new_dimension = dimensions::ActiveModel {
-                dimension_id: Set(Uuid::new_v4()),
+                dimension_id: Set(dimension_id),
                 name: Set(name.to_owned()),
                 description: Set(Some(description.to_owned())),
+                framework_version_id: Set(framework_version_id),
+                origin_dimension_id: Set(dimension_id),
                 ..Default::default()
             };
 
@@ -93,10 +146,14 @@ async fn seed_dimensions(db: &sea_orm::DatabaseConnection) -> anyhow::Result<()>
     Ok(())
 }
 
-async fn seed_states(db: &sea_orm::DatabaseConnection) -> anyhow::Result<()> {
+async fn seed_states(
+    db: &sea_orm::DatabaseConnection,
+    framework_version_id: Uuid,
+) -> anyhow::Result<()> {
     println!("Seeding current and desired states...");
 
-    let dimensions = dimensions::Entity::find().all(db).await?;
+    let dimensions =
+        DimensionsRepository::find_by_framework_version(db, framework_version_id).await?;
 
     for dimension in dimensions {
         // Clear existing states for the dimension
@@ -172,10 +229,14 @@ fn get_states_for_dimension(_dimension_name: &str) -> Vec<(bool, &str, i32)> {
     ]
 }
 
-async fn seed_recommendations(db: &sea_orm::DatabaseConnection) -> anyhow::Result<()> {
+async fn seed_recommendations(
+    db: &sea_orm::DatabaseConnection,
+    framework_version_id: Uuid,
+) -> anyhow::Result<()> {
     println!("Seeding recommendations...");
 
-    let dimensions = dimensions::Entity::find().all(db).await?;
+    let dimensions =
+        DimensionsRepository::find_by_framework_version(db, framework_version_id).await?;
 
     for dimension in dimensions {
         // Clear existing recommendations for the dimension
@@ -223,10 +284,14 @@ fn get_recommendations_for_dimension(
     ]
 }
 
-async fn seed_gaps(db: &sea_orm::DatabaseConnection) -> anyhow::Result<()> {
+async fn seed_gaps(
+    db: &sea_orm::DatabaseConnection,
+    framework_version_id: Uuid,
+) -> anyhow::Result<()> {
     println!("Seeding gaps...");
 
-    let dimensions = dimensions::Entity::find().all(db).await?;
+    let dimensions =
+        DimensionsRepository::find_by_framework_version(db, framework_version_id).await?;
 
     for dimension in dimensions {
         // Clear existing gaps for the dimension
@@ -1,5 +1,10 @@
+use crate::entities::reports::ReportType;
 use envconfig::Envconfig;
+use sea_orm::ActiveEnum;
 use serde::Deserialize;
+use std::collections::HashMap;
+use std::str::FromStr;
+use tracing::warn;
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct Config {
@@ -11,6 +16,13 @@ pub struct Config {
     pub jwt_secret: String,
     pub keycloak_admin_token: String,
     pub minio: MinioConfig,
+    pub storage_backend: StorageBackend,
+    pub local_storage: LocalStorageConfig,
+    pub download_links: DownloadLinkConfig,
+    pub report_queue: ReportQueueConfig,
+    pub report_retention: ReportRetentionConfig,
+    pub pdf_backend: PdfBackend,
+    pub browser_pool: BrowserPoolConfig,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -31,6 +43,186 @@ pub struct MinioConfig {
     pub use_ssl: bool,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+pub struct LocalStorageConfig {
+    /// Directory report files are written under
+    pub root_dir: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct DownloadLinkConfig {
+    /// Key signing backend download links
+    pub secret: String,
+    /// Public URL of the API, prefixed to backend download links
+    pub base_url: String,
+    /// Lifetime of a link unless the caller asks for another
+    pub expiry_secs: u64,
+    /// Longest lifetime a caller may ask for
+    pub max_expiry_secs: u64,
+}
+
+impl Default for DownloadLinkConfig {
+    /// Links signed with a random key, valid until restart
+    fn default() -> Self {
+        Self {
+            secret: hex::encode(rand::random::<[u8; 32]>()),
+            base_url: "http://localhost:3001".to_string(),
+            expiry_secs: 15 * 60,
+            max_expiry_secs: 7 * 24 * 60 * 60,
+        }
+    }
+}
+
+/// Where generated report files are stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum StorageBackend {
+    /// S3 compatible object storage such as MinIO
+    #[default]
+    S3,
+    /// Files on the local disk, no object storage required
+    Local,
+}
+
+impl FromStr for StorageBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "s3" | "minio" => Ok(StorageBackend::S3),
+            "local" => Ok(StorageBackend::Local),
+            _ => Err(format!("Invalid storage backend: {s}")),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ReportQueueConfig {
+    /// Number of reports generated concurrently
+    pub workers: usize,
+    /// Attempts before a report is marked as failed
+    pub max_attempts: i32,
+    /// Delay before the first retry, doubled on every further attempt
+    pub retry_backoff_secs: u64,
+    /// How often idle workers look for due retries
+    pub poll_interval_secs: u64,
+    /// How long a generating report stays claimed without progress before
+    /// another worker may take it over
+    pub lease_secs: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ReportRetentionConfig {
+    pub policies: RetentionPolicies,
+    /// How often expired reports and orphaned files are swept, 0 disables
+    /// the sweeper
+    pub sweep_interval_secs: u64,
+    /// Age below which a file without a report is assumed to be still
+    /// uploading
+    pub orphan_grace_secs: u64,
+}
+
+/// When reports of one type are deleted. Unset limits keep reports forever.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub struct RetentionPolicy {
+    /// Delete reports older than this many days
+    pub max_age_days: Option<u32>,
+    /// Keep only this many of the latest reports per assessment, or per
+    /// organization for portfolio reports
+    pub keep_latest: Option<usize>,
+}
+
+/// Retention policies by report type, e.g.
+/// `summary:keep=5;detailed:days=90,keep=3`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct RetentionPolicies(HashMap<String, RetentionPolicy>);
+
+impl RetentionPolicies {
+    pub fn for_type(&self, report_type: &ReportType) -> Option<&RetentionPolicy> {
+        self.0.get(&report_type.to_value())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromStr for RetentionPolicies {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut policies = HashMap::new();
+        for entry in s
+            .split(';')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            let (report_type, rules) = entry
+                .split_once(':')
+                .ok_or_else(|| format!("Invalid retention policy: {entry}"))?;
+            let report_type = ReportType::try_from_value(&report_type.trim().to_string())
+                .map_err(|_| format!("Invalid report type in retention policy: {entry}"))?;
+
+            let mut policy = RetentionPolicy::default();
+            for rule in rules.split(',').map(str::trim) {
+                let invalid = || format!("Invalid retention rule: {rule}");
+                match rule.split_once('=') {
+                    Some(("days", days)) => {
+                        policy.max_age_days = Some(days.parse().map_err(|_| invalid())?)
+                    }
+                    Some(("keep", keep)) => {
+                        policy.keep_latest = Some(keep.parse().map_err(|_| invalid())?)
+                    }
+                    _ => return Err(invalid()),
+                }
+            }
+            policies.insert(report_type.to_value(), policy);
+        }
+        Ok(Self(policies))
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct BrowserPoolConfig {
+    /// Maximum number of Chrome instances rendering at once
+    pub size: usize,
+    /// Renders after which a browser is restarted
+    pub max_renders: usize,
+    /// Time allowed for a single HTML to PDF conversion
+    pub render_timeout_secs: u64,
+}
+
+impl Default for BrowserPoolConfig {
+    fn default() -> Self {
+        Self {
+            size: 2,
+            max_renders: 50,
+            render_timeout_secs: 60,
+        }
+    }
+}
+
+/// How PDF reports are rendered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum PdfBackend {
+    /// HTML templates printed by headless Chrome
+    #[default]
+    Chrome,
+    /// Drawn directly with `printpdf`, no browser required
+    Native,
+}
+
+impl FromStr for PdfBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "chrome" => Ok(PdfBackend::Chrome),
+            "native" => Ok(PdfBackend::Native),
+            _ => Err(format!("Invalid PDF backend: {s}")),
+        }
+    }
+}
+
 #[derive(Envconfig)]
 struct ConfigEnv {
     #[envconfig(
@@ -81,6 +273,70 @@ struct ConfigEnv {
 
     #[envconfig(from = "DGAT_MINIO_USE_SSL", default = "false")]
     minio_use_ssl: bool,
+
+    // Report storage
+    /// `s3` or `local`
+    #[envconfig(from = "DGAT_STORAGE_BACKEND", default = "s3")]
+    storage_backend: StorageBackend,
+
+    #[envconfig(from = "DGAT_LOCAL_STORAGE_ROOT", default = "data/reports")]
+    local_storage_root: String,
+
+    // Download links
+    /// A random key, valid until restart, when unset
+    #[envconfig(from = "DGAT_DOWNLOAD_LINK_SECRET")]
+    download_link_secret: Option<String>,
+
+    /// Defaults to the server URL
+    #[envconfig(from = "DGAT_DOWNLOAD_LINK_BASE_URL")]
+    download_link_base_url: Option<String>,
+
+    #[envconfig(from = "DGAT_DOWNLOAD_LINK_EXPIRY_SECS", default = "900")]
+    download_link_expiry_secs: u64,
+
+    /// S3 presigned URLs are limited to 7 days
+    #[envconfig(from = "DGAT_DOWNLOAD_LINK_MAX_EXPIRY_SECS", default = "604800")]
+    download_link_max_expiry_secs: u64,
+
+    // Report generation
+    #[envconfig(from = "DGAT_REPORT_WORKERS", default = "2")]
+    report_workers: usize,
+
+    #[envconfig(from = "DGAT_REPORT_MAX_ATTEMPTS", default = "3")]
+    report_max_attempts: i32,
+
+    #[envconfig(from = "DGAT_REPORT_RETRY_BACKOFF_SECS", default = "30")]
+    report_retry_backoff_secs: u64,
+
+    #[envconfig(from = "DGAT_REPORT_POLL_INTERVAL_SECS", default = "10")]
+    report_poll_interval_secs: u64,
+
+    #[envconfig(from = "DGAT_REPORT_LEASE_SECS", default = "900")]
+    report_lease_secs: u64,
+
+    // Report retention
+    /// e.g. `summary:keep=5;detailed:days=90,keep=3`, empty keeps everything
+    #[envconfig(from = "DGAT_REPORT_RETENTION", default = "")]
+    report_retention: RetentionPolicies,
+
+    #[envconfig(from = "DGAT_RETENTION_SWEEP_INTERVAL_SECS", default = "3600")]
+    retention_sweep_interval_secs: u64,
+
+    #[envconfig(from = "DGAT_RETENTION_ORPHAN_GRACE_SECS", default = "3600")]
+    retention_orphan_grace_secs: u64,
+
+    /// `chrome` or `native`
+    #[envconfig(from = "DGAT_PDF_BACKEND", default = "chrome")]
+    pdf_backend: PdfBackend,
+
+    #[envconfig(from = "DGAT_BROWSER_POOL_SIZE", default = "2")]
+    browser_pool_size: usize,
+
+    #[envconfig(from = "DGAT_BROWSER_MAX_RENDERS", default = "50")]
+    browser_max_renders: usize,
+
+    #[envconfig(from = "DGAT_BROWSER_RENDER_TIMEOUT_SECS", default = "60")]
+    browser_render_timeout_secs: u64,
 }
 
 impl Config {
@@ -89,11 +345,12 @@ impl Config {
         let _ = dotenv::dotenv();
 
         let e = ConfigEnv::init_from_env()?;
+        let server_url = format!("http://{}:{}", e.host, e.port);
         Ok(Self {
             database_url: e.database_url,
             host: e.host.clone(),
             port: e.port,
-            server_url: format!("http://{}:{}", e.host, e.port),
+            server_url: server_url.clone(),
             keycloak: KeycloakConfigs {
                 url: e.keycloak_url,
                 public_url: e.keycloak_public_url,
@@ -110,6 +367,42 @@ impl Config {
                 bucket_name: e.minio_bucket_name,
                 use_ssl: e.minio_use_ssl,
             },
+            storage_backend: e.storage_backend,
+            local_storage: LocalStorageConfig {
+                root_dir: e.local_storage_root,
+            },
+            download_links: DownloadLinkConfig {
+                secret: e
+                    .download_link_secret
+                    .filter(|secret| !secret.is_empty())
+                    .unwrap_or_else(|| {
+                        warn!(
+                            "DGAT_DOWNLOAD_LINK_SECRET is not set, download links are signed with a random key and expire on restart."
+                        );
+                        DownloadLinkConfig::default().secret
+                    }),
+                base_url: e.download_link_base_url.unwrap_or(server_url),
+                expiry_secs: e.download_link_expiry_secs,
+                max_expiry_secs: e.download_link_max_expiry_secs,
+            },
+            report_queue: ReportQueueConfig {
+                workers: e.report_workers,
+                max_attempts: e.report_max_attempts,
+                retry_backoff_secs: e.report_retry_backoff_secs,
+                poll_interval_secs: e.report_poll_interval_secs,
+                lease_secs: e.report_lease_secs,
+            },
+            report_retention: ReportRetentionConfig {
+                policies: e.report_retention,
+                sweep_interval_secs: e.retention_sweep_interval_secs,
+                orphan_grace_secs: e.retention_orphan_grace_secs,
+            },
+            pdf_backend: e.pdf_backend,
+            browser_pool: BrowserPoolConfig {
+                size: e.browser_pool_size,
+                max_renders: e.browser_max_renders,
+                render_timeout_secs: e.browser_render_timeout_secs,
+            },
         })
     }
 }
@@ -17,6 +17,14 @@ pub struct Model {
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
     pub dimensions_id: Option<JsonValue>,
+    /// Digital maturity index (0-100) frozen at submission.
+    pub overall_score: Option<f64>,
+    /// `AssessmentScore` breakdown frozen at submission.
+    pub scores: Option<JsonValue>,
+    /// Assessment this one is a re-assessment of.
+    pub previous_assessment_id: Option<Uuid>,
+    /// Framework version the assessment was started with.
+    pub framework_version_id: Uuid,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
@@ -60,6 +68,14 @@ pub enum Relation {
     ActionPlans,
     #[sea_orm(has_many = "super::assessment_recommendations::Entity")]
     AssessmentRecommendations,
+    #[sea_orm(
+        belongs_to = "super::framework_versions::Entity",
+        from = "Column::FrameworkVersionId",
+        to = "super::framework_versions::Column::FrameworkVersionId",
+        on_update = "Cascade",
+        on_delete = "Restrict"
+    )]
+    FrameworkVersions,
 }
 
 impl Related<super::dimension_assessments::Entity> for Entity {
@@ -86,4 +102,10 @@ impl Related<super::assessment_recommendations::Entity> for Entity {
     }
 }
 
+impl Related<super::framework_versions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FrameworkVersions.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
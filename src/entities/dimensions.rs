@@ -6,12 +6,22 @@ use serde::{Deserialize, Serialize};
 pub struct Model {
     #[sea_orm(primary_key, auto_generate = false)]
     pub dimension_id: Uuid,
-    #[sea_orm(unique)]
+    /// Unique within the framework version
     pub name: String,
     pub description: Option<String>,
     pub weight: Option<i32>,
     pub category: Option<String>,
     pub is_active: Option<bool>,
+    /// Smallest gap rated MEDIUM; the default applies when unset.
+    pub gap_medium_threshold: Option<i32>,
+    /// Smallest gap rated HIGH; the default applies when unset.
+    pub gap_high_threshold: Option<i32>,
+    /// `DimensionTranslation` per locale code
+    pub translations: Option<Json>,
+    pub framework_version_id: Uuid,
+    /// The dimension this one is a copy of in every framework version,
+    /// identifying it across versions
+    pub origin_dimension_id: Uuid,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
@@ -28,6 +38,14 @@ pub enum Relation {
     Gaps,
     #[sea_orm(has_many = "super::recommendations::Entity")]
     Recommendations,
+    #[sea_orm(
+        belongs_to = "super::framework_versions::Entity",
+        from = "Column::FrameworkVersionId",
+        to = "super::framework_versions::Column::FrameworkVersionId",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    FrameworkVersions,
 }
 
 impl Related<super::current_states::Entity> for Entity {
@@ -60,4 +78,10 @@ impl Related<super::recommendations::Entity> for Entity {
     }
 }
 
+impl Related<super::framework_versions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FrameworkVersions.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
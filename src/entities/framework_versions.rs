@@ -0,0 +1,47 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Set of dimensions, states, gaps and recommendations assessments are
+/// answered against. Published versions are immutable.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "framework_versions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_generate = false)]
+    pub framework_version_id: Uuid,
+    #[sea_orm(unique)]
+    pub version_number: i32,
+    pub notes: Option<String>,
+    pub created_by: Option<String>,
+    /// `None` while the version is a draft
+    pub published_at: Option<DateTimeUtc>,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+impl Model {
+    pub fn is_draft(&self) -> bool {
+        self.published_at.is_none()
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::dimensions::Entity")]
+    Dimensions,
+    #[sea_orm(has_many = "super::assessments::Entity")]
+    Assessments,
+}
+
+impl Related<super::dimensions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Dimensions.def()
+    }
+}
+
+impl Related<super::assessments::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Assessments.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -9,6 +9,8 @@ pub struct Model {
     pub dimension_id: Uuid,
     pub priority: RecommendationPriority,
     pub description: String,
+    /// `RecommendationTranslation` per locale code
+    pub translations: Option<Json>,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
@@ -0,0 +1,30 @@
+use super::reports::ReportType;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+/// Versioned HTML template of a report type, with its branding
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "report_templates")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_generate = false)]
+    pub template_id: Uuid,
+    /// `None` for the default template of all organizations
+    pub organization_id: Option<String>,
+    pub report_type: ReportType,
+    pub version: i32,
+    /// Tera template, may extend `report_base.html` and include the partials
+    pub content: String,
+    /// Serialized `ReportBranding`
+    pub branding: JsonValue,
+    /// Only the active version is used to render reports
+    pub is_active: bool,
+    pub created_by: Option<String>,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
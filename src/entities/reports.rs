@@ -9,14 +9,31 @@ use utoipa::ToSchema;
 pub struct Model {
     #[sea_orm(primary_key, auto_generate = false)]
     pub report_id: Uuid,
-    pub assessment_id: Uuid,
+    /// Unset for portfolio reports
+    pub assessment_id: Option<Uuid>,
+    /// Organization a portfolio report aggregates
+    pub organization_id: Option<String>,
     pub report_type: ReportType,
     pub title: String,
     pub format: ReportFormat,
     pub summary: Option<String>,
+    /// Data the file was rendered from, frozen at generation
     pub report_data: Option<JsonValue>,
     pub file_path: Option<String>,
+    /// Hex SHA-256 of the stored file
+    pub file_sha256: Option<String>,
     pub status: ReportStatus,
+    /// Serialized `ReportOptions` the report is rendered with
+    pub options: Option<JsonValue>,
+    /// Generation progress, 0-100
+    pub progress: i32,
+    /// Generation attempts made so far
+    pub attempts: i32,
+    /// Earliest time a failed job is retried
+    pub next_attempt_at: Option<DateTimeUtc>,
+    pub failure_reason: Option<String>,
+    /// When the generating worker last renewed its lease on the job
+    pub claimed_at: Option<DateTimeUtc>,
     pub generated_at: DateTimeUtc,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
@@ -31,6 +48,8 @@ pub enum ReportType {
     Detailed,
     #[sea_orm(string_value = "action_plan")]
     ActionPlan,
+    #[sea_orm(string_value = "portfolio")]
+    Portfolio,
 }
 
 #[derive(
@@ -56,6 +75,26 @@ impl fmt::Display for ReportFormat {
     }
 }
 
+impl ReportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ReportFormat::Pdf => "application/pdf",
+            ReportFormat::Excel => {
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            }
+            ReportFormat::Json => "application/json",
+        }
+    }
+
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            ReportFormat::Pdf => "pdf",
+            ReportFormat::Excel => "xlsx",
+            ReportFormat::Json => "json",
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "report_status")]
 pub enum ReportStatus {
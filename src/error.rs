@@ -14,12 +14,25 @@ pub enum AppError {
     #[error("Validation error: {0}")]
     ValidationError(String),
 
+    /// Validation failure carrying machine-readable details for the client.
+    #[error("Validation error: {message}")]
+    ValidationFailed {
+        message: String,
+        details: serde_json::Value,
+    },
+
     #[error("Not found: {0}")]
     NotFound(String),
 
     #[error("Unauthorized: {0}")]
     Unauthorized(String),
 
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
     #[error("Bad request: {0}")]
     BadRequest(String),
 
@@ -41,8 +54,13 @@ impl IntoResponse for AppError {
         let (status, error_message) = match self {
             AppError::DatabaseError(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Database error"),
             AppError::ValidationError(_) => (StatusCode::BAD_REQUEST, "Validation error"),
+            AppError::ValidationFailed { .. } => {
+                (StatusCode::UNPROCESSABLE_ENTITY, "Validation error")
+            }
             AppError::NotFound(_) => (StatusCode::NOT_FOUND, "Resource not found"),
             AppError::Unauthorized(_) => (StatusCode::UNAUTHORIZED, "Unauthorized"),
+            AppError::Forbidden(_) => (StatusCode::FORBIDDEN, "Forbidden"),
+            AppError::Conflict(_) => (StatusCode::CONFLICT, "Conflict"),
             AppError::BadRequest(_) => (StatusCode::BAD_REQUEST, "Bad request"),
             AppError::InternalServerError(_) => {
                 (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error")
@@ -56,10 +74,17 @@ impl IntoResponse for AppError {
             }
         };
 
-        let body = Json(json!({
-            "error": error_message,
-            "message": self.to_string(),
-        }));
+        let body = match self {
+            AppError::ValidationFailed { message, details } => Json(json!({
+                "error": error_message,
+                "message": message,
+                "details": details,
+            })),
+            _ => Json(json!({
+                "error": error_message,
+                "message": self.to_string(),
+            })),
+        };
 
         (status, body).into_response()
     }
@@ -12,7 +12,8 @@ pub mod services;
 
 use crate::api::routes;
 use crate::auth::jwt_validator::JwtValidator;
-use crate::config::Config;
+use crate::config::{Config, PdfBackend};
+use crate::services::cooperation_membership::CooperationMembershipService;
 use crate::services::keycloak::KeycloakService;
 use axum::Router;
 use sea_orm::DatabaseConnection;
@@ -21,7 +22,11 @@ use std::sync::Arc;
 use tracing::{info, warn, Level};
 use tracing_subscriber::FmtSubscriber;
 
+use crate::services::browser_pool::BrowserPool;
+use crate::services::report_queue::ReportQueue;
+use crate::services::report_retention::ReportRetention;
 use crate::services::report_service::ReportService;
+use crate::services::report_templates::ReportTemplateService;
 
 #[derive(Clone)]
 pub struct AppState {
@@ -29,6 +34,10 @@ pub struct AppState {
     pub keycloak_service: Arc<KeycloakService>,
     pub jwt_validator: Arc<JwtValidator>,
     pub report_service: Arc<ReportService>,
+    pub report_queue: Arc<ReportQueue>,
+    pub browser_pool: Arc<BrowserPool>,
+    pub report_templates: Arc<ReportTemplateService>,
+    pub cooperation_membership: Arc<CooperationMembershipService>,
 }
 
 pub async fn run() -> anyhow::Result<()> {
@@ -53,11 +62,52 @@ pub async fn run() -> anyhow::Result<()> {
     // Run migrations
     database::run_migrations(&db).await?;
 
+    // Share one browser pool for Chrome rendering, launched up front
+    let browser_pool = Arc::new(BrowserPool::new(config.browser_pool.clone()));
+    if config.pdf_backend == PdfBackend::Chrome {
+        if let Err(e) = browser_pool.warm_up().await {
+            warn!(error = %e, "Failed to warm up browser pool, browsers will be launched on demand");
+        }
+    }
+
+    // Report templates are compiled once and shared with the API
+    let report_templates = Arc::new(ReportTemplateService::new(db.clone())?);
+
     // Initialize Report Service
-    let report_service = Arc::new(ReportService::new(&config.minio, db.clone()).await?);
+    let report_service = Arc::new(
+        ReportService::from_config(&config, db.clone())
+            .await?
+            .with_pdf_backend(config.pdf_backend)
+            .with_download_links(config.download_links.clone())
+            .with_browser_pool(browser_pool.clone())
+            .with_report_templates(report_templates.clone()),
+    );
+
+    // Start the report generation workers
+    let report_queue = Arc::new(ReportQueue::new(
+        db.clone(),
+        report_service.clone(),
+        config.report_queue.clone(),
+    ));
+    report_queue.start().await?;
+
+    // Sweep expired reports and orphaned files in the background
+    Arc::new(ReportRetention::new(
+        db.clone(),
+        report_service.clone(),
+        config.report_retention.clone(),
+    ))
+    .start();
 
     // Build our application with routes
-    let app = create_app(db, config.clone(), report_service);
+    let app = create_app(
+        db,
+        config.clone(),
+        report_service,
+        report_queue,
+        browser_pool,
+        report_templates,
+    );
 
     // Run the server
     let addr: SocketAddr = (config.host.parse::<std::net::IpAddr>()?, config.port).into();
@@ -73,6 +123,9 @@ fn create_app(
     db: Arc<DatabaseConnection>,
     config: Config,
     report_service: Arc<ReportService>,
+    report_queue: Arc<ReportQueue>,
+    browser_pool: Arc<BrowserPool>,
+    report_templates: Arc<ReportTemplateService>,
 ) -> Router {
     use http::header::{AUTHORIZATION, CONTENT_TYPE};
     use tower_http::cors::CorsLayer;
@@ -93,11 +146,16 @@ fn create_app(
         .allow_headers([AUTHORIZATION, CONTENT_TYPE])
         .allow_credentials(true);
 
+    let keycloak_service = Arc::new(KeycloakService::new(config.clone()));
     let state = AppState {
         db,
-        keycloak_service: Arc::new(KeycloakService::new(config.clone())),
+        keycloak_service: keycloak_service.clone(),
         jwt_validator: Arc::new(JwtValidator::new(config.keycloak.clone())),
         report_service,
+        report_queue,
+        browser_pool,
+        report_templates,
+        cooperation_membership: Arc::new(CooperationMembershipService::new(keycloak_service)),
     };
 
     // Create API router with all routes
@@ -110,6 +168,7 @@ fn create_app(
     // Combine all routers
     Router::new()
         .merge(api_router)
+        .merge(routes::report::create_public_report_routes())
         .merge(api::openapi::docs_routes(config))
         .with_state(state)
         .layer(cors)
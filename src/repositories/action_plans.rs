@@ -1,5 +1,6 @@
 use crate::{
     api::dto::action_plan::ActionPlanResponse,
+    auth::tenant::TenantContext,
     entities::{
         action_items, action_plans,
         dimension_assessments::{self},
@@ -14,8 +15,12 @@ use uuid::Uuid;
 pub struct ActionPlansRepository;
 
 impl ActionPlansRepository {
-    pub async fn find_all(db: &DbConn) -> Result<Vec<action_plans::Model>, AppError> {
+    pub async fn find_all(
+        db: &DbConn,
+        tenant: &TenantContext,
+    ) -> Result<Vec<action_plans::Model>, AppError> {
         action_plans::Entity::find()
+            .filter(tenant.assessment_condition(action_plans::Column::AssessmentId))
             .all(db)
             .await
             .map_err(AppError::from)
@@ -23,10 +28,12 @@ impl ActionPlansRepository {
 
     pub async fn find_action_plan_with_items_by_assessment_id(
         db: &DbConn,
+        tenant: &TenantContext,
         assessment_id: Uuid,
     ) -> Result<Option<ActionPlanResponse>, AppError> {
         let plan_with_items = action_plans::Entity::find()
             .filter(action_plans::Column::AssessmentId.eq(assessment_id))
+            .filter(tenant.assessment_condition(action_plans::Column::AssessmentId))
             .find_with_related(action_items::Entity)
             .all(db)
             .await?;
@@ -137,4 +144,16 @@ impl ActionPlansRepository {
             .await
             .map_err(AppError::from)
     }
+
+    pub async fn find_by_id_for_tenant(
+        db: &DbConn,
+        tenant: &TenantContext,
+        id: Uuid,
+    ) -> Result<Option<action_plans::Model>, AppError> {
+        action_plans::Entity::find_by_id(id)
+            .filter(tenant.assessment_condition(action_plans::Column::AssessmentId))
+            .one(db)
+            .await
+            .map_err(AppError::from)
+    }
 }
@@ -1,3 +1,4 @@
+use crate::auth::tenant::TenantContext;
 use crate::entities::assessments::{self, Entity as Assessments};
 use crate::error::AppError;
 use sea_orm::*;
@@ -6,8 +7,15 @@ use uuid::Uuid;
 pub struct AssessmentsRepository;
 
 impl AssessmentsRepository {
-    pub async fn find_all(db: &DbConn) -> Result<Vec<assessments::Model>, AppError> {
-        Assessments::find().all(db).await.map_err(AppError::from)
+    pub async fn find_all(
+        db: &DbConn,
+        tenant: &TenantContext,
+    ) -> Result<Vec<assessments::Model>, AppError> {
+        Assessments::find()
+            .filter(tenant.assessments_condition())
+            .all(db)
+            .await
+            .map_err(AppError::from)
     }
 
     pub async fn find_by_id(
@@ -20,6 +28,18 @@ impl AssessmentsRepository {
             .map_err(AppError::from)
     }
 
+    pub async fn find_by_id_for_tenant(
+        db: &DbConn,
+        tenant: &TenantContext,
+        assessment_id: Uuid,
+    ) -> Result<Option<assessments::Model>, AppError> {
+        Assessments::find_by_id(assessment_id)
+            .filter(tenant.assessments_condition())
+            .one(db)
+            .await
+            .map_err(AppError::from)
+    }
+
     pub async fn create(
         db: &DbConn,
         assessment_data: assessments::ActiveModel,
@@ -58,14 +78,26 @@ impl AssessmentsRepository {
         if assessment_data.dimensions_id.is_set() {
             active_model.dimensions_id = assessment_data.dimensions_id;
         }
+        if assessment_data.overall_score.is_set() {
+            active_model.overall_score = assessment_data.overall_score;
+        }
+        if assessment_data.scores.is_set() {
+            active_model.scores = assessment_data.scores;
+        }
 
         active_model.updated_at = Set(chrono::Utc::now());
 
         active_model.update(db).await.map_err(AppError::from)
     }
 
-    pub async fn delete(db: &DbConn, assessment_id: Uuid) -> Result<bool, AppError> {
-        let result = Assessments::delete_by_id(assessment_id)
+    pub async fn delete(
+        db: &DbConn,
+        tenant: &TenantContext,
+        assessment_id: Uuid,
+    ) -> Result<bool, AppError> {
+        let result = Assessments::delete_many()
+            .filter(assessments::Column::AssessmentId.eq(assessment_id))
+            .filter(tenant.assessments_condition())
             .exec(db)
             .await
             .map_err(AppError::from)?;
@@ -90,10 +122,12 @@ impl AssessmentsRepository {
 
     pub async fn find_by_organization_id(
         db: &DbConn,
+        tenant: &TenantContext,
         organization_id: String,
     ) -> Result<Vec<assessments::Model>, AppError> {
         Assessments::find()
             .filter(assessments::Column::OrganizationId.eq(organization_id))
+            .filter(tenant.assessments_condition())
             .all(db)
             .await
             .map_err(AppError::from)
@@ -113,10 +147,12 @@ impl AssessmentsRepository {
 
     pub async fn find_all_completed_by_organization_id(
         db: &DbConn,
+        tenant: &TenantContext,
         organization_id: String,
     ) -> Result<Vec<assessments::Model>, AppError> {
         Assessments::find()
             .filter(assessments::Column::OrganizationId.eq(organization_id))
+            .filter(tenant.assessments_condition())
             .filter(
                 assessments::Column::Status
                     .eq(crate::entities::assessments::AssessmentStatus::Completed),
@@ -129,20 +165,24 @@ impl AssessmentsRepository {
 
     pub async fn find_by_cooperation_id(
         db: &DbConn,
+        tenant: &TenantContext,
         cooperation_id: String,
     ) -> Result<Vec<assessments::Model>, AppError> {
         Assessments::find()
             .filter(assessments::Column::CooperationId.eq(cooperation_id))
+            .filter(tenant.assessments_condition())
             .all(db)
             .await
             .map_err(AppError::from)
     }
     pub async fn find_all_completed_by_cooperation_id(
         db: &DbConn,
+        tenant: &TenantContext,
         cooperation_id: String,
     ) -> Result<Vec<assessments::Model>, AppError> {
         Assessments::find()
             .filter(assessments::Column::CooperationId.eq(cooperation_id))
+            .filter(tenant.assessments_condition())
             .filter(
                 assessments::Column::Status
                     .eq(crate::entities::assessments::AssessmentStatus::Completed),
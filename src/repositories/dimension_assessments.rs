@@ -1,3 +1,4 @@
+use crate::auth::tenant::TenantContext;
 use crate::entities::dimension_assessments::{self, Entity as DimensionAssessments};
 use crate::error::AppError;
 use sea_orm::*;
@@ -23,6 +24,18 @@ impl DimensionAssessmentsRepository {
             .map_err(AppError::from)
     }
 
+    pub async fn find_by_id_for_tenant(
+        db: &DbConn,
+        tenant: &TenantContext,
+        dimension_assessment_id: Uuid,
+    ) -> Result<Option<dimension_assessments::Model>, AppError> {
+        DimensionAssessments::find_by_id(dimension_assessment_id)
+            .filter(tenant.assessment_condition(dimension_assessments::Column::AssessmentId))
+            .one(db)
+            .await
+            .map_err(AppError::from)
+    }
+
     pub async fn find_by_assessment_id(
         db: &DbConn,
         assessment_id: Uuid,
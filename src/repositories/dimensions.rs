@@ -10,6 +10,19 @@ impl DimensionsRepository {
         Dimensions::find().all(db).await.map_err(AppError::from)
     }
 
+    /// Dimensions of a framework version, by name
+    pub async fn find_by_framework_version<C: ConnectionTrait>(
+        db: &C,
+        framework_version_id: Uuid,
+    ) -> Result<Vec<dimensions::Model>, AppError> {
+        Dimensions::find()
+            .filter(dimensions::Column::FrameworkVersionId.eq(framework_version_id))
+            .order_by_asc(dimensions::Column::Name)
+            .all(db)
+            .await
+            .map_err(AppError::from)
+    }
+
     pub async fn find_by_id(
         db: &DbConn,
         dimension_id: Uuid,
@@ -64,6 +77,12 @@ impl DimensionsRepository {
         if dimension_data.is_active.is_set() {
             active_model.is_active = dimension_data.is_active;
         }
+        if dimension_data.gap_medium_threshold.is_set() {
+            active_model.gap_medium_threshold = dimension_data.gap_medium_threshold;
+        }
+        if dimension_data.gap_high_threshold.is_set() {
+            active_model.gap_high_threshold = dimension_data.gap_high_threshold;
+        }
 
         active_model.updated_at = Set(chrono::Local::now().naive_local());
 
@@ -0,0 +1,57 @@
+use crate::entities::framework_versions::{self, Entity as FrameworkVersions};
+use crate::error::AppError;
+use sea_orm::*;
+use uuid::Uuid;
+
+pub struct FrameworkVersionsRepository;
+
+impl FrameworkVersionsRepository {
+    /// All versions, newest first
+    pub async fn find_all(db: &DbConn) -> Result<Vec<framework_versions::Model>, AppError> {
+        FrameworkVersions::find()
+            .order_by_desc(framework_versions::Column::VersionNumber)
+            .all(db)
+            .await
+            .map_err(AppError::from)
+    }
+
+    pub async fn find_by_id<C: ConnectionTrait>(
+        db: &C,
+        framework_version_id: Uuid,
+    ) -> Result<Option<framework_versions::Model>, AppError> {
+        FrameworkVersions::find_by_id(framework_version_id)
+            .one(db)
+            .await
+            .map_err(AppError::from)
+    }
+
+    /// Latest published version, the one new assessments are started with
+    pub async fn find_current<C: ConnectionTrait>(
+        db: &C,
+    ) -> Result<Option<framework_versions::Model>, AppError> {
+        FrameworkVersions::find()
+            .filter(framework_versions::Column::PublishedAt.is_not_null())
+            .order_by_desc(framework_versions::Column::VersionNumber)
+            .one(db)
+            .await
+            .map_err(AppError::from)
+    }
+
+    pub async fn find_draft<C: ConnectionTrait>(
+        db: &C,
+    ) -> Result<Option<framework_versions::Model>, AppError> {
+        FrameworkVersions::find()
+            .filter(framework_versions::Column::PublishedAt.is_null())
+            .one(db)
+            .await
+            .map_err(AppError::from)
+    }
+
+    pub async fn next_version_number<C: ConnectionTrait>(db: &C) -> Result<i32, AppError> {
+        let latest = FrameworkVersions::find()
+            .order_by_desc(framework_versions::Column::VersionNumber)
+            .one(db)
+            .await?;
+        Ok(latest.map_or(1, |version| version.version_number + 1))
+    }
+}
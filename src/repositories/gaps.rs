@@ -1,4 +1,6 @@
+use crate::auth::tenant::TenantContext;
 use crate::entities::gaps::{self, Entity as Gaps};
+use crate::entities::{dimension_assessments, dimensions};
 use crate::error::AppError;
 use crate::repositories::dimension_assessments::DimensionAssessmentsRepository;
 use sea_orm::*;
@@ -11,6 +13,19 @@ impl GapsRepository {
         Gaps::find().all(db).await.map_err(AppError::from)
     }
 
+    /// Gaps of the dimensions of a framework version
+    pub async fn find_by_framework_version(
+        db: &DbConn,
+        framework_version_id: Uuid,
+    ) -> Result<Vec<gaps::Model>, AppError> {
+        Gaps::find()
+            .inner_join(dimensions::Entity)
+            .filter(dimensions::Column::FrameworkVersionId.eq(framework_version_id))
+            .all(db)
+            .await
+            .map_err(AppError::from)
+    }
+
     pub async fn find_by_id(db: &DbConn, gap_id: Uuid) -> Result<Option<gaps::Model>, AppError> {
         Gaps::find_by_id(gap_id)
             .one(db)
@@ -64,11 +79,16 @@ impl GapsRepository {
 
     pub async fn find_by_dimension_assessment(
         db: &DbConn,
+        tenant: &TenantContext,
         dimension_assessment_id: Uuid,
     ) -> Result<Vec<gaps::Model>, AppError> {
         // Map the dimension assessment to its dimension_id, then query gaps by dimension_id
-        if let Some(da) =
-            DimensionAssessmentsRepository::find_by_id(db, dimension_assessment_id).await?
+        if let Some(da) = DimensionAssessmentsRepository::find_by_id_for_tenant(
+            db,
+            tenant,
+            dimension_assessment_id,
+        )
+        .await?
         {
             Gaps::find()
                 .filter(gaps::Column::DimensionId.eq(da.dimension_id))
@@ -135,11 +155,15 @@ impl GapsRepository {
     /// Find gaps by assessment ID through dimension assessments
     pub async fn find_by_assessment(
         db: &DbConn,
+        tenant: &TenantContext,
         assessment_id: Uuid,
     ) -> Result<Vec<gaps::Model>, AppError> {
         // Get dimensions involved in this assessment and query gaps by dimension_id
-        let dimension_assessments =
-            DimensionAssessmentsRepository::find_by_assessment_id(db, assessment_id).await?;
+        let dimension_assessments = dimension_assessments::Entity::find()
+            .filter(dimension_assessments::Column::AssessmentId.eq(assessment_id))
+            .filter(tenant.assessment_condition(dimension_assessments::Column::AssessmentId))
+            .all(db)
+            .await?;
         let dimension_ids: Vec<Uuid> = dimension_assessments
             .into_iter()
             .map(|da| da.dimension_id)
@@ -6,7 +6,9 @@ pub mod current_states;
 pub mod desired_states;
 pub mod dimension_assessments;
 pub mod dimensions;
+pub mod framework_versions;
 pub mod gaps;
 pub mod organisation_dimension;
 pub mod recommendations;
+pub mod report_templates;
 pub mod reports;
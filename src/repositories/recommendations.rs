@@ -1,3 +1,4 @@
+use crate::entities::dimensions;
 use crate::entities::recommendations::{self, Entity as Recommendations};
 use crate::error::AppError;
 use sea_orm::*;
@@ -135,13 +136,16 @@ impl RecommendationsRepository {
         active_model.update(db).await.map_err(AppError::from)
     }
 
-    /// Find all recommendations with pagination
+    /// Find the recommendations of a framework version with pagination
     pub async fn find_all_paginated(
         db: &DbConn,
+        framework_version_id: Uuid,
         page: u64,
         page_size: u64,
     ) -> Result<(Vec<recommendations::Model>, u64), AppError> {
         let paginator = Recommendations::find()
+            .inner_join(dimensions::Entity)
+            .filter(dimensions::Column::FrameworkVersionId.eq(framework_version_id))
             .order_by_asc(recommendations::Column::CreatedAt)
             .paginate(db, page_size);
 
@@ -0,0 +1,142 @@
+use crate::auth::tenant::TenantContext;
+use crate::entities::report_templates::{self, Entity as ReportTemplates};
+use crate::entities::reports::ReportType;
+use crate::error::AppError;
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+use uuid::Uuid;
+
+pub struct ReportTemplatesRepository;
+
+impl ReportTemplatesRepository {
+    pub async fn find_by_id(
+        db: &DbConn,
+        template_id: Uuid,
+    ) -> Result<Option<report_templates::Model>, AppError> {
+        ReportTemplates::find_by_id(template_id)
+            .one(db)
+            .await
+            .map_err(AppError::from)
+    }
+
+    /// Templates visible to the tenant: its own and the defaults, newest
+    /// version first
+    pub async fn find_all(
+        db: &DbConn,
+        tenant: &TenantContext,
+        report_type: Option<ReportType>,
+    ) -> Result<Vec<report_templates::Model>, AppError> {
+        let mut query = ReportTemplates::find();
+        if let Some(organization_id) = tenant.organization_id() {
+            query = query.filter(
+                Condition::any()
+                    .add(report_templates::Column::OrganizationId.eq(organization_id))
+                    .add(report_templates::Column::OrganizationId.is_null()),
+            );
+        }
+        if let Some(report_type) = report_type {
+            query = query.filter(report_templates::Column::ReportType.eq(report_type));
+        }
+        query
+            .order_by_asc(report_templates::Column::OrganizationId)
+            .order_by_asc(report_templates::Column::ReportType)
+            .order_by_desc(report_templates::Column::Version)
+            .all(db)
+            .await
+            .map_err(AppError::from)
+    }
+
+    /// Active template of an organization, or the active default when
+    /// `organization_id` is `None`
+    pub async fn find_active(
+        db: &DbConn,
+        organization_id: Option<&str>,
+        report_type: ReportType,
+    ) -> Result<Option<report_templates::Model>, AppError> {
+        ReportTemplates::find()
+            .filter(Self::owner_condition(organization_id))
+            .filter(report_templates::Column::ReportType.eq(report_type))
+            .filter(report_templates::Column::IsActive.eq(true))
+            .one(db)
+            .await
+            .map_err(AppError::from)
+    }
+
+    /// Store `template` as the next version of its organization and report
+    /// type and make it the active one
+    pub async fn create_version(
+        db: &DbConn,
+        mut template: report_templates::ActiveModel,
+    ) -> Result<report_templates::Model, AppError> {
+        let organization_id = template.organization_id.clone().unwrap();
+        let report_type = template.report_type.clone().unwrap();
+
+        let txn = db.begin().await?;
+        let latest = ReportTemplates::find()
+            .filter(Self::owner_condition(organization_id.as_deref()))
+            .filter(report_templates::Column::ReportType.eq(report_type.clone()))
+            .order_by_desc(report_templates::Column::Version)
+            .one(&txn)
+            .await?;
+        Self::deactivate(&txn, organization_id.as_deref(), report_type).await?;
+
+        template.version = Set(latest.map_or(1, |t| t.version + 1));
+        template.is_active = Set(true);
+        let template = template.insert(&txn).await?;
+        txn.commit().await?;
+        Ok(template)
+    }
+
+    /// Make `template_id` the active version, e.g. to roll back
+    pub async fn activate(
+        db: &DbConn,
+        template_id: Uuid,
+    ) -> Result<report_templates::Model, AppError> {
+        let txn = db.begin().await?;
+        let template = ReportTemplates::find_by_id(template_id)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Report template not found".to_string()))?;
+        Self::deactivate(
+            &txn,
+            template.organization_id.as_deref(),
+            template.report_type.clone(),
+        )
+        .await?;
+
+        let mut active: report_templates::ActiveModel = template.into();
+        active.is_active = Set(true);
+        active.updated_at = Set(chrono::Utc::now());
+        let template = active.update(&txn).await?;
+        txn.commit().await?;
+        Ok(template)
+    }
+
+    async fn deactivate<C: ConnectionTrait>(
+        db: &C,
+        organization_id: Option<&str>,
+        report_type: ReportType,
+    ) -> Result<(), AppError> {
+        ReportTemplates::update_many()
+            .col_expr(report_templates::Column::IsActive, Expr::value(false))
+            .col_expr(
+                report_templates::Column::UpdatedAt,
+                Expr::value(chrono::Utc::now()),
+            )
+            .filter(Self::owner_condition(organization_id))
+            .filter(report_templates::Column::ReportType.eq(report_type))
+            .filter(report_templates::Column::IsActive.eq(true))
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+
+    fn owner_condition(organization_id: Option<&str>) -> Condition {
+        match organization_id {
+            Some(organization_id) => {
+                Condition::all().add(report_templates::Column::OrganizationId.eq(organization_id))
+            }
+            None => Condition::all().add(report_templates::Column::OrganizationId.is_null()),
+        }
+    }
+}
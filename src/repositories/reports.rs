@@ -1,14 +1,23 @@
-use crate::entities::reports::{self, Entity as Reports};
+use crate::auth::tenant::TenantContext;
+use crate::entities::reports::{self, Entity as Reports, ReportStatus};
 use crate::error::AppError;
-use sea_orm::sea_query::{Alias, Expr};
+use sea_orm::sea_query::{Alias, Expr, LockBehavior, LockType};
 use sea_orm::*;
+use std::time::Duration;
 use uuid::Uuid;
 
 pub struct ReportsRepository;
 
 impl ReportsRepository {
-    pub async fn find_all(db: &DbConn) -> Result<Vec<reports::Model>, AppError> {
-        Reports::find().all(db).await.map_err(AppError::from)
+    pub async fn find_all(
+        db: &DbConn,
+        tenant: &TenantContext,
+    ) -> Result<Vec<reports::Model>, AppError> {
+        Reports::find()
+            .filter(Self::tenant_condition(tenant))
+            .all(db)
+            .await
+            .map_err(AppError::from)
     }
 
     pub async fn find_by_id(
@@ -21,8 +30,20 @@ impl ReportsRepository {
             .map_err(AppError::from)
     }
 
-    pub async fn create(
+    pub async fn find_by_id_for_tenant(
         db: &DbConn,
+        tenant: &TenantContext,
+        report_id: Uuid,
+    ) -> Result<Option<reports::Model>, AppError> {
+        Reports::find_by_id(report_id)
+            .filter(Self::tenant_condition(tenant))
+            .one(db)
+            .await
+            .map_err(AppError::from)
+    }
+
+    pub async fn create<C: ConnectionTrait>(
+        db: &C,
         report_data: reports::ActiveModel,
     ) -> Result<reports::Model, AppError> {
         let report_id = *report_data.report_id.as_ref();
@@ -90,10 +111,12 @@ impl ReportsRepository {
 
     pub async fn find_by_assessment(
         db: &DbConn,
+        tenant: &TenantContext,
         assessment_id: Uuid,
     ) -> Result<Vec<reports::Model>, AppError> {
         Reports::find()
             .filter(reports::Column::AssessmentId.eq(assessment_id))
+            .filter(Self::tenant_condition(tenant))
             .all(db)
             .await
             .map_err(AppError::from)
@@ -101,12 +124,14 @@ impl ReportsRepository {
 
     pub async fn find_latest_pdf_by_assessment(
         db: &DbConn,
+        tenant: &TenantContext,
         assessment_id: Uuid,
     ) -> Result<Option<reports::Model>, AppError> {
         // We cast columns to text to handle both Enum and Varchar types safely.
         // This resolves "operator does not exist" errors caused by schema inconsistencies.
         Reports::find()
             .filter(reports::Column::AssessmentId.eq(assessment_id))
+            .filter(Self::tenant_condition(tenant))
             .filter(
                 Expr::col(reports::Column::Format)
                     .cast_as(Alias::new("text"))
@@ -180,4 +205,183 @@ impl ReportsRepository {
 
         active_model.update(db).await.map_err(AppError::from)
     }
+
+    /// Lock the oldest due pending report, mark it as generating and count
+    /// the attempt. Reports locked by another worker are skipped.
+    pub async fn claim_next_pending(db: &DbConn) -> Result<Option<reports::Model>, AppError> {
+        let now = chrono::Utc::now();
+        let txn = db.begin().await.map_err(AppError::from)?;
+
+        let next = Reports::find()
+            .filter(reports::Column::Status.eq(ReportStatus::Pending))
+            .filter(
+                Condition::any()
+                    .add(reports::Column::NextAttemptAt.is_null())
+                    .add(reports::Column::NextAttemptAt.lte(now)),
+            )
+            .order_by_asc(reports::Column::CreatedAt)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .one(&txn)
+            .await
+            .map_err(AppError::from)?;
+
+        let Some(report) = next else {
+            txn.commit().await.map_err(AppError::from)?;
+            return Ok(None);
+        };
+
+        let attempts = report.attempts + 1;
+        let mut active_model: reports::ActiveModel = report.into();
+        active_model.status = Set(ReportStatus::Generating);
+        active_model.attempts = Set(attempts);
+        active_model.progress = Set(10);
+        active_model.next_attempt_at = Set(None);
+        active_model.claimed_at = Set(Some(now));
+        active_model.updated_at = Set(now);
+        let claimed = active_model.update(&txn).await.map_err(AppError::from)?;
+
+        txn.commit().await.map_err(AppError::from)?;
+        Ok(Some(claimed))
+    }
+
+    /// Put reports whose worker let its lease expire, e.g. because its
+    /// server stopped, back in the queue. Reports another worker is still
+    /// generating keep their claim.
+    pub async fn requeue_interrupted(db: &DbConn, lease: Duration) -> Result<u64, AppError> {
+        let now = chrono::Utc::now();
+        let expired_before = now
+            - chrono::Duration::from_std(lease)
+                .map_err(|e| AppError::InternalServerError(format!("Invalid lease: {}", e)))?;
+        let requeued = Reports::update_many()
+            .set(reports::ActiveModel {
+                status: Set(ReportStatus::Pending),
+                progress: Set(0),
+                claimed_at: Set(None),
+                updated_at: Set(now),
+                ..Default::default()
+            })
+            .filter(reports::Column::Status.eq(ReportStatus::Generating))
+            .filter(
+                Condition::any()
+                    .add(reports::Column::ClaimedAt.is_null())
+                    .add(reports::Column::ClaimedAt.lt(expired_before)),
+            )
+            .exec(db)
+            .await
+            .map_err(AppError::from)?;
+        Ok(requeued.rows_affected)
+    }
+
+    /// Record progress of a generating report, renewing the worker's lease
+    pub async fn update_progress(
+        db: &DbConn,
+        report_id: Uuid,
+        progress: i32,
+    ) -> Result<reports::Model, AppError> {
+        let now = chrono::Utc::now();
+        let mut active_model = Self::active_model(db, report_id).await?;
+        active_model.progress = Set(progress);
+        active_model.claimed_at = Set(Some(now));
+        active_model.updated_at = Set(now);
+        active_model.update(db).await.map_err(AppError::from)
+    }
+
+    /// Record a generated file with its hash and the data snapshot it was
+    /// rendered from.
+    pub async fn mark_completed(
+        db: &DbConn,
+        report_id: Uuid,
+        file_path: String,
+        file_sha256: String,
+        report_data: serde_json::Value,
+    ) -> Result<reports::Model, AppError> {
+        let now = chrono::Utc::now();
+        let mut active_model = Self::active_model(db, report_id).await?;
+        active_model.status = Set(ReportStatus::Completed);
+        active_model.file_path = Set(Some(file_path));
+        active_model.file_sha256 = Set(Some(file_sha256));
+        active_model.report_data = Set(Some(report_data));
+        active_model.progress = Set(100);
+        active_model.failure_reason = Set(None);
+        active_model.generated_at = Set(now);
+        active_model.updated_at = Set(now);
+        active_model.update(db).await.map_err(AppError::from)
+    }
+
+    /// Return a failed attempt to the queue, due again at `next_attempt_at`.
+    pub async fn schedule_retry(
+        db: &DbConn,
+        report_id: Uuid,
+        failure_reason: String,
+        next_attempt_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<reports::Model, AppError> {
+        let mut active_model = Self::active_model(db, report_id).await?;
+        active_model.status = Set(ReportStatus::Pending);
+        active_model.progress = Set(0);
+        active_model.failure_reason = Set(Some(failure_reason));
+        active_model.next_attempt_at = Set(Some(next_attempt_at));
+        active_model.claimed_at = Set(None);
+        active_model.updated_at = Set(chrono::Utc::now());
+        active_model.update(db).await.map_err(AppError::from)
+    }
+
+    pub async fn mark_failed(
+        db: &DbConn,
+        report_id: Uuid,
+        failure_reason: String,
+    ) -> Result<reports::Model, AppError> {
+        let mut active_model = Self::active_model(db, report_id).await?;
+        active_model.status = Set(ReportStatus::Failed);
+        active_model.failure_reason = Set(Some(failure_reason));
+        active_model.next_attempt_at = Set(None);
+        active_model.updated_at = Set(chrono::Utc::now());
+        active_model.update(db).await.map_err(AppError::from)
+    }
+
+    /// Every report regardless of tenant, for background maintenance
+    pub async fn find_all_unscoped(db: &DbConn) -> Result<Vec<reports::Model>, AppError> {
+        Reports::find()
+            .order_by_desc(reports::Column::CreatedAt)
+            .all(db)
+            .await
+            .map_err(AppError::from)
+    }
+
+    /// Fail a completed report whose file is gone from storage.
+    pub async fn mark_file_missing(
+        db: &DbConn,
+        report_id: Uuid,
+    ) -> Result<reports::Model, AppError> {
+        let mut active_model = Self::active_model(db, report_id).await?;
+        active_model.status = Set(ReportStatus::Failed);
+        active_model.file_path = Set(None);
+        active_model.file_sha256 = Set(None);
+        active_model.failure_reason = Set(Some("Report file is missing from storage".to_string()));
+        active_model.updated_at = Set(chrono::Utc::now());
+        active_model.update(db).await.map_err(AppError::from)
+    }
+
+    /// Reports of the caller's assessments, plus the portfolio reports of
+    /// the caller's organization. Portfolio reports cover every cooperation,
+    /// so coop members do not see them.
+    fn tenant_condition(tenant: &TenantContext) -> Condition {
+        if tenant.is_unrestricted() {
+            return Condition::all();
+        }
+        let condition =
+            Condition::any().add(tenant.assessment_condition(reports::Column::AssessmentId));
+        if tenant.is_cooperation_restricted() {
+            return condition;
+        }
+        condition.add(tenant.organization_condition(reports::Column::OrganizationId))
+    }
+
+    async fn active_model(db: &DbConn, report_id: Uuid) -> Result<reports::ActiveModel, AppError> {
+        Reports::find_by_id(report_id)
+            .one(db)
+            .await
+            .map_err(AppError::from)?
+            .map(Into::into)
+            .ok_or_else(|| AppError::NotFound("Report not found".to_string()))
+    }
 }
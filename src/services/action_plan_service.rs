@@ -48,6 +48,7 @@ impl ActionPlanService {
                 dimension_id: Set(dimension_assessment.dimension_id),
                 priority: Set(recommendations::RecommendationPriority::Medium), // Default priority for custom items
                 description: Set(format!("{}: {}", title, description)), // Combine title and description
+                translations: Set(None),
                 created_at: Set(chrono::Utc::now()),
                 updated_at: Set(chrono::Utc::now()),
             };
@@ -0,0 +1,260 @@
+use std::collections::{BTreeSet, HashMap};
+
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+use crate::api::dto::assessment::{AssessmentComparisonResponse, DimensionComparison};
+use crate::entities::{assessments, dimensions, gaps, gaps::GapSeverity};
+use crate::error::AppError;
+use crate::repositories::dimension_assessments::DimensionAssessmentsRepository;
+use crate::services::dimension_scoring::DimensionScoringService;
+
+/// Scores and gap severity of one dimension within an assessment.
+#[derive(Debug, Clone)]
+pub struct DimensionSnapshot {
+    pub dimension_name: String,
+    pub current_score: i32,
+    pub desired_score: i32,
+    pub gap_severity: Option<GapSeverity>,
+}
+
+/// What an assessment looked like, as far as the comparison is concerned.
+#[derive(Debug, Clone, Default)]
+pub struct AssessmentSnapshot {
+    pub maturity_index: Option<f64>,
+    /// Keyed by origin dimension, so assessments answered against different
+    /// framework versions line up
+    pub dimensions: HashMap<Uuid, DimensionSnapshot>,
+}
+
+/// Compares assessments of the same organization, typically an assessment and
+/// its re-assessment a year later.
+pub struct AssessmentComparisonService;
+
+impl AssessmentComparisonService {
+    pub async fn compare_assessments(
+        db: &DatabaseConnection,
+        base: &assessments::Model,
+        target: &assessments::Model,
+    ) -> Result<AssessmentComparisonResponse, AppError> {
+        if base.organization_id != target.organization_id {
+            return Err(AppError::ValidationError(
+                "Only assessments of the same organization can be compared".to_string(),
+            ));
+        }
+
+        let base_snapshot = Self::snapshot(db, base).await?;
+        let target_snapshot = Self::snapshot(db, target).await?;
+
+        Ok(Self::compare(
+            base.assessment_id,
+            &base_snapshot,
+            target.assessment_id,
+            &target_snapshot,
+        ))
+    }
+
+    /// Load an assessment's scores (frozen ones for submitted assessments) and
+    /// the severity of each dimension's gap.
+    pub async fn snapshot(
+        db: &DatabaseConnection,
+        assessment: &assessments::Model,
+    ) -> Result<AssessmentSnapshot, AppError> {
+        let scores = DimensionScoringService::assessment_score(db, assessment).await?;
+
+        let dimension_assessments =
+            DimensionAssessmentsRepository::find_by_assessment_id(db, assessment.assessment_id)
+                .await?;
+        let severities: HashMap<Uuid, GapSeverity> = gaps::Entity::find()
+            .filter(
+                gaps::Column::GapId.is_in(
+                    dimension_assessments
+                        .iter()
+                        .map(|da| da.gap_id)
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|gap| (gap.gap_id, gap.gap_severity))
+            .collect();
+        let severity_by_dimension: HashMap<Uuid, GapSeverity> = dimension_assessments
+            .iter()
+            .filter_map(|da| {
+                severities
+                    .get(&da.gap_id)
+                    .map(|severity| (da.dimension_id, severity.clone()))
+            })
+            .collect();
+        let origins: HashMap<Uuid, Uuid> = dimensions::Entity::find()
+            .filter(
+                dimensions::Column::DimensionId.is_in(
+                    scores
+                        .dimensions
+                        .iter()
+                        .map(|score| score.dimension_id)
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|dimension| (dimension.dimension_id, dimension.origin_dimension_id))
+            .collect();
+
+        let maturity_index = if scores.dimensions.is_empty() {
+            None
+        } else {
+            Some(assessment.overall_score.unwrap_or(scores.maturity_index))
+        };
+
+        Ok(AssessmentSnapshot {
+            maturity_index,
+            dimensions: scores
+                .dimensions
+                .into_iter()
+                .map(|score| {
+                    (
+                        origins
+                            .get(&score.dimension_id)
+                            .copied()
+                            .unwrap_or(score.dimension_id),
+                        DimensionSnapshot {
+                            dimension_name: score.dimension_name,
+                            current_score: score.current_score,
+                            desired_score: score.desired_score,
+                            gap_severity: severity_by_dimension.get(&score.dimension_id).cloned(),
+                        },
+                    )
+                })
+                .collect(),
+        })
+    }
+
+    /// Per-dimension deltas (`target - base`) over the union of both
+    /// assessments' dimensions, ordered by dimension name.
+    pub fn compare(
+        base_assessment_id: Uuid,
+        base: &AssessmentSnapshot,
+        target_assessment_id: Uuid,
+        target: &AssessmentSnapshot,
+    ) -> AssessmentComparisonResponse {
+        let dimension_ids: BTreeSet<Uuid> = base
+            .dimensions
+            .keys()
+            .chain(target.dimensions.keys())
+            .copied()
+            .collect();
+
+        let mut dimensions: Vec<DimensionComparison> = dimension_ids
+            .into_iter()
+            .map(|dimension_id| {
+                let before = base.dimensions.get(&dimension_id);
+                let after = target.dimensions.get(&dimension_id);
+                let delta = |value: fn(&DimensionSnapshot) -> Option<i32>| {
+                    Some(value(after?)? - value(before?)?)
+                };
+
+                DimensionComparison {
+                    dimension_id,
+                    dimension_name: after
+                        .or(before)
+                        .map(|d| d.dimension_name.clone())
+                        .unwrap_or_default(),
+                    base_current_score: before.map(|d| d.current_score),
+                    target_current_score: after.map(|d| d.current_score),
+                    current_score_delta: delta(|d| Some(d.current_score)),
+                    base_desired_score: before.map(|d| d.desired_score),
+                    target_desired_score: after.map(|d| d.desired_score),
+                    desired_score_delta: delta(|d| Some(d.desired_score)),
+                    base_gap_severity: before.and_then(|d| d.gap_severity.clone()).map(Into::into),
+                    target_gap_severity: after.and_then(|d| d.gap_severity.clone()).map(Into::into),
+                    gap_severity_delta: delta(|d| d.gap_severity.as_ref().map(severity_level)),
+                }
+            })
+            .collect();
+        dimensions.sort_by(|a, b| a.dimension_name.cmp(&b.dimension_name));
+
+        AssessmentComparisonResponse {
+            base_assessment_id,
+            target_assessment_id,
+            base_maturity_index: base.maturity_index,
+            target_maturity_index: target.maturity_index,
+            maturity_index_delta: base
+                .maturity_index
+                .zip(target.maturity_index)
+                .map(|(before, after)| after - before),
+            dimensions,
+        }
+    }
+}
+
+fn severity_level(severity: &GapSeverity) -> i32 {
+    match severity {
+        GapSeverity::Low => 1,
+        GapSeverity::Medium => 2,
+        GapSeverity::High => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(dimensions: &[(Uuid, &str, i32, i32, GapSeverity)]) -> AssessmentSnapshot {
+        AssessmentSnapshot {
+            maturity_index: Some(50.0),
+            dimensions: dimensions
+                .iter()
+                .map(|(id, name, current, desired, severity)| {
+                    (
+                        *id,
+                        DimensionSnapshot {
+                            dimension_name: name.to_string(),
+                            current_score: *current,
+                            desired_score: *desired,
+                            gap_severity: Some(severity.clone()),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_compare_reports_deltas() {
+        let dimension = Uuid::new_v4();
+        let base = snapshot(&[(dimension, "Strategy", 1, 5, GapSeverity::High)]);
+        let mut target = snapshot(&[(dimension, "Strategy", 3, 5, GapSeverity::Medium)]);
+        target.maturity_index = Some(60.0);
+
+        let comparison =
+            AssessmentComparisonService::compare(Uuid::new_v4(), &base, Uuid::new_v4(), &target);
+
+        let row = &comparison.dimensions[0];
+        assert_eq!(row.current_score_delta, Some(2));
+        assert_eq!(row.desired_score_delta, Some(0));
+        assert_eq!(row.gap_severity_delta, Some(-1));
+        assert_eq!(comparison.maturity_index_delta, Some(10.0));
+    }
+
+    #[test]
+    fn test_dimension_missing_on_one_side_has_no_delta() {
+        let (shared, added) = (Uuid::new_v4(), Uuid::new_v4());
+        let base = snapshot(&[(shared, "A", 2, 4, GapSeverity::Medium)]);
+        let target = snapshot(&[
+            (shared, "A", 2, 4, GapSeverity::Medium),
+            (added, "B", 1, 3, GapSeverity::Medium),
+        ]);
+
+        let comparison =
+            AssessmentComparisonService::compare(Uuid::new_v4(), &base, Uuid::new_v4(), &target);
+
+        assert_eq!(comparison.dimensions.len(), 2);
+        let added_row = &comparison.dimensions[1];
+        assert_eq!(added_row.dimension_id, added);
+        assert_eq!(added_row.base_current_score, None);
+        assert_eq!(added_row.current_score_delta, None);
+    }
+}
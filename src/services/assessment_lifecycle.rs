@@ -0,0 +1,281 @@
+use crate::entities::assessments::{self, AssessmentStatus};
+use crate::error::AppError;
+use sea_orm::Set;
+
+/// Assessment lifecycle:
+///
+/// ```text
+/// draft ──> in_progress ──> completed ──> archived
+///   │                          │             │
+///   └──────> archived          └─ reopen <───┘  (admins only, back to in_progress)
+/// ```
+pub struct AssessmentLifecycleService;
+
+impl AssessmentLifecycleService {
+    /// Whether `from -> to` is a legal transition. Reopening a completed or
+    /// archived assessment requires `is_admin`.
+    pub fn can_transition(from: &AssessmentStatus, to: &AssessmentStatus, is_admin: bool) -> bool {
+        use AssessmentStatus::*;
+        match (from, to) {
+            (Draft, InProgress) | (Draft, Archived) => true,
+            (InProgress, Completed) => true,
+            (Completed, Archived) => true,
+            (Completed, InProgress) | (Archived, InProgress) => is_admin,
+            _ => false,
+        }
+    }
+
+    pub fn validate_transition(
+        from: &AssessmentStatus,
+        to: &AssessmentStatus,
+        is_admin: bool,
+    ) -> Result<(), AppError> {
+        if Self::can_transition(from, to, is_admin) {
+            return Ok(());
+        }
+        let reason = match (from, to) {
+            (AssessmentStatus::Completed, AssessmentStatus::InProgress)
+            | (AssessmentStatus::Archived, AssessmentStatus::InProgress) => {
+                " (only administrators can reopen an assessment)"
+            }
+            _ => "",
+        };
+        Err(AppError::Conflict(format!(
+            "Cannot change assessment status from {} to {}{}",
+            Self::status_name(from),
+            Self::status_name(to),
+            reason
+        )))
+    }
+
+    /// Move `assessment` to `to`, updating `started_at` / `completed_at`.
+    ///
+    /// Setting the current status again is a no-op.
+    pub fn apply_transition(
+        assessment: &assessments::Model,
+        active_model: &mut assessments::ActiveModel,
+        to: AssessmentStatus,
+        is_admin: bool,
+    ) -> Result<(), AppError> {
+        if assessment.status == to {
+            return Ok(());
+        }
+        Self::validate_transition(&assessment.status, &to, is_admin)?;
+
+        let now = chrono::Utc::now();
+        match to {
+            AssessmentStatus::InProgress => {
+                if assessment.started_at.is_none() {
+                    active_model.started_at = Set(Some(now));
+                }
+                // Reopening clears the previous completion and its frozen scores
+                active_model.completed_at = Set(None);
+                active_model.overall_score = Set(None);
+                active_model.scores = Set(None);
+            }
+            AssessmentStatus::Completed => {
+                if assessment.started_at.is_none() {
+                    active_model.started_at = Set(Some(now));
+                }
+                active_model.completed_at = Set(Some(now));
+            }
+            AssessmentStatus::Draft | AssessmentStatus::Archived => {}
+        }
+        active_model.status = Set(to);
+        Ok(())
+    }
+
+    /// Status changes requested through an assessment update. Completing an
+    /// assessment checks its answers, freezes its scores and queues its
+    /// summary report, so it has to go through submission instead.
+    pub fn ensure_status_update(
+        assessment: &assessments::Model,
+        to: &AssessmentStatus,
+    ) -> Result<(), AppError> {
+        if *to == AssessmentStatus::Completed && assessment.status != AssessmentStatus::Completed {
+            return Err(AppError::Conflict(
+                "Assessments are completed by submitting them to /api/submissions/submit"
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Submission completes an in-progress assessment. Unlike a status update,
+    /// submitting a completed assessment again is rejected, so its frozen
+    /// scores and summary report stay as they were.
+    pub fn ensure_submittable(assessment: &assessments::Model) -> Result<(), AppError> {
+        if assessment.status == AssessmentStatus::Completed {
+            return Err(AppError::Conflict(
+                "Assessment has already been submitted".to_string(),
+            ));
+        }
+        Self::validate_transition(&assessment.status, &AssessmentStatus::Completed, false)
+    }
+
+    /// Dimension assessments may only be written while the assessment is open.
+    pub fn ensure_editable(assessment: &assessments::Model) -> Result<(), AppError> {
+        match assessment.status {
+            AssessmentStatus::Draft | AssessmentStatus::InProgress => Ok(()),
+            AssessmentStatus::Completed | AssessmentStatus::Archived => {
+                Err(AppError::Conflict(format!(
+                    "Assessment is {} and can no longer be edited",
+                    Self::status_name(&assessment.status)
+                )))
+            }
+        }
+    }
+
+    fn status_name(status: &AssessmentStatus) -> &'static str {
+        match status {
+            AssessmentStatus::Draft => "draft",
+            AssessmentStatus::InProgress => "in_progress",
+            AssessmentStatus::Completed => "completed",
+            AssessmentStatus::Archived => "archived",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn assessment(status: AssessmentStatus) -> assessments::Model {
+        let now = chrono::Utc::now();
+        assessments::Model {
+            assessment_id: Uuid::new_v4(),
+            organization_id: "org".to_string(),
+            cooperation_id: None,
+            document_title: "Assessment".to_string(),
+            status,
+            started_at: None,
+            completed_at: None,
+            created_at: now,
+            updated_at: now,
+            dimensions_id: None,
+            overall_score: None,
+            scores: None,
+            previous_assessment_id: None,
+            framework_version_id: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn test_forward_transitions_are_allowed() {
+        use AssessmentStatus::*;
+        assert!(AssessmentLifecycleService::can_transition(
+            &Draft,
+            &InProgress,
+            false
+        ));
+        assert!(AssessmentLifecycleService::can_transition(
+            &InProgress,
+            &Completed,
+            false
+        ));
+        assert!(AssessmentLifecycleService::can_transition(
+            &Completed, &Archived, false
+        ));
+    }
+
+    #[test]
+    fn test_skipping_or_going_back_is_rejected() {
+        use AssessmentStatus::*;
+        assert!(!AssessmentLifecycleService::can_transition(
+            &Draft, &Completed, true
+        ));
+        assert!(!AssessmentLifecycleService::can_transition(
+            &InProgress,
+            &Draft,
+            true
+        ));
+        assert!(matches!(
+            AssessmentLifecycleService::validate_transition(&Archived, &Completed, true),
+            Err(AppError::Conflict(_))
+        ));
+    }
+
+    #[test]
+    fn test_reopen_requires_admin() {
+        use AssessmentStatus::*;
+        assert!(!AssessmentLifecycleService::can_transition(
+            &Completed,
+            &InProgress,
+            false
+        ));
+        assert!(AssessmentLifecycleService::can_transition(
+            &Completed,
+            &InProgress,
+            true
+        ));
+        assert!(AssessmentLifecycleService::can_transition(
+            &Archived,
+            &InProgress,
+            true
+        ));
+    }
+
+    #[test]
+    fn test_completion_sets_timestamps() {
+        let mut model = assessment(AssessmentStatus::InProgress);
+        model.started_at = Some(chrono::Utc::now());
+        let mut active: assessments::ActiveModel = model.clone().into();
+
+        AssessmentLifecycleService::apply_transition(
+            &model,
+            &mut active,
+            AssessmentStatus::Completed,
+            false,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            active.completed_at,
+            sea_orm::ActiveValue::Set(Some(_))
+        ));
+        assert_eq!(active.status, Set(AssessmentStatus::Completed));
+    }
+
+    #[test]
+    fn test_completed_assessment_cannot_be_resubmitted() {
+        assert!(AssessmentLifecycleService::ensure_submittable(&assessment(
+            AssessmentStatus::InProgress
+        ))
+        .is_ok());
+        assert!(matches!(
+            AssessmentLifecycleService::ensure_submittable(&assessment(
+                AssessmentStatus::Completed
+            )),
+            Err(AppError::Conflict(_))
+        ));
+    }
+
+    #[test]
+    fn test_status_update_cannot_complete() {
+        assert!(matches!(
+            AssessmentLifecycleService::ensure_status_update(
+                &assessment(AssessmentStatus::InProgress),
+                &AssessmentStatus::Completed
+            ),
+            Err(AppError::Conflict(_))
+        ));
+        assert!(AssessmentLifecycleService::ensure_status_update(
+            &assessment(AssessmentStatus::Completed),
+            &AssessmentStatus::Archived
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_completed_assessment_is_not_editable() {
+        assert!(AssessmentLifecycleService::ensure_editable(&assessment(
+            AssessmentStatus::InProgress
+        ))
+        .is_ok());
+        assert!(AssessmentLifecycleService::ensure_editable(&assessment(
+            AssessmentStatus::Completed
+        ))
+        .is_err());
+    }
+}
@@ -0,0 +1,334 @@
+use std::collections::{HashMap, HashSet};
+
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+use crate::api::dto::analytics::{
+    BenchmarkResponse, DimensionBenchmark, GapSeverityShare, ScoreDistribution,
+};
+use crate::entities::assessments::{self, AssessmentStatus};
+use crate::entities::gaps::{self, GapSeverity};
+use crate::entities::{current_states, desired_states, dimension_assessments, dimensions};
+use crate::error::AppError;
+use crate::repositories::framework_versions::FrameworkVersionsRepository;
+
+/// Dimensions answered by fewer organizations than this are not reported, so
+/// that no single organization can be singled out. Cooperatives of the same
+/// organization count once.
+pub const MIN_COHORT_SIZE: usize = 5;
+
+/// One cooperative's answer for a dimension.
+#[derive(Debug, Clone)]
+pub struct BenchmarkSample {
+    pub organization_id: String,
+    pub current_score: i32,
+    pub desired_score: i32,
+    pub gap_severity: GapSeverity,
+}
+
+/// Anonymized peer distributions over all completed assessments.
+pub struct BenchmarkService;
+
+impl BenchmarkService {
+    pub async fn benchmark(
+        db: &DatabaseConnection,
+        category: Option<String>,
+    ) -> Result<BenchmarkResponse, AppError> {
+        let mut dimension_query = dimensions::Entity::find();
+        if let Some(category) = &category {
+            dimension_query = dimension_query.filter(dimensions::Column::Category.eq(category));
+        }
+        // Answers given against earlier framework versions count towards the
+        // current version's copy of their dimension
+        let all_dimensions = dimension_query.all(db).await?;
+        let origins: HashMap<Uuid, Uuid> = all_dimensions
+            .iter()
+            .map(|d| (d.dimension_id, d.origin_dimension_id))
+            .collect();
+        let current = FrameworkVersionsRepository::find_current(db).await?;
+        let mut dimensions: Vec<dimensions::Model> = all_dimensions
+            .into_iter()
+            .filter(|d| {
+                current
+                    .as_ref()
+                    .is_some_and(|version| version.framework_version_id == d.framework_version_id)
+            })
+            .collect();
+        dimensions.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let latest = Self::latest_per_cooperation(
+            assessments::Entity::find()
+                .filter(assessments::Column::Status.eq(AssessmentStatus::Completed))
+                .all(db)
+                .await?,
+        );
+        let assessment_ids: Vec<Uuid> = latest.iter().map(|a| a.assessment_id).collect();
+        let organization_of: HashMap<Uuid, &str> = latest
+            .iter()
+            .map(|a| (a.assessment_id, a.organization_id.as_str()))
+            .collect();
+
+        let dimension_assessments = dimension_assessments::Entity::find()
+            .filter(dimension_assessments::Column::AssessmentId.is_in(assessment_ids))
+            .filter(
+                dimension_assessments::Column::DimensionId
+                    .is_in(origins.keys().copied().collect::<Vec<_>>()),
+            )
+            .all(db)
+            .await?;
+
+        let current_scores: HashMap<Uuid, i32> = current_states::Entity::find()
+            .filter(
+                current_states::Column::CurrentStateId.is_in(
+                    dimension_assessments
+                        .iter()
+                        .map(|da| da.current_state_id)
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|state| (state.current_state_id, state.score))
+            .collect();
+        let desired_scores: HashMap<Uuid, i32> = desired_states::Entity::find()
+            .filter(
+                desired_states::Column::DesiredStateId.is_in(
+                    dimension_assessments
+                        .iter()
+                        .map(|da| da.desired_state_id)
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|state| (state.desired_state_id, state.score))
+            .collect();
+        let severities: HashMap<Uuid, GapSeverity> = gaps::Entity::find()
+            .filter(
+                gaps::Column::GapId.is_in(
+                    dimension_assessments
+                        .iter()
+                        .map(|da| da.gap_id)
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|gap| (gap.gap_id, gap.gap_severity))
+            .collect();
+
+        let mut samples: HashMap<Uuid, Vec<BenchmarkSample>> = HashMap::new();
+        for da in &dimension_assessments {
+            let (
+                Some(current_score),
+                Some(desired_score),
+                Some(gap_severity),
+                Some(organization_id),
+            ) = (
+                current_scores.get(&da.current_state_id),
+                desired_scores.get(&da.desired_state_id),
+                severities.get(&da.gap_id),
+                organization_of.get(&da.assessment_id),
+            )
+            else {
+                continue;
+            };
+            samples
+                .entry(origins[&da.dimension_id])
+                .or_default()
+                .push(BenchmarkSample {
+                    organization_id: organization_id.to_string(),
+                    current_score: *current_score,
+                    desired_score: *desired_score,
+                    gap_severity: gap_severity.clone(),
+                });
+        }
+
+        Ok(BenchmarkResponse {
+            category,
+            min_cohort_size: MIN_COHORT_SIZE,
+            dimensions: dimensions
+                .into_iter()
+                .map(|dimension| {
+                    let dimension_samples = samples
+                        .remove(&dimension.origin_dimension_id)
+                        .unwrap_or_default();
+                    Self::dimension_benchmark(dimension, &dimension_samples)
+                })
+                .collect(),
+        })
+    }
+
+    /// Latest completed assessment per cooperative (or organization when
+    /// the assessment is not tied to a cooperation)
+    pub fn latest_per_cooperation(completed: Vec<assessments::Model>) -> Vec<assessments::Model> {
+        let mut latest: HashMap<(String, Option<String>), assessments::Model> = HashMap::new();
+        for assessment in completed {
+            let key = (
+                assessment.organization_id.clone(),
+                assessment.cooperation_id.clone(),
+            );
+            let completed = assessment.completed_at.unwrap_or(assessment.created_at);
+            let newer = latest
+                .get(&key)
+                .is_none_or(|kept| completed > kept.completed_at.unwrap_or(kept.created_at));
+            if newer {
+                latest.insert(key, assessment);
+            }
+        }
+        latest.into_values().collect()
+    }
+
+    /// Distribution of one dimension, suppressed when fewer than
+    /// [`MIN_COHORT_SIZE`] organizations answered it.
+    pub fn dimension_benchmark(
+        dimension: dimensions::Model,
+        samples: &[BenchmarkSample],
+    ) -> DimensionBenchmark {
+        let organizations: HashSet<&str> =
+            samples.iter().map(|s| s.organization_id.as_str()).collect();
+        let suppressed = organizations.len() < MIN_COHORT_SIZE;
+        let mut benchmark = DimensionBenchmark {
+            dimension_id: dimension.dimension_id,
+            dimension_name: dimension.name,
+            category: dimension.category,
+            suppressed,
+            cohort_size: None,
+            current_score: None,
+            desired_score: None,
+            gap_severity_share: None,
+        };
+        if suppressed {
+            return benchmark;
+        }
+
+        let current: Vec<i32> = samples.iter().map(|s| s.current_score).collect();
+        let desired: Vec<i32> = samples.iter().map(|s| s.desired_score).collect();
+        let share = |severity: GapSeverity| {
+            samples
+                .iter()
+                .filter(|s| s.gap_severity == severity)
+                .count() as f64
+                / samples.len() as f64
+        };
+
+        benchmark.cohort_size = Some(samples.len());
+        benchmark.current_score = Self::distribution(&current);
+        benchmark.desired_score = Self::distribution(&desired);
+        benchmark.gap_severity_share = Some(GapSeverityShare {
+            low: share(GapSeverity::Low),
+            medium: share(GapSeverity::Medium),
+            high: share(GapSeverity::High),
+        });
+        benchmark
+    }
+
+    /// Quartiles (linear interpolation between closest ranks) and mean.
+    pub fn distribution(values: &[i32]) -> Option<ScoreDistribution> {
+        if values.is_empty() {
+            return None;
+        }
+        let mut sorted: Vec<f64> = values.iter().map(|v| *v as f64).collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let quantile = |q: f64| {
+            let position = q * (sorted.len() - 1) as f64;
+            let lower = position.floor() as usize;
+            let upper = position.ceil() as usize;
+            sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+        };
+
+        Some(ScoreDistribution {
+            lower_quartile: quantile(0.25),
+            median: quantile(0.5),
+            upper_quartile: quantile(0.75),
+            mean: sorted.iter().sum::<f64>() / sorted.len() as f64,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dimension() -> dimensions::Model {
+        let now = chrono::Utc::now().naive_utc();
+        dimensions::Model {
+            dimension_id: Uuid::new_v4(),
+            name: "Strategy".to_string(),
+            description: None,
+            weight: None,
+            category: Some("Organization".to_string()),
+            is_active: Some(true),
+            gap_medium_threshold: None,
+            gap_high_threshold: None,
+            translations: None,
+            framework_version_id: Uuid::nil(),
+            origin_dimension_id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn sample(current_score: i32, gap_severity: GapSeverity) -> BenchmarkSample {
+        BenchmarkSample {
+            organization_id: Uuid::new_v4().to_string(),
+            current_score,
+            desired_score: 5,
+            gap_severity,
+        }
+    }
+
+    #[test]
+    fn test_distribution_quartiles() {
+        let distribution = BenchmarkService::distribution(&[1, 2, 3, 4, 5]).unwrap();
+        assert_eq!(distribution.lower_quartile, 2.0);
+        assert_eq!(distribution.median, 3.0);
+        assert_eq!(distribution.upper_quartile, 4.0);
+        assert_eq!(distribution.mean, 3.0);
+
+        let distribution = BenchmarkService::distribution(&[4, 1, 3, 2]).unwrap();
+        assert_eq!(distribution.median, 2.5);
+        assert!(BenchmarkService::distribution(&[]).is_none());
+    }
+
+    #[test]
+    fn test_small_cohort_is_suppressed() {
+        let samples: Vec<BenchmarkSample> = (1..MIN_COHORT_SIZE)
+            .map(|_| sample(1, GapSeverity::High))
+            .collect();
+        let benchmark = BenchmarkService::dimension_benchmark(dimension(), &samples);
+        assert!(benchmark.suppressed);
+        assert!(benchmark.cohort_size.is_none());
+        assert!(benchmark.current_score.is_none());
+    }
+
+    #[test]
+    fn test_cooperatives_of_one_organization_are_suppressed() {
+        let samples: Vec<BenchmarkSample> = (0..MIN_COHORT_SIZE * 2)
+            .map(|_| BenchmarkSample {
+                organization_id: "org-a".to_string(),
+                ..sample(3, GapSeverity::Medium)
+            })
+            .collect();
+        let benchmark = BenchmarkService::dimension_benchmark(dimension(), &samples);
+        assert!(benchmark.suppressed);
+        assert!(benchmark.current_score.is_none());
+    }
+
+    #[test]
+    fn test_severity_shares() {
+        let mut samples: Vec<BenchmarkSample> =
+            (0..3).map(|_| sample(1, GapSeverity::High)).collect();
+        samples.extend((0..2).map(|_| sample(4, GapSeverity::Low)));
+        let benchmark = BenchmarkService::dimension_benchmark(dimension(), &samples);
+        let share = benchmark.gap_severity_share.unwrap();
+        assert_eq!(share.high, 0.6);
+        assert_eq!(share.low, 0.4);
+        assert_eq!(share.medium, 0.0);
+        assert_eq!(benchmark.cohort_size, Some(5));
+    }
+}
@@ -0,0 +1,188 @@
+use crate::config::BrowserPoolConfig;
+use crate::error::AppError;
+use base64::{engine::general_purpose, Engine as _};
+use bytes::Bytes;
+use headless_chrome::{Browser, LaunchOptions};
+use std::ffi::OsStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tracing::{error, info, instrument, warn};
+
+/// Pooled browsers sit idle between reports, so they must not shut down
+/// after headless_chrome's default 30 seconds without events
+const IDLE_BROWSER_TIMEOUT: Duration = Duration::from_secs(24 * 60 * 60);
+
+struct PooledBrowser {
+    browser: Browser,
+    renders: usize,
+}
+
+/// Bounded pool of warm headless Chrome instances for HTML to PDF
+/// conversion.
+///
+/// At most `size` conversions run at once, including ones that timed out
+/// but are still running in Chrome. Browsers are health checked
+/// before reuse, restarted after `max_renders` conversions and discarded
+/// when a conversion fails or exceeds `render_timeout_secs`.
+pub struct BrowserPool {
+    config: BrowserPoolConfig,
+    idle: Mutex<Vec<PooledBrowser>>,
+    permits: Arc<Semaphore>,
+}
+
+impl BrowserPool {
+    pub fn new(config: BrowserPoolConfig) -> Self {
+        let size = config.size.max(1);
+        Self {
+            config,
+            idle: Mutex::new(Vec::with_capacity(size)),
+            permits: Arc::new(Semaphore::new(size)),
+        }
+    }
+
+    /// Launch browsers until the pool is full
+    pub async fn warm_up(&self) -> Result<(), AppError> {
+        let missing = self.config.size.max(1).saturating_sub(self.idle_count());
+        for _ in 0..missing {
+            let pooled = Self::launch().await?;
+            self.idle.lock().unwrap().push(pooled);
+        }
+        info!(browsers = self.idle_count(), "Browser pool warmed up");
+        Ok(())
+    }
+
+    /// Converts HTML to PDF in a pooled browser tab
+    #[instrument(skip(self, html))]
+    pub async fn html_to_pdf(&self, html: &str) -> Result<Bytes, AppError> {
+        let permit =
+            self.permits.clone().acquire_owned().await.map_err(|e| {
+                AppError::InternalServerError(format!("Browser pool closed: {}", e))
+            })?;
+
+        let mut pooled = self.checkout().await?;
+        let browser = pooled.browser.clone();
+        let html = html.to_string();
+        // The render holds the permit until it really finishes, even after
+        // the caller gave up on it
+        let render = tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+            print_to_pdf(&browser, &html)
+        });
+
+        let timeout = Duration::from_secs(self.config.render_timeout_secs);
+        match tokio::time::timeout(timeout, render).await {
+            Ok(Ok(Ok(pdf))) => {
+                pooled.renders += 1;
+                self.checkin(pooled);
+                Ok(pdf)
+            }
+            Ok(Ok(Err(e))) => {
+                warn!("Discarding browser after failed conversion.");
+                Err(e)
+            }
+            Ok(Err(e)) => Err(AppError::InternalServerError(format!(
+                "PDF conversion task failed: {}",
+                e
+            ))),
+            Err(_) => {
+                // The browser is not returned to the pool; the blocking task
+                // keeps its own handle, so Chrome exits and frees the permit
+                // once the render gives up
+                error!(
+                    timeout_secs = timeout.as_secs(),
+                    "PDF conversion timed out."
+                );
+                Err(AppError::InternalServerError(format!(
+                    "PDF conversion timed out after {} seconds",
+                    timeout.as_secs()
+                )))
+            }
+        }
+    }
+
+    fn idle_count(&self) -> usize {
+        self.idle.lock().unwrap().len()
+    }
+
+    /// Take a responsive idle browser, or launch a new one
+    async fn checkout(&self) -> Result<PooledBrowser, AppError> {
+        loop {
+            let candidate = self.idle.lock().unwrap().pop();
+            let Some(pooled) = candidate else {
+                return Self::launch().await;
+            };
+
+            let browser = pooled.browser.clone();
+            let healthy = tokio::task::spawn_blocking(move || browser.get_version().is_ok())
+                .await
+                .unwrap_or(false);
+            if healthy {
+                return Ok(pooled);
+            }
+            warn!("Discarding unresponsive browser.");
+        }
+    }
+
+    fn checkin(&self, pooled: PooledBrowser) {
+        if pooled.renders >= self.config.max_renders {
+            info!(renders = pooled.renders, "Recycling browser.");
+            return;
+        }
+        self.idle.lock().unwrap().push(pooled);
+    }
+
+    async fn launch() -> Result<PooledBrowser, AppError> {
+        tokio::task::spawn_blocking(|| {
+            info!("Launching headless browser.");
+            Browser::new(LaunchOptions {
+                headless: true,
+                sandbox: true,
+                idle_browser_timeout: IDLE_BROWSER_TIMEOUT,
+                args: vec![OsStr::new("--no-sandbox"), OsStr::new("--disable-gpu")],
+                ..Default::default()
+            })
+            .map(|browser| PooledBrowser {
+                browser,
+                renders: 0,
+            })
+            .map_err(|e| {
+                error!(error = %e, "Failed to launch headless browser.");
+                AppError::InternalServerError(format!("Failed to launch browser: {}", e))
+            })
+        })
+        .await
+        .map_err(|e| AppError::InternalServerError(format!("Browser launch task failed: {}", e)))?
+    }
+}
+
+/// Renders `html` in a fresh tab, closing the tab afterwards
+fn print_to_pdf(browser: &Browser, html: &str) -> Result<Bytes, AppError> {
+    let tab = browser.new_tab().map_err(|e| {
+        error!(error = %e, "Failed to create new browser tab.");
+        AppError::InternalServerError(format!("Failed to create new tab: {}", e))
+    })?;
+
+    // Navigate to a data URL to load the HTML content
+    let data_url = format!(
+        "data:text/html;base64,{}",
+        general_purpose::STANDARD.encode(html)
+    );
+    let result = tab
+        .navigate_to(&data_url)
+        .map_err(|e| {
+            error!(error = %e, "Failed to navigate to data URL in headless browser.");
+            AppError::InternalServerError(format!("Failed to navigate: {}", e))
+        })
+        .and_then(|tab| {
+            tab.print_to_pdf(None).map_err(|e| {
+                error!(error = %e, "Failed to print to PDF.");
+                AppError::InternalServerError(format!("Failed to print to PDF: {}", e))
+            })
+        });
+
+    if let Err(e) = tab.close(false) {
+        warn!(error = %e, "Failed to close browser tab.");
+    }
+    result.map(Bytes::from)
+}
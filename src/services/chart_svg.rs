@@ -0,0 +1,144 @@
+use crate::services::i18n::Locale;
+use crate::services::pdf_generator::{ChartData, PdfGeneratorService};
+use std::fmt::Write;
+
+const WIDTH: f32 = 800.0;
+const HEIGHT: f32 = 400.0;
+const PLOT_LEFT: f32 = 40.0;
+const PLOT_RIGHT: f32 = 20.0;
+const PLOT_TOP: f32 = 40.0;
+const PLOT_BOTTOM: f32 = 90.0;
+/// Longest dimension name shown under a bar group
+const LABEL_CHARS: usize = 24;
+
+const CURRENT_FILL: &str = "#3b82f6";
+const CURRENT_STROKE: &str = "#2563eb";
+const DESIRED_FILL: &str = "#f97316";
+const DESIRED_STROKE: &str = "#ea580c";
+const GRID: &str = "#e2e8f0";
+const MUTED: &str = "#64748b";
+
+/// Draws report charts as standalone SVG so they are embedded in the HTML
+/// without any JavaScript or network access.
+pub struct ChartRenderer;
+
+impl ChartRenderer {
+    /// Grouped bar chart of the current and desired state of each dimension
+    pub fn bar_chart_svg(chart: &ChartData, locale: Locale) -> String {
+        let count = chart.labels.len().max(1);
+        let max_score = chart
+            .current_state
+            .iter()
+            .chain(&chart.desired_state)
+            .copied()
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let plot_width = WIDTH - PLOT_LEFT - PLOT_RIGHT;
+        let plot_height = HEIGHT - PLOT_TOP - PLOT_BOTTOM;
+        let base_y = PLOT_TOP + plot_height;
+        let scale = plot_height / max_score as f32;
+
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {WIDTH} {HEIGHT}" width="100%" font-family="Helvetica, Arial, sans-serif" role="img" aria-label="{}">"#,
+            escape(&locale.text("sections.chart"))
+        );
+
+        // Legend
+        for (index, (label, fill, stroke)) in [
+            ("chart.current_state", CURRENT_FILL, CURRENT_STROKE),
+            ("chart.desired_state", DESIRED_FILL, DESIRED_STROKE),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let x = WIDTH / 2.0 - 120.0 + index as f32 * 130.0;
+            let _ = write!(
+                svg,
+                r#"<rect x="{x}" y="12" width="28" height="12" fill="{fill}" stroke="{stroke}"/><text x="{}" y="22" font-size="12" fill="{MUTED}">{}</text>"#,
+                x + 34.0,
+                escape(&locale.text(label))
+            );
+        }
+
+        // Grid lines and y-axis ticks
+        for tick in 0..=max_score {
+            let y = base_y - tick as f32 * scale;
+            let _ = write!(
+                svg,
+                r#"<line x1="{PLOT_LEFT}" y1="{y}" x2="{}" y2="{y}" stroke="{GRID}" stroke-width="1"/><text x="{}" y="{}" font-size="11" fill="{MUTED}" text-anchor="end">{tick}</text>"#,
+                WIDTH - PLOT_RIGHT,
+                PLOT_LEFT - 8.0,
+                y + 4.0
+            );
+        }
+
+        let group_width = plot_width / count as f32;
+        let bar_width = (group_width * 0.35).min(40.0);
+        for (index, label) in chart.labels.iter().enumerate() {
+            let center = PLOT_LEFT + group_width * (index as f32 + 0.5);
+            let bars = [
+                (
+                    center - bar_width,
+                    chart.current_state.get(index),
+                    CURRENT_FILL,
+                    CURRENT_STROKE,
+                ),
+                (
+                    center,
+                    chart.desired_state.get(index),
+                    DESIRED_FILL,
+                    DESIRED_STROKE,
+                ),
+            ];
+            for (x, score, fill, stroke) in bars {
+                let height = score.copied().unwrap_or(0).max(0) as f32 * scale;
+                let _ = write!(
+                    svg,
+                    r#"<rect x="{x}" y="{}" width="{bar_width}" height="{height}" fill="{fill}" stroke="{stroke}"/>"#,
+                    base_y - height
+                );
+            }
+
+            // Names are slanted so long dimension names do not overlap
+            let label_y = base_y + 14.0;
+            let _ = write!(
+                svg,
+                r#"<text x="{center}" y="{label_y}" font-size="11" fill="{MUTED}" text-anchor="end" transform="rotate(-30 {center} {label_y})">{}</text>"#,
+                escape(&PdfGeneratorService::truncate(label, LABEL_CHARS))
+            );
+        }
+
+        svg.push_str("</svg>");
+        svg
+    }
+}
+
+/// Escape text for use in SVG markup
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_chart_svg() {
+        let chart = ChartData {
+            labels: vec!["Strategy & <Vision>".to_string(), "Data".to_string()],
+            current_state: vec![2, 3],
+            desired_state: vec![4, 5],
+        };
+
+        let svg = ChartRenderer::bar_chart_svg(&chart, Locale::De);
+        assert!(svg.starts_with("<svg") && svg.ends_with("</svg>"));
+        assert!(svg.contains(&Locale::De.text("chart.current_state")));
+        assert!(svg.contains("Strategy &amp; &lt;Vision&gt;"));
+        // Two legend swatches plus two bars per dimension
+        assert_eq!(svg.matches("<rect").count(), 6);
+    }
+}
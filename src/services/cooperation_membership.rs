@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::RwLock;
+use tracing::{debug, error};
+
+use crate::error::AppError;
+use crate::services::keycloak::KeycloakService;
+
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(300);
+
+#[derive(Debug, Clone)]
+struct CachedMembership {
+    cooperation_ids: Vec<String>,
+    fetched_at: Instant,
+}
+
+/// Resolves which cooperations (Keycloak groups) a user belongs to.
+///
+/// Cooperation groups are named `{organization_id}-{name}`, so the lookup lists
+/// the user's groups and keeps those of the organization. Coop users cannot
+/// read groups themselves, so the lookup uses the backend's service account.
+/// Results are cached per organization and user.
+#[derive(Debug)]
+pub struct CooperationMembershipService {
+    keycloak_service: Arc<KeycloakService>,
+    cache: RwLock<HashMap<(String, String), CachedMembership>>,
+    ttl: Duration,
+}
+
+impl CooperationMembershipService {
+    pub fn new(keycloak_service: Arc<KeycloakService>) -> Self {
+        Self::with_ttl(keycloak_service, DEFAULT_CACHE_TTL)
+    }
+
+    pub fn with_ttl(keycloak_service: Arc<KeycloakService>, ttl: Duration) -> Self {
+        Self {
+            keycloak_service,
+            cache: RwLock::new(HashMap::new()),
+            ttl,
+        }
+    }
+
+    /// IDs of the cooperation groups of `organization_id` that `user_id` is a member of.
+    pub async fn cooperation_ids(
+        &self,
+        organization_id: &str,
+        user_id: &str,
+    ) -> Result<Vec<String>, AppError> {
+        let key = (organization_id.to_string(), user_id.to_string());
+
+        if let Some(cached) = self.cache.read().await.get(&key) {
+            if cached.fetched_at.elapsed() < self.ttl {
+                return Ok(cached.cooperation_ids.clone());
+            }
+        }
+
+        let groups = async {
+            let token = self.keycloak_service.service_account_token().await?;
+            self.keycloak_service.get_user_groups(&token, user_id).await
+        }
+        .await
+        .map_err(|e| {
+            error!("Failed to get cooperation groups: {}", e);
+            AppError::InternalServerError("Failed to resolve cooperation membership".to_string())
+        })?;
+
+        let prefix = format!("{}-", organization_id);
+        let cooperation_ids: Vec<String> = groups
+            .into_iter()
+            .filter(|group| group.name.starts_with(&prefix))
+            .map(|group| group.id)
+            .collect();
+
+        debug!(
+            user_id = %user_id,
+            organization_id = %organization_id,
+            count = cooperation_ids.len(),
+            "Resolved cooperation membership"
+        );
+
+        let mut cache = self.cache.write().await;
+        cache.retain(|_, cached| cached.fetched_at.elapsed() < self.ttl);
+        cache.insert(
+            key,
+            CachedMembership {
+                cooperation_ids: cooperation_ids.clone(),
+                fetched_at: Instant::now(),
+            },
+        );
+
+        Ok(cooperation_ids)
+    }
+
+    /// Drop cached memberships of a user, e.g. after they were added to a group.
+    pub async fn invalidate_user(&self, user_id: &str) {
+        self.cache
+            .write()
+            .await
+            .retain(|(_, cached_user), _| cached_user != user_id);
+    }
+}
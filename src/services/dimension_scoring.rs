@@ -1,9 +1,43 @@
-use crate::entities::dimension_assessments;
-use crate::entities::dimensions;
+use crate::entities::{
+    assessments, current_states, desired_states, dimension_assessments, dimensions,
+};
 use crate::error::AppError;
 use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+/// Score of a single assessed dimension.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct DimensionScore {
+    pub dimension_id: Uuid,
+    pub dimension_name: String,
+    /// Relative weight of the dimension (`dimensions.weight / 100`)
+    pub weight: f64,
+    pub current_score: i32,
+    pub desired_score: i32,
+    /// Highest current state score defined for the dimension
+    pub max_score: i32,
+    /// `desired_score - current_score`, never negative
+    pub gap: i32,
+    pub weighted_gap: f64,
+}
+
+/// Weighted scores of a whole assessment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AssessmentScore {
+    pub dimensions: Vec<DimensionScore>,
+    /// Weighted average of the current scores
+    pub overall_current_score: f64,
+    /// Weighted average of the desired scores
+    pub overall_desired_score: f64,
+    /// Weighted average of the gaps
+    pub overall_gap: f64,
+    /// Digital maturity index: weighted average of `current_score / max_score`, 0-100
+    pub maturity_index: f64,
+}
+
 pub struct DimensionScoringService;
 
 impl DimensionScoringService {
@@ -13,7 +47,7 @@ impl DimensionScoringService {
         base_score: i32,
         dimension_weight: Option<i32>,
     ) -> Result<f64, AppError> {
-        let weight = dimension_weight.unwrap_or(100) as f64 / 100.0;
+        let weight = Self::weight_factor(dimension_weight);
         let weighted_score = base_score as f64 * weight;
 
         Ok(weighted_score)
@@ -30,43 +64,153 @@ impl DimensionScoringService {
     }
 
     /// Calculate the total weighted score for an assessment
-    /// This takes into account all dimension weights and their respective scores
+    /// This is the weighted average gap over all assessed dimensions
     pub async fn calculate_total_weighted_score(
         db: &DatabaseConnection,
         assessment_id: Uuid,
     ) -> Result<f64, AppError> {
-        // Get all dimension assessments for this assessment
+        Ok(Self::calculate_assessment_score(db, assessment_id)
+            .await?
+            .overall_gap)
+    }
+
+    /// Scores of `assessment`: the ones frozen at submission if present,
+    /// otherwise computed from its current answers.
+    pub async fn assessment_score(
+        db: &DatabaseConnection,
+        assessment: &assessments::Model,
+    ) -> Result<AssessmentScore, AppError> {
+        match assessment.scores.clone() {
+            Some(stored) => serde_json::from_value(stored)
+                .map_err(|e| AppError::InternalServerError(format!("Invalid stored scores: {e}"))),
+            None => Self::calculate_assessment_score(db, assessment.assessment_id).await,
+        }
+    }
+
+    /// Load the dimension assessments of an assessment with their states and
+    /// dimensions and score them.
+    pub async fn calculate_assessment_score(
+        db: &DatabaseConnection,
+        assessment_id: Uuid,
+    ) -> Result<AssessmentScore, AppError> {
         let dimension_assessments = dimension_assessments::Entity::find()
             .filter(dimension_assessments::Column::AssessmentId.eq(assessment_id))
             .all(db)
             .await
             .map_err(AppError::from)?;
 
-        let mut total_weighted_score = 0.0;
-        let mut total_weight = 0.0;
+        let dimension_ids: Vec<Uuid> = dimension_assessments
+            .iter()
+            .map(|da| da.dimension_id)
+            .collect();
 
+        let dimensions: HashMap<Uuid, dimensions::Model> = dimensions::Entity::find()
+            .filter(dimensions::Column::DimensionId.is_in(dimension_ids.clone()))
+            .all(db)
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|dimension| (dimension.dimension_id, dimension))
+            .collect();
+
+        let current_states: Vec<current_states::Model> = current_states::Entity::find()
+            .filter(current_states::Column::DimensionId.is_in(dimension_ids.clone()))
+            .all(db)
+            .await
+            .map_err(AppError::from)?;
+        let desired_states: HashMap<Uuid, desired_states::Model> = desired_states::Entity::find()
+            .filter(desired_states::Column::DimensionId.is_in(dimension_ids))
+            .all(db)
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|state| (state.desired_state_id, state))
+            .collect();
+
+        let mut max_scores: HashMap<Uuid, i32> = HashMap::new();
+        for state in &current_states {
+            let max = max_scores.entry(state.dimension_id).or_insert(state.score);
+            *max = (*max).max(state.score);
+        }
+        let current_states: HashMap<Uuid, current_states::Model> = current_states
+            .into_iter()
+            .map(|state| (state.current_state_id, state))
+            .collect();
+
+        let mut scores = Vec::with_capacity(dimension_assessments.len());
         for da in dimension_assessments {
-            // Get the dimension to retrieve its weight
-            let dimension = dimensions::Entity::find_by_id(da.dimension_id)
-                .one(db)
-                .await
-                .map_err(AppError::DatabaseError)?
+            let dimension = dimensions
+                .get(&da.dimension_id)
                 .ok_or_else(|| AppError::NotFound("Dimension not found".to_string()))?;
+            let current = current_states
+                .get(&da.current_state_id)
+                .ok_or_else(|| AppError::NotFound("Current state not found".to_string()))?;
+            let desired = desired_states
+                .get(&da.desired_state_id)
+                .ok_or_else(|| AppError::NotFound("Desired state not found".to_string()))?;
 
-            let weight = dimension.weight.unwrap_or(100) as f64 / 100.0;
-            let gap_size = 0.0;
+            scores.push(Self::score_dimension(
+                dimension,
+                current.score,
+                desired.score,
+                max_scores.get(&da.dimension_id).copied().unwrap_or(0),
+            ));
+        }
+
+        Ok(Self::aggregate(scores))
+    }
 
-            // Calculate weighted gap size
-            let weighted_gap = gap_size * weight;
-            total_weighted_score += weighted_gap;
-            total_weight += weight;
+    /// Score a single dimension from its selected current and desired state.
+    pub fn score_dimension(
+        dimension: &dimensions::Model,
+        current_score: i32,
+        desired_score: i32,
+        max_score: i32,
+    ) -> DimensionScore {
+        let weight = Self::weight_factor(dimension.weight);
+        let gap = (desired_score - current_score).max(0);
+
+        DimensionScore {
+            dimension_id: dimension.dimension_id,
+            dimension_name: dimension.name.clone(),
+            weight,
+            current_score,
+            desired_score,
+            max_score: max_score.max(current_score),
+            gap,
+            weighted_gap: gap as f64 * weight,
         }
+    }
+
+    /// Combine dimension scores into weighted averages. Dimensions with a
+    /// weight of 0 are listed but do not contribute to the totals.
+    pub fn aggregate(dimensions: Vec<DimensionScore>) -> AssessmentScore {
+        let total_weight: f64 = dimensions.iter().map(|d| d.weight).sum();
+        let weighted_average = |value: &dyn Fn(&DimensionScore) -> f64| {
+            if total_weight > 0.0 {
+                dimensions.iter().map(|d| value(d) * d.weight).sum::<f64>() / total_weight
+            } else {
+                0.0
+            }
+        };
 
-        // Normalize by total weight to get average weighted score
-        if total_weight > 0.0 {
-            Ok(total_weighted_score / total_weight)
-        } else {
-            Ok(0.0)
+        let overall_current_score = weighted_average(&|d| d.current_score as f64);
+        let overall_desired_score = weighted_average(&|d| d.desired_score as f64);
+        let overall_gap = weighted_average(&|d| d.gap as f64);
+        let maturity_index = weighted_average(&|d| {
+            if d.max_score > 0 {
+                d.current_score as f64 / d.max_score as f64 * 100.0
+            } else {
+                0.0
+            }
+        });
+
+        AssessmentScore {
+            dimensions,
+            overall_current_score,
+            overall_desired_score,
+            overall_gap,
+            maturity_index,
         }
     }
 
@@ -75,17 +219,40 @@ impl DimensionScoringService {
         gap_size: i32,
         dimension_weight: Option<i32>,
     ) -> Result<i32, AppError> {
-        let weight = dimension_weight.unwrap_or(100) as f64 / 100.0;
+        let weight = Self::weight_factor(dimension_weight);
         let priority_score = (gap_size as f64 * weight * 100.0) as i32;
 
         Ok(priority_score)
     }
+
+    fn weight_factor(dimension_weight: Option<i32>) -> f64 {
+        dimension_weight.unwrap_or(100) as f64 / 100.0
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn dimension(weight: Option<i32>) -> dimensions::Model {
+        let now = chrono::Utc::now().naive_utc();
+        dimensions::Model {
+            dimension_id: Uuid::new_v4(),
+            name: "Dimension".to_string(),
+            description: None,
+            weight,
+            category: None,
+            is_active: Some(true),
+            gap_medium_threshold: None,
+            gap_high_threshold: None,
+            translations: None,
+            framework_version_id: Uuid::nil(),
+            origin_dimension_id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
     #[test]
     fn test_calculate_weighted_score() {
         // Test with normal weight
@@ -128,4 +295,40 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 3000); // 30 * 1.0 * 100
     }
+
+    #[test]
+    fn test_score_dimension() {
+        let score = DimensionScoringService::score_dimension(&dimension(Some(50)), 2, 5, 5);
+        assert_eq!(score.gap, 3);
+        assert_eq!(score.weighted_gap, 1.5); // 3 * 0.5
+
+        // Desired below current is no gap
+        let score = DimensionScoringService::score_dimension(&dimension(None), 4, 3, 5);
+        assert_eq!(score.gap, 0);
+    }
+
+    #[test]
+    fn test_aggregate_uses_weights() {
+        let heavy = DimensionScoringService::score_dimension(&dimension(Some(100)), 5, 5, 5);
+        let light = DimensionScoringService::score_dimension(&dimension(Some(25)), 1, 5, 5);
+        let total = DimensionScoringService::aggregate(vec![heavy, light]);
+
+        // (0 * 1.0 + 4 * 0.25) / 1.25
+        assert!((total.overall_gap - 0.8).abs() < 1e-9);
+        // (100 * 1.0 + 20 * 0.25) / 1.25
+        assert!((total.maturity_index - 84.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_aggregate_without_weight_is_zero() {
+        let total =
+            DimensionScoringService::aggregate(vec![DimensionScoringService::score_dimension(
+                &dimension(Some(0)),
+                3,
+                5,
+                5,
+            )]);
+        assert_eq!(total.maturity_index, 0.0);
+        assert_eq!(total.dimensions.len(), 1);
+    }
 }
@@ -0,0 +1,142 @@
+use crate::config::DownloadLinkConfig;
+use crate::error::AppError;
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// A URL a report file can be downloaded from without credentials
+#[derive(Debug, Clone)]
+pub struct DownloadLink {
+    pub url: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Issues and checks HMAC-signed links to the backend's public report file
+/// route, for storage backends that cannot presign URLs themselves.
+///
+/// A signature covers the report and the expiry time, so a link cannot be
+/// reused for another report or extended.
+pub struct DownloadLinkSigner {
+    config: DownloadLinkConfig,
+}
+
+impl DownloadLinkSigner {
+    pub fn new(config: DownloadLinkConfig) -> Self {
+        Self { config }
+    }
+
+    /// Requested lifetime capped to the configured maximum, or the default
+    /// lifetime
+    pub fn lifetime(&self, expires_in_secs: Option<u64>) -> Duration {
+        let secs = expires_in_secs
+            .unwrap_or(self.config.expiry_secs)
+            .clamp(1, self.config.max_expiry_secs.max(1));
+        Duration::seconds(secs as i64)
+    }
+
+    /// Absolute URL of the public file route for `report_id`
+    pub fn signed_url(&self, report_id: Uuid, expires_at: DateTime<Utc>) -> String {
+        let expires = expires_at.timestamp();
+        format!(
+            "{}/public/reports/{}/file?expires={}&signature={}",
+            self.config.base_url.trim_end_matches('/'),
+            report_id,
+            expires,
+            self.signature(report_id, expires)
+        )
+    }
+
+    /// Check a link's signature and expiry time at `now`
+    pub fn verify(
+        &self,
+        report_id: Uuid,
+        expires: i64,
+        signature: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), AppError> {
+        let signature = hex::decode(signature)
+            .map_err(|_| AppError::Forbidden("Invalid download link".to_string()))?;
+        self.mac(report_id, expires)
+            .verify_slice(&signature)
+            .map_err(|_| AppError::Forbidden("Invalid download link".to_string()))?;
+        if now.timestamp() > expires {
+            return Err(AppError::Forbidden("Download link has expired".to_string()));
+        }
+        Ok(())
+    }
+
+    fn signature(&self, report_id: Uuid, expires: i64) -> String {
+        hex::encode(self.mac(report_id, expires).finalize().into_bytes())
+    }
+
+    fn mac(&self, report_id: Uuid, expires: i64) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(self.config.secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(format!("{report_id}:{expires}").as_bytes());
+        mac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signer() -> DownloadLinkSigner {
+        DownloadLinkSigner::new(DownloadLinkConfig {
+            secret: "test-secret".to_string(),
+            base_url: "https://dgat.example.org/".to_string(),
+            expiry_secs: 900,
+            max_expiry_secs: 3600,
+        })
+    }
+
+    fn query_value<'a>(url: &'a str, key: &str) -> &'a str {
+        url.split(['?', '&'])
+            .find_map(|pair| pair.strip_prefix(&format!("{key}=")))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_signed_url_verifies_until_expiry() {
+        let signer = signer();
+        let report_id = Uuid::new_v4();
+        let now = Utc::now();
+        let expires_at = now + signer.lifetime(None);
+
+        let url = signer.signed_url(report_id, expires_at);
+        assert!(url.starts_with(&format!(
+            "https://dgat.example.org/public/reports/{report_id}/file?"
+        )));
+        let expires: i64 = query_value(&url, "expires").parse().unwrap();
+        let signature = query_value(&url, "signature");
+
+        assert!(signer.verify(report_id, expires, signature, now).is_ok());
+        assert!(signer
+            .verify(
+                report_id,
+                expires,
+                signature,
+                expires_at + Duration::seconds(1)
+            )
+            .is_err());
+        assert!(signer
+            .verify(Uuid::new_v4(), expires, signature, now)
+            .is_err());
+        assert!(signer
+            .verify(report_id, expires + 60, signature, now)
+            .is_err());
+        assert!(signer.verify(report_id, expires, "not-hex", now).is_err());
+    }
+
+    #[test]
+    fn test_lifetime_is_capped() {
+        let signer = signer();
+        assert_eq!(signer.lifetime(None), Duration::seconds(900));
+        assert_eq!(signer.lifetime(Some(60)), Duration::seconds(60));
+        assert_eq!(signer.lifetime(Some(86_400)), Duration::seconds(3600));
+        assert_eq!(signer.lifetime(Some(0)), Duration::seconds(1));
+    }
+}
@@ -0,0 +1,462 @@
+use crate::entities::{
+    current_states, desired_states, dimensions, framework_versions, gaps, organisation_dimension,
+    recommendations,
+};
+use crate::error::AppError;
+use crate::repositories::dimensions::DimensionsRepository;
+use crate::repositories::framework_versions::FrameworkVersionsRepository;
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+use std::collections::HashMap;
+use tracing::info;
+use uuid::Uuid;
+
+/// Framework versions:
+///
+/// ```text
+/// create draft (copy of the current version) ──> edit ──> publish
+///                                                  └────> discard
+/// ```
+///
+/// Assessments are pinned to the version that was current when they were
+/// started. Only the dimensions, states, gaps and recommendations of the
+/// draft can be changed, so published versions stay as they were answered.
+pub struct FrameworkVersioningService;
+
+impl FrameworkVersioningService {
+    /// Version new assessments are started with
+    pub async fn current_version<C: ConnectionTrait>(
+        db: &C,
+    ) -> Result<framework_versions::Model, AppError> {
+        FrameworkVersionsRepository::find_current(db)
+            .await?
+            .ok_or_else(|| AppError::NotFound("No framework version is published".to_string()))
+    }
+
+    /// The given version, or the current one
+    pub async fn resolve_version(
+        db: &DbConn,
+        framework_version_id: Option<Uuid>,
+    ) -> Result<framework_versions::Model, AppError> {
+        match framework_version_id {
+            Some(id) => FrameworkVersionsRepository::find_by_id(db, id)
+                .await?
+                .ok_or_else(|| AppError::NotFound("Framework version not found".to_string())),
+            None => Self::current_version(db).await,
+        }
+    }
+
+    /// Draft the framework is edited in
+    pub async fn draft_version(db: &DbConn) -> Result<framework_versions::Model, AppError> {
+        FrameworkVersionsRepository::find_draft(db)
+            .await?
+            .ok_or_else(|| {
+                AppError::Conflict(
+                    "No draft framework version exists; create one to edit the framework"
+                        .to_string(),
+                )
+            })
+    }
+
+    pub fn ensure_draft(version: &framework_versions::Model) -> Result<(), AppError> {
+        if version.is_draft() {
+            return Ok(());
+        }
+        Err(AppError::Conflict(format!(
+            "Framework version {} is published and cannot be changed; edit a draft version instead",
+            version.version_number
+        )))
+    }
+
+    /// The dimension, if its framework version is a draft
+    pub async fn editable_dimension(
+        db: &DbConn,
+        dimension_id: Uuid,
+    ) -> Result<dimensions::Model, AppError> {
+        let dimension = DimensionsRepository::find_by_id(db, dimension_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Dimension not found".to_string()))?;
+        let version = FrameworkVersionsRepository::find_by_id(db, dimension.framework_version_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Framework version not found".to_string()))?;
+        Self::ensure_draft(&version)?;
+        Ok(dimension)
+    }
+
+    /// Start a draft as a copy of the current version, or an empty one when
+    /// nothing is published yet
+    pub async fn create_draft(
+        db: &DbConn,
+        notes: Option<String>,
+        created_by: Option<String>,
+    ) -> Result<framework_versions::Model, AppError> {
+        let txn = db.begin().await?;
+        if let Some(draft) = FrameworkVersionsRepository::find_draft(&txn).await? {
+            return Err(AppError::Conflict(format!(
+                "Framework version {} is already being drafted",
+                draft.version_number
+            )));
+        }
+
+        let now = chrono::Utc::now();
+        let draft = framework_versions::ActiveModel {
+            framework_version_id: Set(Uuid::new_v4()),
+            version_number: Set(FrameworkVersionsRepository::next_version_number(&txn).await?),
+            notes: Set(notes),
+            created_by: Set(created_by),
+            published_at: Set(None),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+        .insert(&txn)
+        .await?;
+        if let Some(current) = FrameworkVersionsRepository::find_current(&txn).await? {
+            Self::copy_framework(
+                &txn,
+                current.framework_version_id,
+                draft.framework_version_id,
+            )
+            .await?;
+        }
+        txn.commit().await?;
+
+        info!(
+            version_number = draft.version_number,
+            "Framework version drafted"
+        );
+        Ok(draft)
+    }
+
+    /// Copy the dimensions of one version, with their states, gaps and
+    /// recommendations, into another
+    async fn copy_framework<C: ConnectionTrait>(
+        db: &C,
+        from_version_id: Uuid,
+        to_version_id: Uuid,
+    ) -> Result<(), AppError> {
+        let dimensions =
+            DimensionsRepository::find_by_framework_version(db, from_version_id).await?;
+        if dimensions.is_empty() {
+            return Ok(());
+        }
+        let now = chrono::Utc::now();
+        let copies: HashMap<Uuid, Uuid> = dimensions
+            .iter()
+            .map(|dimension| (dimension.dimension_id, Uuid::new_v4()))
+            .collect();
+        let dimension_ids: Vec<Uuid> = copies.keys().copied().collect();
+
+        dimensions::Entity::insert_many(dimensions.into_iter().map(|dimension| {
+            dimensions::ActiveModel {
+                dimension_id: Set(copies[&dimension.dimension_id]),
+                name: Set(dimension.name),
+                description: Set(dimension.description),
+                weight: Set(dimension.weight),
+                category: Set(dimension.category),
+                is_active: Set(dimension.is_active),
+                gap_medium_threshold: Set(dimension.gap_medium_threshold),
+                gap_high_threshold: Set(dimension.gap_high_threshold),
+                translations: Set(dimension.translations),
+                framework_version_id: Set(to_version_id),
+                origin_dimension_id: Set(dimension.origin_dimension_id),
+                created_at: Set(now.naive_utc()),
+                updated_at: Set(now.naive_utc()),
+            }
+        }))
+        .exec(db)
+        .await?;
+
+        let current_states = current_states::Entity::find()
+            .filter(current_states::Column::DimensionId.is_in(dimension_ids.clone()))
+            .all(db)
+            .await?;
+        if !current_states.is_empty() {
+            current_states::Entity::insert_many(current_states.into_iter().map(|state| {
+                current_states::ActiveModel {
+                    current_state_id: Set(Uuid::new_v4()),
+                    dimension_id: Set(copies[&state.dimension_id]),
+                    description: Set(state.description),
+                    score: Set(state.score),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                }
+            }))
+            .exec(db)
+            .await?;
+        }
+
+        let desired_states = desired_states::Entity::find()
+            .filter(desired_states::Column::DimensionId.is_in(dimension_ids.clone()))
+            .all(db)
+            .await?;
+        if !desired_states.is_empty() {
+            desired_states::Entity::insert_many(desired_states.into_iter().map(|state| {
+                desired_states::ActiveModel {
+                    desired_state_id: Set(Uuid::new_v4()),
+                    dimension_id: Set(copies[&state.dimension_id]),
+                    description: Set(state.description),
+                    score: Set(state.score),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                }
+            }))
+            .exec(db)
+            .await?;
+        }
+
+        let gaps = gaps::Entity::find()
+            .filter(gaps::Column::DimensionId.is_in(dimension_ids.clone()))
+            .all(db)
+            .await?;
+        if !gaps.is_empty() {
+            gaps::Entity::insert_many(gaps.into_iter().map(|gap| gaps::ActiveModel {
+                gap_id: Set(Uuid::new_v4()),
+                dimension_id: Set(copies[&gap.dimension_id]),
+                gap_size: Set(gap.gap_size),
+                gap_severity: Set(gap.gap_severity),
+                gap_description: Set(gap.gap_description),
+                calculated_at: Set(gap.calculated_at),
+                created_at: Set(now),
+                updated_at: Set(now),
+            }))
+            .exec(db)
+            .await?;
+        }
+
+        let recommendations = recommendations::Entity::find()
+            .filter(recommendations::Column::DimensionId.is_in(dimension_ids))
+            .all(db)
+            .await?;
+        if !recommendations.is_empty() {
+            recommendations::Entity::insert_many(recommendations.into_iter().map(
+                |recommendation| recommendations::ActiveModel {
+                    recommendation_id: Set(Uuid::new_v4()),
+                    dimension_id: Set(copies[&recommendation.dimension_id]),
+                    priority: Set(recommendation.priority),
+                    description: Set(recommendation.description),
+                    translations: Set(recommendation.translations),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                },
+            ))
+            .exec(db)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Publish a draft, making it the version new assessments are started
+    /// with.
+    ///
+    /// Organizations' dimension assignments move to the new copies of their
+    /// dimensions; assignments of dimensions the draft dropped are removed.
+    pub async fn publish(
+        db: &DbConn,
+        framework_version_id: Uuid,
+    ) -> Result<framework_versions::Model, AppError> {
+        let txn = db.begin().await?;
+        let draft = FrameworkVersionsRepository::find_by_id(&txn, framework_version_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Framework version not found".to_string()))?;
+        Self::ensure_draft(&draft)?;
+
+        let dimensions =
+            DimensionsRepository::find_by_framework_version(&txn, framework_version_id).await?;
+        if dimensions.is_empty() {
+            return Err(AppError::ValidationError(
+                "A framework version without dimensions cannot be published".to_string(),
+            ));
+        }
+
+        if let Some(previous) = FrameworkVersionsRepository::find_current(&txn).await? {
+            let previous_dimensions = DimensionsRepository::find_by_framework_version(
+                &txn,
+                previous.framework_version_id,
+            )
+            .await?;
+            let successors = Self::successors(&previous_dimensions, &dimensions);
+            for dimension in &previous_dimensions {
+                let assigned =
+                    organisation_dimension::Column::DimensionId.eq(dimension.dimension_id);
+                match successors.get(&dimension.dimension_id) {
+                    Some(successor) => {
+                        organisation_dimension::Entity::update_many()
+                            .col_expr(
+                                organisation_dimension::Column::DimensionId,
+                                Expr::value(*successor),
+                            )
+                            .filter(assigned)
+                            .exec(&txn)
+                            .await?;
+                    }
+                    None => {
+                        organisation_dimension::Entity::delete_many()
+                            .filter(assigned)
+                            .exec(&txn)
+                            .await?;
+                    }
+                }
+            }
+        }
+
+        let now = chrono::Utc::now();
+        let mut active_model: framework_versions::ActiveModel = draft.into();
+        active_model.published_at = Set(Some(now));
+        active_model.updated_at = Set(now);
+        let published = active_model.update(&txn).await?;
+        txn.commit().await?;
+
+        info!(
+            version_number = published.version_number,
+            "Framework version published"
+        );
+        Ok(published)
+    }
+
+    /// Delete a draft with everything in it
+    pub async fn discard_draft(db: &DbConn, framework_version_id: Uuid) -> Result<(), AppError> {
+        let draft = FrameworkVersionsRepository::find_by_id(db, framework_version_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Framework version not found".to_string()))?;
+        Self::ensure_draft(&draft)?;
+
+        framework_versions::Entity::delete_by_id(framework_version_id)
+            .exec(db)
+            .await?;
+        Ok(())
+    }
+
+    /// Copies of `dimension_ids` (of any version) in the given version, in
+    /// order. Dimensions the version does not have are left out.
+    pub async fn dimensions_in_version(
+        db: &DbConn,
+        dimension_ids: &[Uuid],
+        framework_version_id: Uuid,
+    ) -> Result<Vec<Uuid>, AppError> {
+        let dimensions = dimensions::Entity::find()
+            .filter(dimensions::Column::DimensionId.is_in(dimension_ids.to_vec()))
+            .all(db)
+            .await?;
+        let version_dimensions =
+            DimensionsRepository::find_by_framework_version(db, framework_version_id).await?;
+        let successors = Self::successors(&dimensions, &version_dimensions);
+
+        Ok(dimension_ids
+            .iter()
+            .filter_map(|dimension_id| successors.get(dimension_id).copied())
+            .collect())
+    }
+
+    /// Like [`Self::dimensions_in_version`], but rejects dimensions the
+    /// version has no copy of instead of leaving them out. Dimensions picked
+    /// from another version, e.g. listed just before a publish, are mapped
+    /// to their copies.
+    pub async fn require_dimensions_in_version(
+        db: &DbConn,
+        dimension_ids: &[Uuid],
+        framework_version_id: Uuid,
+    ) -> Result<Vec<Uuid>, AppError> {
+        let dimensions = dimensions::Entity::find()
+            .filter(dimensions::Column::DimensionId.is_in(dimension_ids.to_vec()))
+            .all(db)
+            .await?;
+        let version_dimensions =
+            DimensionsRepository::find_by_framework_version(db, framework_version_id).await?;
+        let successors = Self::successors(&dimensions, &version_dimensions);
+
+        let outside: Vec<String> = dimension_ids
+            .iter()
+            .filter(|dimension_id| !successors.contains_key(dimension_id))
+            .map(Uuid::to_string)
+            .collect();
+        if !outside.is_empty() {
+            return Err(AppError::ValidationError(format!(
+                "Dimensions are not part of the framework version: {}",
+                outside.join(", ")
+            )));
+        }
+        Ok(dimension_ids
+            .iter()
+            .map(|dimension_id| successors[dimension_id])
+            .collect())
+    }
+
+    /// Map each dimension in `from` to the dimension of `to` with the same
+    /// origin
+    pub fn successors(from: &[dimensions::Model], to: &[dimensions::Model]) -> HashMap<Uuid, Uuid> {
+        let by_origin: HashMap<Uuid, Uuid> = to
+            .iter()
+            .map(|dimension| (dimension.origin_dimension_id, dimension.dimension_id))
+            .collect();
+        from.iter()
+            .filter_map(|dimension| {
+                by_origin
+                    .get(&dimension.origin_dimension_id)
+                    .map(|successor| (dimension.dimension_id, *successor))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dimension(framework_version_id: Uuid, origin_dimension_id: Uuid) -> dimensions::Model {
+        let now = chrono::Utc::now().naive_utc();
+        dimensions::Model {
+            dimension_id: Uuid::new_v4(),
+            name: "Strategy".to_string(),
+            description: None,
+            weight: Some(1),
+            category: None,
+            is_active: Some(true),
+            gap_medium_threshold: None,
+            gap_high_threshold: None,
+            translations: None,
+            framework_version_id,
+            origin_dimension_id,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn version(published: bool) -> framework_versions::Model {
+        let now = chrono::Utc::now();
+        framework_versions::Model {
+            framework_version_id: Uuid::new_v4(),
+            version_number: 2,
+            notes: None,
+            created_by: None,
+            published_at: published.then_some(now),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_successors_follow_origin() {
+        let (v1, v2) = (Uuid::new_v4(), Uuid::new_v4());
+        let (kept, dropped, added) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let previous = vec![dimension(v1, kept), dimension(v1, dropped)];
+        let next = vec![dimension(v2, kept), dimension(v2, added)];
+
+        let successors = FrameworkVersioningService::successors(&previous, &next);
+        assert_eq!(successors.len(), 1);
+        assert_eq!(
+            successors.get(&previous[0].dimension_id),
+            Some(&next[0].dimension_id)
+        );
+        assert!(!successors.contains_key(&previous[1].dimension_id));
+    }
+
+    #[test]
+    fn test_only_drafts_are_editable() {
+        assert!(FrameworkVersioningService::ensure_draft(&version(false)).is_ok());
+        assert!(matches!(
+            FrameworkVersioningService::ensure_draft(&version(true)),
+            Err(AppError::Conflict(_))
+        ));
+    }
+}
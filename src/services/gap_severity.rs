@@ -0,0 +1,183 @@
+use crate::entities::gaps::{self, GapSeverity};
+use crate::entities::{current_states, desired_states, dimensions};
+use crate::error::AppError;
+use crate::repositories::{
+    current_states::CurrentStatesRepository, desired_states::DesiredStatesRepository,
+    dimensions::DimensionsRepository, gaps::GapsRepository,
+};
+use sea_orm::DbConn;
+use uuid::Uuid;
+
+/// Default smallest gap rated MEDIUM.
+pub const DEFAULT_MEDIUM_THRESHOLD: i32 = 2;
+/// Default smallest gap rated HIGH.
+pub const DEFAULT_HIGH_THRESHOLD: i32 = 4;
+
+/// Gap sizes at which a dimension's gap becomes MEDIUM and HIGH.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GapThresholds {
+    pub medium: i32,
+    pub high: i32,
+}
+
+impl Default for GapThresholds {
+    fn default() -> Self {
+        Self {
+            medium: DEFAULT_MEDIUM_THRESHOLD,
+            high: DEFAULT_HIGH_THRESHOLD,
+        }
+    }
+}
+
+impl GapThresholds {
+    pub fn new(medium: i32, high: i32) -> Result<Self, AppError> {
+        if medium < 1 || high < medium {
+            return Err(AppError::ValidationError(
+                "Gap thresholds must satisfy 1 <= medium <= high".to_string(),
+            ));
+        }
+        Ok(Self { medium, high })
+    }
+
+    /// Thresholds configured on `dimension`, falling back to the defaults.
+    pub fn for_dimension(dimension: &dimensions::Model) -> Self {
+        let defaults = Self::default();
+        Self {
+            medium: dimension.gap_medium_threshold.unwrap_or(defaults.medium),
+            high: dimension.gap_high_threshold.unwrap_or(defaults.high),
+        }
+    }
+
+    pub fn severity(&self, gap_size: i32) -> GapSeverity {
+        if gap_size >= self.high {
+            GapSeverity::High
+        } else if gap_size >= self.medium {
+            GapSeverity::Medium
+        } else {
+            GapSeverity::Low
+        }
+    }
+}
+
+/// Gap between the selected current and desired state of a dimension.
+#[derive(Debug, Clone)]
+pub struct ResolvedGap {
+    pub gap_size: i32,
+    pub severity: GapSeverity,
+    pub gap: gaps::Model,
+}
+
+pub struct GapSeverityService;
+
+impl GapSeverityService {
+    /// Gap size between two states. A desired state below the current state
+    /// is rejected.
+    pub fn gap_size(
+        current_state: &current_states::Model,
+        desired_state: &desired_states::Model,
+    ) -> Result<i32, AppError> {
+        if desired_state.score < current_state.score {
+            return Err(AppError::ValidationError(
+                "Desired state cannot be below the current state".to_string(),
+            ));
+        }
+        Ok(desired_state.score - current_state.score)
+    }
+
+    /// Compute the gap from the selected states and find the gap entry of the
+    /// dimension for the resulting severity.
+    pub async fn resolve(
+        db: &DbConn,
+        dimension_id: Uuid,
+        current_state_id: Uuid,
+        desired_state_id: Uuid,
+    ) -> Result<ResolvedGap, AppError> {
+        let dimension = DimensionsRepository::find_by_id(db, dimension_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Dimension not found".to_string()))?;
+        let current_state = CurrentStatesRepository::find_by_id(db, current_state_id)
+            .await?
+            .filter(|state| state.dimension_id == dimension_id)
+            .ok_or_else(|| {
+                AppError::ValidationError(
+                    "Current state does not belong to the dimension".to_string(),
+                )
+            })?;
+        let desired_state = DesiredStatesRepository::find_by_id(db, desired_state_id)
+            .await?
+            .filter(|state| state.dimension_id == dimension_id)
+            .ok_or_else(|| {
+                AppError::ValidationError(
+                    "Desired state does not belong to the dimension".to_string(),
+                )
+            })?;
+
+        let gap_size = Self::gap_size(&current_state, &desired_state)?;
+        let severity = GapThresholds::for_dimension(&dimension).severity(gap_size);
+        let gap =
+            GapsRepository::find_by_dimension_and_severity(db, dimension_id, severity.clone())
+                .await?
+                .ok_or_else(|| {
+                    AppError::NotFound(
+                        "Corresponding gap not found for the given dimension and severity"
+                            .to_string(),
+                    )
+                })?;
+
+        Ok(ResolvedGap {
+            gap_size,
+            severity,
+            gap,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_thresholds() {
+        let thresholds = GapThresholds::default();
+        assert_eq!(thresholds.severity(0), GapSeverity::Low);
+        assert_eq!(thresholds.severity(1), GapSeverity::Low);
+        assert_eq!(thresholds.severity(2), GapSeverity::Medium);
+        assert_eq!(thresholds.severity(4), GapSeverity::High);
+    }
+
+    #[test]
+    fn test_custom_thresholds() {
+        let thresholds = GapThresholds::new(1, 2).unwrap();
+        assert_eq!(thresholds.severity(1), GapSeverity::Medium);
+        assert_eq!(thresholds.severity(3), GapSeverity::High);
+
+        assert!(GapThresholds::new(0, 2).is_err());
+        assert!(GapThresholds::new(3, 2).is_err());
+    }
+
+    #[test]
+    fn test_desired_below_current_is_rejected() {
+        let now = chrono::Utc::now();
+        let dimension_id = Uuid::new_v4();
+        let current = current_states::Model {
+            current_state_id: Uuid::new_v4(),
+            dimension_id,
+            description: None,
+            score: 3,
+            created_at: now,
+            updated_at: now,
+        };
+        let mut desired = desired_states::Model {
+            desired_state_id: Uuid::new_v4(),
+            dimension_id,
+            description: None,
+            score: 2,
+            created_at: now,
+            updated_at: now,
+        };
+        assert!(GapSeverityService::gap_size(&current, &desired).is_err());
+
+        desired.score = 5;
+        assert_eq!(GapSeverityService::gap_size(&current, &desired).unwrap(), 2);
+    }
+}
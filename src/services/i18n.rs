@@ -0,0 +1,231 @@
+use crate::error::AppError;
+use chrono::{DateTime, Datelike, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+use utoipa::ToSchema;
+
+/// Translation catalogs of the report labels, one per locale
+static CATALOGS: LazyLock<HashMap<Locale, JsonValue>> = LazyLock::new(|| {
+    [
+        (Locale::En, include_str!("../../locales/en.json")),
+        (Locale::Fr, include_str!("../../locales/fr.json")),
+        (Locale::De, include_str!("../../locales/de.json")),
+        (Locale::Pt, include_str!("../../locales/pt.json")),
+    ]
+    .into_iter()
+    .map(|(locale, catalog)| {
+        let catalog = serde_json::from_str(catalog)
+            .unwrap_or_else(|e| panic!("Invalid {} catalog: {}", locale, e));
+        (locale, catalog)
+    })
+    .collect()
+});
+
+/// Language reports are written in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    Fr,
+    De,
+    Pt,
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "en" => Ok(Locale::En),
+            "fr" => Ok(Locale::Fr),
+            "de" => Ok(Locale::De),
+            "pt" => Ok(Locale::Pt),
+            _ => Err(format!("Invalid locale: {s}")),
+        }
+    }
+}
+
+impl Locale {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Fr => "fr",
+            Locale::De => "de",
+            Locale::Pt => "pt",
+        }
+    }
+
+    /// The whole catalog, exposed to templates as `t`
+    pub fn catalog(&self) -> &'static JsonValue {
+        &CATALOGS[self]
+    }
+
+    /// Label at a dotted `path` such as `columns.category`. Missing labels
+    /// fall back to English, then to the path itself.
+    pub fn text(&self, path: &str) -> String {
+        [*self, Locale::En]
+            .iter()
+            .find_map(|locale| lookup(locale.catalog(), path))
+            .unwrap_or_else(|| path.to_string())
+    }
+
+    /// e.g. `18 octobre 2026 14:05 UTC`
+    pub fn format_date(&self, date: DateTime<Utc>) -> String {
+        let month = self
+            .catalog()
+            .pointer("/format/months")
+            .and_then(|months| months.get(date.month0() as usize))
+            .and_then(JsonValue::as_str)
+            .unwrap_or_default()
+            .to_string();
+        self.text("format.date")
+            .replace("{day}", &date.day().to_string())
+            .replace("{month}", &month)
+            .replace("{year}", &date.year().to_string())
+            .replace("{time}", &date.format("%H:%M").to_string())
+    }
+
+    /// Number with one decimal and the locale's decimal separator
+    pub fn format_decimal(&self, value: f64) -> String {
+        format!("{:.1}", value).replace('.', &self.text("format.decimal_separator"))
+    }
+
+    /// Translation of `field` in a `translations` column, e.g.
+    /// `{"fr": {"name": "Stratégie numérique"}}`
+    pub fn translated(&self, translations: Option<&JsonValue>, field: &str) -> Option<String> {
+        translations?
+            .get(self.code())?
+            .get(field)?
+            .as_str()
+            .filter(|text| !text.is_empty())
+            .map(str::to_string)
+    }
+}
+
+fn lookup(catalog: &JsonValue, path: &str) -> Option<String> {
+    path.split('.')
+        .try_fold(catalog, |value, key| value.get(key))?
+        .as_str()
+        .map(str::to_string)
+}
+
+/// Value of a `translations` column, keyed by locale code. Keys are
+/// normalized to [`Locale::code`], which is what lookups use.
+pub fn translations_to_json<T: Serialize>(
+    translations: &HashMap<String, T>,
+) -> Result<JsonValue, AppError> {
+    let mut normalized: HashMap<&'static str, &T> = HashMap::new();
+    for (code, translation) in translations {
+        let locale = code.parse::<Locale>().map_err(AppError::ValidationError)?;
+        if normalized.insert(locale.code(), translation).is_some() {
+            return Err(AppError::ValidationError(format!(
+                "Duplicate translations for locale {}",
+                locale.code()
+            )));
+        }
+    }
+    serde_json::to_value(normalized).map_err(|e| {
+        AppError::InternalServerError(format!("Failed to serialize translations: {}", e))
+    })
+}
+
+/// Translations stored by `translations_to_json`
+pub fn translations_from_json<T: DeserializeOwned>(
+    translations: Option<JsonValue>,
+) -> Option<HashMap<String, T>> {
+    translations.and_then(|translations| serde_json::from_value(translations).ok())
+}
+
+/// Translated texts of a dimension in one locale
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct DimensionTranslation {
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Translated text of a recommendation in one locale
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct RecommendationTranslation {
+    pub description: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_catalogs_have_english_keys() {
+        fn paths(value: &JsonValue, prefix: &str, out: &mut Vec<String>) {
+            if let Some(object) = value.as_object() {
+                for (key, child) in object {
+                    paths(child, &format!("{prefix}{key}."), out);
+                }
+            } else {
+                out.push(prefix.trim_end_matches('.').to_string());
+            }
+        }
+        let mut english = Vec::new();
+        paths(Locale::En.catalog(), "", &mut english);
+        for locale in [Locale::Fr, Locale::De, Locale::Pt] {
+            let mut keys = Vec::new();
+            paths(locale.catalog(), "", &mut keys);
+            assert_eq!(keys, english, "{} catalog keys differ", locale);
+        }
+    }
+
+    #[test]
+    fn test_format_per_locale() {
+        let date = Utc.with_ymd_and_hms(2026, 10, 18, 14, 5, 0).unwrap();
+        assert_eq!(Locale::En.format_date(date), "October 18, 2026 14:05 UTC");
+        assert_eq!(Locale::De.format_date(date), "18. Oktober 2026 14:05 UTC");
+        assert_eq!(Locale::Fr.format_decimal(62.5), "62,5");
+        assert_eq!(Locale::En.format_decimal(62.0), "62.0");
+    }
+
+    #[test]
+    fn test_translated_text() {
+        let translations = serde_json::json!({"fr": {"name": "Stratégie"}, "de": {"name": ""}});
+        assert_eq!(
+            Locale::Fr.translated(Some(&translations), "name"),
+            Some("Stratégie".to_string())
+        );
+        assert_eq!(Locale::De.translated(Some(&translations), "name"), None);
+        assert_eq!(Locale::Pt.translated(None, "name"), None);
+        assert_eq!(Locale::De.text("columns.category"), "Kategorie");
+        assert_eq!(Locale::Fr.text("missing.key"), "missing.key");
+    }
+
+    #[test]
+    fn test_translation_keys_are_normalized() {
+        let translations = HashMap::from([(
+            "FR".to_string(),
+            DimensionTranslation {
+                name: Some("Stratégie".to_string()),
+                description: None,
+            },
+        )]);
+        let json = translations_to_json(&translations).unwrap();
+        assert_eq!(
+            Locale::Fr.translated(Some(&json), "name"),
+            Some("Stratégie".to_string())
+        );
+
+        let duplicate = HashMap::from([("fr".to_string(), 1), ("Fr".to_string(), 2)]);
+        assert!(matches!(
+            translations_to_json(&duplicate),
+            Err(AppError::ValidationError(_))
+        ));
+    }
+}
@@ -0,0 +1,524 @@
+use std::collections::HashMap;
+
+use crate::api::dto::report::{
+    JsonReport, JsonReportActionItem, JsonReportAssessment, JsonReportDimension, JsonReportGap,
+    JsonReportRecommendation, JsonReportState, JSON_REPORT_SCHEMA_VERSION,
+};
+use crate::entities::{
+    action_items, assessments, current_states, desired_states, dimension_assessments, dimensions,
+    gaps, recommendations,
+};
+use crate::error::AppError;
+use crate::repositories::assessments::AssessmentsRepository;
+use crate::services::dimension_scoring::{AssessmentScore, DimensionScoringService};
+use crate::services::pdf_generator::ReportOptions;
+use bytes::Bytes;
+use sea_orm::{ActiveEnum, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+/// [`crate::services::pdf_generator::REPORT_SECTIONS`] that have a
+/// counterpart in the JSON report
+pub const JSON_REPORT_SECTIONS: [&str; 3] = ["dimensions", "recommendations", "action_plan"];
+
+/// Rows of one answered dimension
+struct DimensionRows {
+    dimension_assessment: dimension_assessments::Model,
+    dimension: dimensions::Model,
+    current_state: current_states::Model,
+    desired_state: desired_states::Model,
+    gap: gaps::Model,
+    action_items: Vec<action_items::Model>,
+}
+
+pub struct JsonReportService;
+
+impl JsonReportService {
+    /// Main entry point to generate a JSON report for an assessment
+    #[instrument(skip(db, options), fields(assessment_id = %assessment_id))]
+    pub async fn generate_assessment_json(
+        db: &DatabaseConnection,
+        assessment_id: Uuid,
+        options: &ReportOptions,
+    ) -> Result<Bytes, AppError> {
+        let report = Self::build_report(db, assessment_id, options).await?;
+        Self::to_bytes(&report)
+    }
+
+    /// Pretty printed JSON file of a report
+    pub fn to_bytes(report: &JsonReport) -> Result<Bytes, AppError> {
+        let json = serde_json::to_vec_pretty(report).map_err(|e| {
+            AppError::InternalServerError(format!("Failed to serialize JSON report: {}", e))
+        })?;
+        Ok(Bytes::from(json))
+    }
+
+    /// Collect the assessment with its dimensions, states, gaps,
+    /// recommendations and action items, as far as `options` include them
+    pub async fn build_report(
+        db: &DatabaseConnection,
+        assessment_id: Uuid,
+        options: &ReportOptions,
+    ) -> Result<JsonReport, AppError> {
+        let assessment = AssessmentsRepository::find_by_id(db, assessment_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Assessment not found".to_string()))?;
+        let scores = DimensionScoringService::assessment_score(db, &assessment).await?;
+
+        let mut dimensions = Vec::new();
+        if options.includes("dimensions") {
+            let rows = Self::fetch_dimension_rows(db, assessment_id).await?;
+            info!(count = rows.len(), "Building JSON report dimensions.");
+
+            let recommendations: HashMap<Uuid, recommendations::Model> =
+                if options.includes("recommendations") {
+                    recommendations::Entity::find()
+                        .filter(
+                            recommendations::Column::RecommendationId.is_in(
+                                rows.iter()
+                                    .flat_map(|r| &r.action_items)
+                                    .map(|item| item.recommendation_id)
+                                    .collect::<Vec<_>>(),
+                            ),
+                        )
+                        .all(db)
+                        .await?
+                        .into_iter()
+                        .map(|recommendation| (recommendation.recommendation_id, recommendation))
+                        .collect()
+                } else {
+                    HashMap::new()
+                };
+            dimensions = rows
+                .into_iter()
+                .map(|rows| Self::dimension(rows, &recommendations, options))
+                .collect();
+        }
+        Ok(Self::assemble(assessment, scores, dimensions, options))
+    }
+
+    /// Dimension assessments of an assessment with their dimensions, states,
+    /// gaps and action items, loaded with one query per table
+    async fn fetch_dimension_rows(
+        db: &DatabaseConnection,
+        assessment_id: Uuid,
+    ) -> Result<Vec<DimensionRows>, AppError> {
+        let dimension_assessments = dimension_assessments::Entity::find()
+            .filter(dimension_assessments::Column::AssessmentId.eq(assessment_id))
+            .all(db)
+            .await?;
+
+        let mut dimensions: HashMap<Uuid, dimensions::Model> = dimensions::Entity::find()
+            .filter(
+                dimensions::Column::DimensionId.is_in(
+                    dimension_assessments
+                        .iter()
+                        .map(|da| da.dimension_id)
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|dimension| (dimension.dimension_id, dimension))
+            .collect();
+        let mut current_states: HashMap<Uuid, current_states::Model> =
+            current_states::Entity::find()
+                .filter(
+                    current_states::Column::CurrentStateId.is_in(
+                        dimension_assessments
+                            .iter()
+                            .map(|da| da.current_state_id)
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+                .all(db)
+                .await?
+                .into_iter()
+                .map(|state| (state.current_state_id, state))
+                .collect();
+        let mut desired_states: HashMap<Uuid, desired_states::Model> =
+            desired_states::Entity::find()
+                .filter(
+                    desired_states::Column::DesiredStateId.is_in(
+                        dimension_assessments
+                            .iter()
+                            .map(|da| da.desired_state_id)
+                            .collect::<Vec<_>>(),
+                    ),
+                )
+                .all(db)
+                .await?
+                .into_iter()
+                .map(|state| (state.desired_state_id, state))
+                .collect();
+        let mut gaps: HashMap<Uuid, gaps::Model> = gaps::Entity::find()
+            .filter(
+                gaps::Column::GapId.is_in(
+                    dimension_assessments
+                        .iter()
+                        .map(|da| da.gap_id)
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|gap| (gap.gap_id, gap))
+            .collect();
+        let mut action_items: HashMap<Uuid, Vec<action_items::Model>> = HashMap::new();
+        for item in action_items::Entity::find()
+            .filter(
+                action_items::Column::DimensionAssessmentId.is_in(
+                    dimension_assessments
+                        .iter()
+                        .map(|da| da.dimension_assessment_id)
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .all(db)
+            .await?
+        {
+            action_items
+                .entry(item.dimension_assessment_id)
+                .or_default()
+                .push(item);
+        }
+
+        dimension_assessments
+            .into_iter()
+            .map(|da| {
+                Ok(DimensionRows {
+                    dimension: dimensions
+                        .remove(&da.dimension_id)
+                        .ok_or_else(|| AppError::NotFound("Dimension not found".to_string()))?,
+                    current_state: current_states
+                        .remove(&da.current_state_id)
+                        .ok_or_else(|| AppError::NotFound("Current state not found".to_string()))?,
+                    desired_state: desired_states
+                        .remove(&da.desired_state_id)
+                        .ok_or_else(|| AppError::NotFound("Desired state not found".to_string()))?,
+                    gap: gaps
+                        .remove(&da.gap_id)
+                        .ok_or_else(|| AppError::NotFound("Gap not found".to_string()))?,
+                    action_items: action_items
+                        .remove(&da.dimension_assessment_id)
+                        .unwrap_or_default(),
+                    dimension_assessment: da,
+                })
+            })
+            .collect()
+    }
+
+    /// One dimension of the report with the recommendations of its action
+    /// items, as far as they are in `recommendations`
+    fn dimension(
+        rows: DimensionRows,
+        recommendations: &HashMap<Uuid, recommendations::Model>,
+        options: &ReportOptions,
+    ) -> JsonReportDimension {
+        let mut dimension_recommendations: Vec<JsonReportRecommendation> = Vec::new();
+        let listed = if options.includes("recommendations") {
+            rows.action_items.as_slice()
+        } else {
+            &[]
+        };
+        for item in listed {
+            if dimension_recommendations
+                .iter()
+                .any(|r| r.recommendation_id == item.recommendation_id)
+            {
+                continue;
+            }
+            if let Some(recommendation) = recommendations.get(&item.recommendation_id) {
+                dimension_recommendations.push(JsonReportRecommendation {
+                    recommendation_id: recommendation.recommendation_id,
+                    priority: recommendation.priority.to_value(),
+                    description: recommendation.description.clone(),
+                });
+            }
+        }
+        let action_items = if options.includes("action_plan") {
+            rows.action_items
+                .into_iter()
+                .map(|item| JsonReportActionItem {
+                    action_item_id: item.id,
+                    recommendation_id: item.recommendation_id,
+                    status: item.status.to_string(),
+                    priority: item.priority.to_string(),
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let (current_state, desired_state) = (rows.current_state, rows.desired_state);
+        JsonReportDimension {
+            dimension_assessment_id: rows.dimension_assessment.dimension_assessment_id,
+            dimension_id: rows.dimension.dimension_id,
+            name: rows.dimension.name,
+            category: rows.dimension.category,
+            weight: rows.dimension.weight,
+            gap: JsonReportGap {
+                gap_id: rows.gap.gap_id,
+                size: desired_state.score - current_state.score,
+                severity: rows.gap.gap_severity.into(),
+                description: rows.gap.gap_description,
+            },
+            current_state: JsonReportState {
+                state_id: current_state.current_state_id,
+                score: current_state.score,
+                description: current_state.description,
+            },
+            desired_state: JsonReportState {
+                state_id: desired_state.desired_state_id,
+                score: desired_state.score,
+                description: desired_state.description,
+            },
+            recommendations: dimension_recommendations,
+            action_items,
+        }
+    }
+
+    fn assemble(
+        assessment: assessments::Model,
+        scores: AssessmentScore,
+        mut dimensions: Vec<JsonReportDimension>,
+        options: &ReportOptions,
+    ) -> JsonReport {
+        dimensions.sort_by(|a, b| a.name.cmp(&b.name));
+        JsonReport {
+            schema_version: JSON_REPORT_SCHEMA_VERSION.to_string(),
+            generated_at: chrono::Utc::now(),
+            sections: JSON_REPORT_SECTIONS
+                .iter()
+                .filter(|section| options.includes(section))
+                .map(|section| section.to_string())
+                .collect(),
+            assessment: JsonReportAssessment {
+                assessment_id: assessment.assessment_id,
+                title: assessment.document_title,
+                organization_id: assessment.organization_id,
+                cooperation_id: assessment.cooperation_id,
+                status: assessment.status.to_value(),
+                started_at: assessment.started_at,
+                completed_at: assessment.completed_at,
+                previous_assessment_id: assessment.previous_assessment_id,
+            },
+            scores,
+            dimensions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::action_items::{ActionItemPriority, ActionItemStatus};
+    use crate::entities::assessments::AssessmentStatus;
+    use crate::entities::gaps::GapSeverity;
+    use crate::entities::recommendations::RecommendationPriority;
+    use crate::entities::reports::ReportType;
+
+    fn assessment() -> assessments::Model {
+        let now = chrono::Utc::now();
+        assessments::Model {
+            assessment_id: Uuid::new_v4(),
+            organization_id: "org".to_string(),
+            cooperation_id: Some("org-coop".to_string()),
+            document_title: "Assessment".to_string(),
+            status: AssessmentStatus::Completed,
+            started_at: Some(now),
+            completed_at: Some(now),
+            created_at: now,
+            updated_at: now,
+            dimensions_id: None,
+            overall_score: None,
+            scores: None,
+            previous_assessment_id: None,
+            framework_version_id: Uuid::new_v4(),
+        }
+    }
+
+    fn scores() -> AssessmentScore {
+        AssessmentScore {
+            dimensions: Vec::new(),
+            overall_current_score: 2.0,
+            overall_desired_score: 4.0,
+            overall_gap: 2.0,
+            maturity_index: 40.0,
+        }
+    }
+
+    fn rows(recommendation: &recommendations::Model) -> DimensionRows {
+        let now = chrono::Utc::now();
+        let dimension_id = Uuid::new_v4();
+        let da = dimension_assessments::Model {
+            dimension_assessment_id: Uuid::new_v4(),
+            assessment_id: Uuid::new_v4(),
+            dimension_id,
+            current_state_id: Uuid::new_v4(),
+            desired_state_id: Uuid::new_v4(),
+            gap_score: 3,
+            gap_id: Uuid::new_v4(),
+            organization_id: "org".to_string(),
+            cooperation_id: Some("org-coop".to_string()),
+            created_at: now,
+            updated_at: now,
+        };
+        DimensionRows {
+            dimension: dimensions::Model {
+                dimension_id,
+                name: "Digital strategy".to_string(),
+                description: None,
+                weight: Some(2),
+                category: None,
+                is_active: Some(true),
+                gap_medium_threshold: None,
+                gap_high_threshold: None,
+                translations: None,
+                framework_version_id: Uuid::new_v4(),
+                origin_dimension_id: dimension_id,
+                created_at: now.naive_utc(),
+                updated_at: now.naive_utc(),
+            },
+            current_state: current_states::Model {
+                current_state_id: da.current_state_id,
+                dimension_id,
+                description: None,
+                score: 1,
+                created_at: now,
+                updated_at: now,
+            },
+            desired_state: desired_states::Model {
+                desired_state_id: da.desired_state_id,
+                dimension_id,
+                description: None,
+                score: 4,
+                created_at: now,
+                updated_at: now,
+            },
+            gap: gaps::Model {
+                gap_id: da.gap_id,
+                dimension_id,
+                gap_size: 3,
+                gap_severity: GapSeverity::High,
+                gap_description: None,
+                calculated_at: now,
+                created_at: now,
+                updated_at: now,
+            },
+            action_items: vec![action_items::Model {
+                id: Uuid::new_v4(),
+                action_plan_id: Uuid::new_v4(),
+                recommendation_id: recommendation.recommendation_id,
+                dimension_assessment_id: da.dimension_assessment_id,
+                status: ActionItemStatus::Todo,
+                priority: ActionItemPriority::High,
+                created_at: now,
+                updated_at: now,
+            }],
+            dimension_assessment: da,
+        }
+    }
+
+    fn recommendation() -> recommendations::Model {
+        let now = chrono::Utc::now();
+        recommendations::Model {
+            recommendation_id: Uuid::new_v4(),
+            dimension_id: Uuid::new_v4(),
+            priority: RecommendationPriority::High,
+            description: "Write a digital strategy".to_string(),
+            translations: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn render(options: &ReportOptions) -> serde_json::Value {
+        let recommendation = recommendation();
+        let recommendations =
+            HashMap::from([(recommendation.recommendation_id, recommendation.clone())]);
+        let dimension =
+            JsonReportService::dimension(rows(&recommendation), &recommendations, options);
+        let report = JsonReportService::assemble(assessment(), scores(), vec![dimension], options);
+        assert_eq!(report.schema_version, JSON_REPORT_SCHEMA_VERSION);
+        serde_json::to_value(&report).unwrap()
+    }
+
+    fn keys(value: &serde_json::Value) -> Vec<&str> {
+        let mut keys: Vec<&str> = value
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        keys
+    }
+
+    #[test]
+    fn test_report_shape() {
+        let report = render(&ReportOptions::for_type(ReportType::Detailed));
+        assert_eq!(report["schema_version"], JSON_REPORT_SCHEMA_VERSION);
+        assert_eq!(
+            keys(&report),
+            [
+                "assessment",
+                "dimensions",
+                "generated_at",
+                "schema_version",
+                "scores",
+                "sections"
+            ]
+        );
+        assert_eq!(
+            report["sections"],
+            serde_json::json!(["dimensions", "recommendations", "action_plan"])
+        );
+
+        let dimension = &report["dimensions"][0];
+        assert_eq!(
+            keys(dimension),
+            [
+                "action_items",
+                "category",
+                "current_state",
+                "desired_state",
+                "dimension_assessment_id",
+                "dimension_id",
+                "gap",
+                "name",
+                "recommendations",
+                "weight"
+            ]
+        );
+        assert_eq!(dimension["gap"]["size"], 3);
+        assert_eq!(dimension["recommendations"][0]["priority"], "HIGH");
+        assert_eq!(dimension["action_items"][0]["status"], "todo");
+    }
+
+    #[test]
+    fn test_options_leave_out_sections() {
+        let report = render(&ReportOptions::for_type(ReportType::Summary));
+        assert_eq!(report["sections"], serde_json::json!(["dimensions"]));
+        assert_eq!(
+            report["dimensions"][0]["recommendations"],
+            serde_json::json!([])
+        );
+        assert_eq!(
+            report["dimensions"][0]["action_items"],
+            serde_json::json!([])
+        );
+
+        let options = ReportOptions::new(
+            ReportType::Detailed,
+            None,
+            None,
+            Some(vec!["action_plan".to_string()]),
+        )
+        .unwrap();
+        let report = render(&options);
+        assert_eq!(report["sections"], serde_json::json!(["action_plan"]));
+    }
+}
@@ -1201,6 +1201,59 @@ impl KeycloakService {
         }
     }
 
+    /// Access token of the backend's own client (client credentials grant),
+    /// for lookups the caller's token may not be allowed to make
+    pub async fn service_account_token(&self) -> Result<String> {
+        let url = format!(
+            "{}/realms/{}/protocol/openid-connect/token",
+            self.config.keycloak.url, self.config.keycloak.realm
+        );
+
+        let response = self
+            .client
+            .post(&url)
+            .form(&[
+                ("grant_type", "client_credentials"),
+                ("client_id", self.config.keycloak.client_id.as_str()),
+                ("client_secret", self.config.keycloak.client_secret.as_str()),
+            ])
+            .send()
+            .await?
+            .error_for_status()?;
+
+        let token: TokenResponse = response.json().await?;
+        Ok(token.access_token)
+    }
+
+    /// Groups a user is a member of. Names are returned as stored in
+    /// Keycloak, including the organization prefix.
+    pub async fn get_user_groups(&self, token: &str, user_id: &str) -> Result<Vec<KeycloakGroup>> {
+        let url = format!(
+            "{}/admin/realms/{}/users/{}/groups",
+            self.config.keycloak.url, self.config.keycloak.realm, user_id
+        );
+        debug!(user_id = %user_id, "Getting user groups");
+
+        let response = self
+            .client
+            .get(&url)
+            .bearer_auth(token)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        let raw_groups: Vec<RawKeycloakGroup> = response.json().await?;
+        Ok(raw_groups
+            .into_iter()
+            .map(|g| KeycloakGroup {
+                id: g.id,
+                name: g.name,
+                path: g.path,
+                description: g.description,
+            })
+            .collect())
+    }
+
     /// Get all members of a group
     pub async fn get_group_members(
         &self,
@@ -0,0 +1,249 @@
+use crate::config::LocalStorageConfig;
+use crate::error::AppError;
+use crate::services::s3_storage::{FileStorageService, StoredFile};
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tracing::warn;
+use uuid::Uuid;
+
+/// Stores report files on the local disk, under the same object names as
+/// `S3StorageService`.
+///
+/// Files are written to a temporary file next to their destination and
+/// renamed into place, so readers never see a partially written report.
+pub struct LocalStorageService {
+    root_dir: PathBuf,
+}
+
+impl LocalStorageService {
+    pub async fn new(config: &LocalStorageConfig) -> Result<Self, AppError> {
+        let root_dir = PathBuf::from(&config.root_dir);
+        fs::create_dir_all(&root_dir).await.map_err(|e| {
+            AppError::FileStorageError(format!(
+                "Failed to create storage directory {}: {e}",
+                root_dir.display()
+            ))
+        })?;
+
+        Ok(Self { root_dir })
+    }
+
+    /// Path of an object, rejecting names that would escape the root
+    /// directory
+    fn object_path(&self, object_name: &str) -> Result<PathBuf, AppError> {
+        let relative = Path::new(object_name);
+        let is_plain = relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+        if object_name.is_empty() || !is_plain {
+            return Err(AppError::FileStorageError(format!(
+                "Invalid object name: {object_name}"
+            )));
+        }
+        Ok(self.root_dir.join(relative))
+    }
+
+    pub async fn upload_file(
+        &self,
+        object_name: &str,
+        data: Bytes,
+        _content_type: &str,
+    ) -> Result<String, AppError> {
+        let path = self.object_path(object_name)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await.map_err(|e| {
+                AppError::FileStorageError(format!("Failed to create directory: {e}"))
+            })?;
+        }
+
+        let temp_path = path.with_extension(format!("{}.tmp", Uuid::new_v4()));
+        if let Err(e) = write_synced(&temp_path, &data).await {
+            if let Err(cleanup) = fs::remove_file(&temp_path).await {
+                warn!(error = %cleanup, path = %temp_path.display(), "Failed to remove temporary file.");
+            }
+            return Err(AppError::FileStorageError(format!(
+                "Failed to upload file: {e}"
+            )));
+        }
+        fs::rename(&temp_path, &path)
+            .await
+            .map_err(|e| AppError::FileStorageError(format!("Failed to upload file: {e}")))?;
+
+        Ok(format!("file://{}", path.display()))
+    }
+
+    pub async fn download_file(&self, object_name: &str) -> Result<Bytes, AppError> {
+        let path = self.object_path(object_name)?;
+        let data = fs::read(&path)
+            .await
+            .map_err(|e| AppError::FileStorageError(format!("Failed to download file: {e}")))?;
+
+        Ok(Bytes::from(data))
+    }
+
+    /// Deleting a missing file succeeds, as it does on S3
+    pub async fn delete_file(&self, object_name: &str) -> Result<(), AppError> {
+        let path = self.object_path(object_name)?;
+        match fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(AppError::FileStorageError(format!(
+                "Failed to delete file: {e}"
+            ))),
+        }
+    }
+
+    /// Every file whose object name starts with `prefix`
+    pub async fn list_files(&self, prefix: &str) -> Result<Vec<StoredFile>, AppError> {
+        let list_error =
+            |e: std::io::Error| AppError::FileStorageError(format!("Failed to list files: {e}"));
+
+        let mut files = Vec::new();
+        let mut directories = vec![self.root_dir.clone()];
+        while let Some(directory) = directories.pop() {
+            let mut entries = fs::read_dir(&directory).await.map_err(list_error)?;
+            while let Some(entry) = entries.next_entry().await.map_err(list_error)? {
+                let metadata = entry.metadata().await.map_err(list_error)?;
+                if metadata.is_dir() {
+                    directories.push(entry.path());
+                    continue;
+                }
+                let Some(object_name) = self.object_name(&entry.path()) else {
+                    continue;
+                };
+                if object_name.starts_with(prefix) {
+                    files.push(StoredFile {
+                        object_name,
+                        last_modified: metadata.modified().ok().map(DateTime::<Utc>::from),
+                    });
+                }
+            }
+        }
+
+        Ok(files)
+    }
+
+    /// Object name of a file under the root directory, `/` separated
+    fn object_name(&self, path: &Path) -> Option<String> {
+        let relative = path.strip_prefix(&self.root_dir).ok()?;
+        let parts: Option<Vec<&str>> = relative
+            .components()
+            .map(|component| component.as_os_str().to_str())
+            .collect();
+        Some(parts?.join("/"))
+    }
+}
+
+async fn write_synced(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    let mut file = fs::File::create(path).await?;
+    file.write_all(data).await?;
+    file.sync_all().await
+}
+
+#[async_trait]
+impl FileStorageService for LocalStorageService {
+    async fn upload_file(
+        &self,
+        object_name: &str,
+        data: Bytes,
+        content_type: &str,
+    ) -> Result<String, AppError> {
+        self.upload_file(object_name, data, content_type).await
+    }
+
+    async fn download_file(&self, object_name: &str) -> Result<Bytes, AppError> {
+        self.download_file(object_name).await
+    }
+
+    async fn delete_file(&self, object_name: &str) -> Result<(), AppError> {
+        self.delete_file(object_name).await
+    }
+
+    async fn list_files(&self, prefix: &str) -> Result<Vec<StoredFile>, AppError> {
+        self.list_files(prefix).await
+    }
+
+    /// Files on disk are only reachable through the API, which signs its
+    /// own links
+    async fn presigned_download_url(
+        &self,
+        _object_name: &str,
+        _filename: &str,
+        _expires_in: Duration,
+    ) -> Result<Option<String>, AppError> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::s3_storage::report_object_name;
+
+    async fn storage() -> LocalStorageService {
+        let root_dir = std::env::temp_dir().join(format!("dgat-storage-{}", Uuid::new_v4()));
+        LocalStorageService::new(&LocalStorageConfig {
+            root_dir: root_dir.to_string_lossy().into_owned(),
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_upload_download_delete() {
+        let storage = storage().await;
+        let object_name = report_object_name(&Uuid::new_v4(), "PDF");
+
+        let url = storage
+            .upload_file(
+                &object_name,
+                Bytes::from_static(b"first"),
+                "application/pdf",
+            )
+            .await
+            .unwrap();
+        assert!(url.starts_with("file://") && url.ends_with("report.pdf"));
+        storage
+            .upload_file(
+                &object_name,
+                Bytes::from_static(b"second"),
+                "application/pdf",
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            storage.download_file(&object_name).await.unwrap(),
+            Bytes::from_static(b"second")
+        );
+
+        // Only the final file is left behind
+        let files = storage.list_files("reports/").await.unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].object_name, object_name);
+        assert!(files[0].last_modified.is_some());
+        assert!(storage.list_files("other/").await.unwrap().is_empty());
+
+        storage.delete_file(&object_name).await.unwrap();
+        storage.delete_file(&object_name).await.unwrap();
+        assert!(storage.download_file(&object_name).await.is_err());
+
+        std::fs::remove_dir_all(&storage.root_dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rejects_names_outside_root() {
+        let storage = storage().await;
+        for object_name in ["", "../secret", "/etc/passwd", "reports/../../secret"] {
+            assert!(storage.object_path(object_name).is_err(), "{object_name}");
+        }
+        assert!(storage.object_path("reports/a/report.pdf").is_ok());
+
+        std::fs::remove_dir_all(&storage.root_dir).unwrap();
+    }
+}
@@ -0,0 +1,80 @@
+use sea_orm::DatabaseConnection;
+
+use crate::api::dto::analytics::MaturityTrendPoint;
+use crate::entities::assessments;
+use crate::error::AppError;
+use crate::services::dimension_scoring::DimensionScoringService;
+
+/// Builds maturity time series from completed assessments.
+pub struct MaturityTrendService;
+
+impl MaturityTrendService {
+    /// One point per completed assessment, ordered by completion date.
+    pub async fn build_points(
+        db: &DatabaseConnection,
+        mut assessments: Vec<assessments::Model>,
+    ) -> Result<Vec<MaturityTrendPoint>, AppError> {
+        assessments.sort_by_key(|a| a.completed_at.unwrap_or(a.created_at));
+
+        let mut points = Vec::with_capacity(assessments.len());
+        for assessment in assessments {
+            let score = DimensionScoringService::assessment_score(db, &assessment).await?;
+            points.push(MaturityTrendPoint {
+                assessment_id: assessment.assessment_id,
+                document_title: assessment.document_title,
+                cooperation_id: assessment.cooperation_id,
+                completed_at: assessment.completed_at,
+                maturity_index: assessment.overall_score.unwrap_or(score.maturity_index),
+                overall_current_score: score.overall_current_score,
+                overall_desired_score: score.overall_desired_score,
+                overall_gap: score.overall_gap,
+                dimensions: score.dimensions,
+            });
+        }
+
+        Ok(points)
+    }
+
+    /// Change of the maturity index between the first and the last point.
+    pub fn maturity_index_change(points: &[MaturityTrendPoint]) -> Option<f64> {
+        match (points.first(), points.last()) {
+            (Some(first), Some(last)) if points.len() > 1 => {
+                Some(last.maturity_index - first.maturity_index)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn point(maturity_index: f64) -> MaturityTrendPoint {
+        MaturityTrendPoint {
+            assessment_id: Uuid::new_v4(),
+            document_title: "Assessment".to_string(),
+            cooperation_id: None,
+            completed_at: None,
+            maturity_index,
+            overall_current_score: 0.0,
+            overall_desired_score: 0.0,
+            overall_gap: 0.0,
+            dimensions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_maturity_index_change() {
+        assert_eq!(MaturityTrendService::maturity_index_change(&[]), None);
+        assert_eq!(
+            MaturityTrendService::maturity_index_change(&[point(40.0)]),
+            None
+        );
+        assert_eq!(
+            MaturityTrendService::maturity_index_change(&[point(40.0), point(45.0), point(55.0)]),
+            Some(15.0)
+        );
+    }
+}
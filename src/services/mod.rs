@@ -1,6 +1,26 @@
 pub mod action_plan_service;
+pub mod assessment_comparison;
+pub mod assessment_lifecycle;
+pub mod benchmark;
+pub mod browser_pool;
+pub mod chart_svg;
+pub mod cooperation_membership;
+pub mod dimension_scoring;
+pub mod download_links;
+pub mod framework_versioning;
+pub mod gap_severity;
+pub mod i18n;
+pub mod json_report;
 pub mod keycloak;
+pub mod local_storage;
+pub mod maturity_trend;
+pub mod native_pdf;
 pub mod pdf_generator;
+pub mod portfolio_report;
+pub mod report_queue;
+pub mod report_retention;
 pub mod report_service;
+pub mod report_templates;
 pub mod s3_storage;
 pub mod submission_service;
+pub mod xlsx_generator;
@@ -0,0 +1,827 @@
+use crate::error::AppError;
+use crate::services::i18n::Locale;
+use crate::services::pdf_generator::{
+    PdfGeneratorService, PdfReportData, PdfReportRow, ReportActionItem, ReportOptions,
+};
+use crate::services::portfolio_report::{PortfolioReportData, PortfolioReportService};
+use bytes::Bytes;
+use printpdf::{
+    path::PaintMode, BuiltinFont, Color, IndirectFontRef, Line, Mm, PdfDocument,
+    PdfDocumentReference, PdfLayerReference, Point, Rect, Rgb,
+};
+
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 15.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+const FOOTER_HEIGHT: f32 = 12.0;
+/// Millimetres per typographic point
+const PT: f32 = 0.3528;
+/// Average Helvetica glyph width relative to the font size
+const GLYPH_WIDTH: f32 = 0.5;
+const CELL_PADDING: f32 = 1.5;
+
+const PRIMARY: u32 = 0x1e40af;
+const MUTED: u32 = 0x64748b;
+const BORDER: u32 = 0xe2e8f0;
+const CURRENT_BAR: u32 = 0x3b82f6;
+const DESIRED_BAR: u32 = 0xf97316;
+
+/// Renders reports straight to PDF with `printpdf`, for deployments without
+/// a Chrome installation. Mirrors the sections of the HTML templates.
+pub struct NativePdfRenderer;
+
+impl NativePdfRenderer {
+    pub fn render(data: &PdfReportData, options: &ReportOptions) -> Result<Bytes, AppError> {
+        let locale = data.locale;
+        let heading = PdfGeneratorService::report_heading(&options.report_type, locale);
+        let mut canvas = Self::start(
+            &heading,
+            &data.assessment_title,
+            &data.generation_date,
+            locale,
+        )?;
+
+        let (rows, actions) = PdfGeneratorService::ordered_items(data, options);
+        if options.includes("overview") {
+            Self::overview(&mut canvas, data);
+        }
+        if options.includes("chart") && !rows.is_empty() {
+            Self::chart(&mut canvas, &rows, locale);
+        }
+        if options.includes("dimensions") {
+            Self::dimensions(
+                &mut canvas,
+                &rows,
+                options.includes("recommendations"),
+                locale,
+            );
+        }
+        if options.includes("action_plan") {
+            Self::action_plan(&mut canvas, &actions, locale);
+        }
+
+        canvas.finish()
+    }
+
+    /// Portfolio report: overview, heatmap of the cooperatives, weakest
+    /// dimensions and the most common high gaps and recommendations
+    pub fn render_portfolio(data: &PortfolioReportData) -> Result<Bytes, AppError> {
+        let locale = data.locale;
+        let heading = PortfolioReportService::report_heading(locale);
+        let mut canvas = Self::start(&heading, &data.title, &data.generation_date, locale)?;
+
+        Self::cards(
+            &mut canvas,
+            &[
+                (
+                    data.assessments.len().to_string(),
+                    locale.text("portfolio.cooperatives"),
+                    None,
+                ),
+                (
+                    data.average_maturity
+                        .map(|score| locale.format_decimal(score))
+                        .unwrap_or_else(|| "-".to_string()),
+                    locale.text("portfolio.average_maturity"),
+                    None,
+                ),
+                (
+                    data.dimensions.len().to_string(),
+                    locale.text("overview.dimensions"),
+                    None,
+                ),
+            ],
+        );
+        if data.assessments.is_empty() {
+            canvas.text(
+                &locale.text("portfolio.no_assessments"),
+                9.0,
+                MARGIN,
+                canvas.y - 4.0,
+                false,
+                0x000000,
+            );
+            return canvas.finish();
+        }
+
+        // Heatmap: one row per cooperative, one column per dimension
+        let label_width = 34.0;
+        let score_width = 16.0;
+        let cell_width =
+            (CONTENT_WIDTH - label_width - score_width) / data.dimensions.len().max(1) as f32;
+        let mut columns = vec![
+            Column::new(locale.text("portfolio.cooperative"), label_width),
+            Column::new(locale.text("overview.maturity_index"), score_width),
+        ];
+        columns.extend(
+            data.dimensions
+                .iter()
+                .map(|dimension| Column::new(dimension.name.clone(), cell_width)),
+        );
+        canvas.section_title(&locale.text("portfolio.heatmap"), 20.0);
+        let table = Table::new(columns);
+        table.header(&mut canvas);
+        for assessment in &data.assessments {
+            let mut cells = vec![
+                Cell::plain(PortfolioReportService::row_label(assessment, locale)),
+                Cell::plain(
+                    assessment
+                        .overall_score
+                        .map(|score| locale.format_decimal(score))
+                        .unwrap_or_else(|| "-".to_string()),
+                ),
+            ];
+            cells.extend(assessment.cells.iter().map(|cell| match cell {
+                Some(cell) => Cell::severity(
+                    format!("{}/{}", cell.current_score, cell.desired_score),
+                    &cell.gap_class,
+                ),
+                None => Cell::plain("-"),
+            }));
+            table.row(&mut canvas, &cells);
+        }
+        canvas.y -= 6.0;
+
+        let column = |key: &str, width| Column::new(locale.text(key), width);
+        canvas.section_title(&locale.text("portfolio.weakest_dimensions"), 20.0);
+        let table = Table::new(vec![
+            column("columns.dimension", 60.0),
+            column("portfolio.average_current", 24.0),
+            column("portfolio.average_desired", 24.0),
+            column("portfolio.average_gap", 24.0),
+            column("overview.high_gaps", 24.0),
+            column("portfolio.assessed", 24.0),
+        ]);
+        table.header(&mut canvas);
+        for dimension in &data.weakest_dimensions {
+            table.row(
+                &mut canvas,
+                &[
+                    Cell::plain(&dimension.name),
+                    Cell::plain(locale.format_decimal(dimension.average_current)),
+                    Cell::plain(locale.format_decimal(dimension.average_desired)),
+                    Cell::plain(locale.format_decimal(dimension.average_gap)),
+                    Cell::plain(dimension.high_gaps.to_string()),
+                    Cell::plain(dimension.assessed.to_string()),
+                ],
+            );
+        }
+        canvas.y -= 6.0;
+
+        for (title, text_column, items) in [
+            (
+                "portfolio.common_high_gaps",
+                "columns.result",
+                &data.common_high_gaps,
+            ),
+            (
+                "portfolio.common_recommendations",
+                "columns.recommendation",
+                &data.common_recommendations,
+            ),
+        ] {
+            if items.is_empty() {
+                continue;
+            }
+            canvas.section_title(&locale.text(title), 20.0);
+            let table = Table::new(vec![
+                column("columns.dimension", 44.0),
+                column(text_column, 108.0),
+                column("portfolio.occurrences", 28.0),
+            ]);
+            table.header(&mut canvas);
+            for item in items {
+                table.row(
+                    &mut canvas,
+                    &[
+                        Cell::plain(&item.dimension),
+                        Cell::plain(&item.text),
+                        Cell::plain(item.occurrences.to_string()),
+                    ],
+                );
+            }
+            canvas.y -= 6.0;
+        }
+
+        canvas.finish()
+    }
+
+    /// New document with the heading block and footer of every report
+    fn start(
+        heading: &str,
+        subtitle: &str,
+        generation_date: &str,
+        locale: Locale,
+    ) -> Result<Canvas, AppError> {
+        let footer = format!("{}: {}", locale.text("fields.generated"), generation_date);
+        let mut canvas = Canvas::new(heading, footer)?;
+
+        canvas.text(heading, 20.0, MARGIN, canvas.y - 8.0, true, PRIMARY);
+        canvas.text(subtitle, 12.0, MARGIN, canvas.y - 15.0, false, MUTED);
+        canvas.y -= 20.0;
+        canvas.rule(PRIMARY, 0.8);
+        canvas.y -= 6.0;
+        Ok(canvas)
+    }
+
+    fn overview(canvas: &mut Canvas, data: &PdfReportData) {
+        let overview = PdfGeneratorService::overview(data);
+        let cards = [
+            (
+                overview
+                    .maturity_index_text
+                    .unwrap_or_else(|| "-".to_string()),
+                "overview.maturity_index",
+                None,
+            ),
+            (
+                overview.dimension_count.to_string(),
+                "overview.dimensions",
+                None,
+            ),
+            (
+                overview.high_gaps.to_string(),
+                "overview.high_gaps",
+                Some("high"),
+            ),
+            (
+                overview.medium_gaps.to_string(),
+                "overview.medium_gaps",
+                Some("medium"),
+            ),
+            (
+                overview.low_gaps.to_string(),
+                "overview.low_gaps",
+                Some("low"),
+            ),
+        ];
+
+        let cards: Vec<_> = cards
+            .into_iter()
+            .map(|(value, label, severity)| (value, data.locale.text(label), severity))
+            .collect();
+        Self::cards(canvas, &cards);
+    }
+
+    /// Row of headline figures: value, label and optional severity colour
+    fn cards(canvas: &mut Canvas, cards: &[(String, String, Option<&str>)]) {
+        let height = 18.0;
+        canvas.ensure_space(height + 6.0);
+        let gap = 3.0;
+        let width = (CONTENT_WIDTH - gap * (cards.len() - 1) as f32) / cards.len() as f32;
+        for (index, (value, label, severity)) in cards.iter().enumerate() {
+            let x = MARGIN + index as f32 * (width + gap);
+            let (fill, color) = severity.map(severity_colors).unwrap_or((0xffffff, PRIMARY));
+            canvas.rect(x, canvas.y - height, width, height, fill, Some(BORDER));
+            canvas.text_centered(value, 16.0, x, width, canvas.y - 9.0, true, color);
+            let label = label.to_uppercase();
+            canvas.text_centered(&label, 7.0, x, width, canvas.y - 15.0, false, MUTED);
+        }
+        canvas.y -= height + 6.0;
+    }
+
+    fn chart(canvas: &mut Canvas, rows: &[PdfReportRow], locale: Locale) {
+        let plot_height = 60.0;
+        let label_height = 8.0;
+        canvas.section_title(&locale.text("sections.chart"), plot_height + 20.0);
+
+        let max_score = rows
+            .iter()
+            .map(|row| row.current_score.max(row.desired_score))
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let axis_x = MARGIN + 8.0;
+        let plot_width = CONTENT_WIDTH - 8.0;
+        let base_y = canvas.y - plot_height;
+        let scale = plot_height / max_score as f32;
+
+        // Grid lines and y-axis ticks
+        for tick in 0..=max_score {
+            let y = base_y + tick as f32 * scale;
+            canvas.line(axis_x, y, axis_x + plot_width, y, BORDER, 0.3);
+            canvas.text(&tick.to_string(), 7.0, MARGIN + 2.0, y - 1.0, false, MUTED);
+        }
+
+        let group_width = plot_width / rows.len() as f32;
+        let bar_width = (group_width * 0.35).min(12.0);
+        for (index, row) in rows.iter().enumerate() {
+            let center = axis_x + group_width * (index as f32 + 0.5);
+            let bars = [
+                (center - bar_width, row.current_score, CURRENT_BAR),
+                (center, row.desired_score, DESIRED_BAR),
+            ];
+            for (x, score, color) in bars {
+                if score > 0 {
+                    canvas.rect(x, base_y, bar_width, score as f32 * scale, color, None);
+                }
+            }
+            let label = PdfGeneratorService::truncate(&row.category, max_chars(group_width, 7.0));
+            canvas.text_centered(
+                &label,
+                7.0,
+                center - group_width / 2.0,
+                group_width,
+                base_y - 4.0,
+                false,
+                MUTED,
+            );
+        }
+
+        // Legend
+        let legend_y = base_y - label_height - 2.0;
+        for (index, (label, color)) in [
+            ("chart.current_state", CURRENT_BAR),
+            ("chart.desired_state", DESIRED_BAR),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let x = axis_x + index as f32 * 35.0;
+            canvas.rect(x, legend_y, 4.0, 3.0, *color, None);
+            canvas.text(
+                &locale.text(label),
+                8.0,
+                x + 6.0,
+                legend_y + 0.3,
+                false,
+                MUTED,
+            );
+        }
+        canvas.y = legend_y - 6.0;
+    }
+
+    fn dimensions(
+        canvas: &mut Canvas,
+        rows: &[PdfReportRow],
+        include_recommendations: bool,
+        locale: Locale,
+    ) {
+        let column = |key: &str, width| Column::new(locale.text(key), width);
+        let mut columns = vec![
+            column("columns.category", 34.0),
+            column("columns.gap", 20.0),
+            column("columns.current", 18.0),
+            column("columns.desired", 18.0),
+        ];
+        if include_recommendations {
+            columns.push(column("columns.result", 40.0));
+            columns.push(column("columns.recommendations", 50.0));
+        } else {
+            columns.push(column("columns.result", 90.0));
+        }
+
+        canvas.section_title(&locale.text("sections.dimension_results"), 20.0);
+        let table = Table::new(columns);
+        table.header(canvas);
+        for row in rows {
+            let mut cells = vec![
+                Cell::plain(&row.category),
+                Cell::severity(&row.gap, &row.gap_class),
+                Cell::plain(row.current_score.to_string()),
+                Cell::plain(row.desired_score.to_string()),
+                Cell::plain(&row.result),
+            ];
+            if include_recommendations {
+                let recommendations = if row.recommendations.is_empty() {
+                    locale.text("text.none")
+                } else {
+                    row.recommendations
+                        .iter()
+                        .map(|r| format!("- {}", r))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                };
+                cells.push(Cell::plain(recommendations));
+            }
+            table.row(canvas, &cells);
+        }
+        canvas.y -= 6.0;
+    }
+
+    fn action_plan(canvas: &mut Canvas, actions: &[ReportActionItem], locale: Locale) {
+        canvas.section_title(&locale.text("sections.action_plan"), 20.0);
+        if actions.is_empty() {
+            canvas.text(
+                &locale.text("text.no_actions"),
+                9.0,
+                MARGIN,
+                canvas.y - 4.0,
+                false,
+                0x000000,
+            );
+            canvas.y -= 10.0;
+            return;
+        }
+
+        let column = |key: &str, width| Column::new(locale.text(key), width);
+        let table = Table::new(vec![
+            column("columns.priority", 22.0),
+            column("columns.dimension", 38.0),
+            column("columns.action", 94.0),
+            column("columns.status", 26.0),
+        ]);
+        table.header(canvas);
+        for action in actions {
+            table.row(
+                canvas,
+                &[
+                    Cell::severity(
+                        locale.text(&format!("priority.{}", action.priority)),
+                        &action.priority,
+                    ),
+                    Cell::plain(&action.dimension),
+                    Cell::plain(&action.recommendation),
+                    Cell::plain(locale.text(&format!("status.{}", action.status))),
+                ],
+            );
+        }
+        canvas.y -= 6.0;
+    }
+}
+
+/// Drawing surface that keeps a top-down cursor and breaks pages as needed
+struct Canvas {
+    doc: PdfDocumentReference,
+    layer: PdfLayerReference,
+    regular: IndirectFontRef,
+    bold: IndirectFontRef,
+    /// Current vertical position, in mm from the bottom of the page
+    y: f32,
+    footer: String,
+}
+
+impl Canvas {
+    fn new(title: &str, footer: String) -> Result<Self, AppError> {
+        let (doc, page, layer) =
+            PdfDocument::new(title, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+        let regular = doc
+            .add_builtin_font(BuiltinFont::Helvetica)
+            .map_err(pdf_error)?;
+        let bold = doc
+            .add_builtin_font(BuiltinFont::HelveticaBold)
+            .map_err(pdf_error)?;
+        let layer = doc.get_page(page).get_layer(layer);
+
+        let canvas = Self {
+            doc,
+            layer,
+            regular,
+            bold,
+            y: PAGE_HEIGHT - MARGIN,
+            footer,
+        };
+        canvas.draw_footer();
+        Ok(canvas)
+    }
+
+    fn finish(self) -> Result<Bytes, AppError> {
+        self.doc.save_to_bytes().map(Bytes::from).map_err(pdf_error)
+    }
+
+    /// Start a new page if less than `height` is left above the footer.
+    /// Returns whether a page was added.
+    fn ensure_space(&mut self, height: f32) -> bool {
+        if self.y - height >= MARGIN + FOOTER_HEIGHT {
+            return false;
+        }
+        let (page, layer) = self
+            .doc
+            .add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+        self.layer = self.doc.get_page(page).get_layer(layer);
+        self.y = PAGE_HEIGHT - MARGIN;
+        self.draw_footer();
+        true
+    }
+
+    fn draw_footer(&self) {
+        let y = MARGIN + FOOTER_HEIGHT - 4.0;
+        self.line(MARGIN, y, PAGE_WIDTH - MARGIN, y, BORDER, 0.5);
+        self.text_centered(
+            &self.footer,
+            8.0,
+            MARGIN,
+            CONTENT_WIDTH,
+            MARGIN + 2.0,
+            false,
+            MUTED,
+        );
+    }
+
+    fn section_title(&mut self, title: &str, keep_with: f32) {
+        self.ensure_space(10.0 + keep_with);
+        self.text(title, 13.0, MARGIN, self.y - 5.0, true, PRIMARY);
+        self.y -= 10.0;
+    }
+
+    fn rule(&self, color: u32, thickness: f32) {
+        self.line(
+            MARGIN,
+            self.y,
+            PAGE_WIDTH - MARGIN,
+            self.y,
+            color,
+            thickness,
+        );
+    }
+
+    fn text(&self, text: &str, size: f32, x: f32, y: f32, bold: bool, color: u32) {
+        let font = if bold { &self.bold } else { &self.regular };
+        self.layer.set_fill_color(rgb(color));
+        self.layer.use_text(text, size, Mm(x), Mm(y), font);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn text_centered(
+        &self,
+        text: &str,
+        size: f32,
+        x: f32,
+        width: f32,
+        y: f32,
+        bold: bool,
+        color: u32,
+    ) {
+        let offset = ((width - text_width(text, size)) / 2.0).max(0.0);
+        self.text(text, size, x + offset, y, bold, color);
+    }
+
+    fn rect(&self, x: f32, y: f32, width: f32, height: f32, fill: u32, outline: Option<u32>) {
+        self.layer.set_fill_color(rgb(fill));
+        let rect = Rect::new(Mm(x), Mm(y), Mm(x + width), Mm(y + height));
+        match outline {
+            Some(outline) => {
+                self.layer.set_outline_color(rgb(outline));
+                self.layer.set_outline_thickness(0.5);
+                self.layer.add_rect(rect.with_mode(PaintMode::FillStroke));
+            }
+            None => self.layer.add_rect(rect.with_mode(PaintMode::Fill)),
+        }
+    }
+
+    fn line(&self, x1: f32, y1: f32, x2: f32, y2: f32, color: u32, thickness: f32) {
+        self.layer.set_outline_color(rgb(color));
+        self.layer.set_outline_thickness(thickness);
+        self.layer.add_line(Line {
+            points: vec![
+                (Point::new(Mm(x1), Mm(y1)), false),
+                (Point::new(Mm(x2), Mm(y2)), false),
+            ],
+            is_closed: false,
+        });
+    }
+}
+
+struct Column {
+    /// Shown upper-cased, like the HTML report headers
+    title: String,
+    width: f32,
+}
+
+impl Column {
+    fn new(title: String, width: f32) -> Self {
+        Self {
+            title: title.to_uppercase(),
+            width,
+        }
+    }
+}
+
+struct Cell<'a> {
+    text: String,
+    /// `high`, `medium` or `low` colours the cell like the HTML report
+    severity: Option<&'a str>,
+}
+
+impl<'a> Cell<'a> {
+    fn plain(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            severity: None,
+        }
+    }
+
+    fn severity(text: impl Into<String>, severity: &'a str) -> Self {
+        Self {
+            text: text.into(),
+            severity: Some(severity),
+        }
+    }
+}
+
+struct Table {
+    columns: Vec<Column>,
+}
+
+impl Table {
+    const FONT_SIZE: f32 = 8.0;
+    const HEADER_HEIGHT: f32 = 7.0;
+
+    fn new(columns: Vec<Column>) -> Self {
+        Self { columns }
+    }
+
+    fn line_height() -> f32 {
+        Self::FONT_SIZE * PT * 1.3
+    }
+
+    fn header(&self, canvas: &mut Canvas) {
+        canvas.ensure_space(Self::HEADER_HEIGHT);
+        let mut x = MARGIN;
+        canvas.rect(
+            MARGIN,
+            canvas.y - Self::HEADER_HEIGHT,
+            CONTENT_WIDTH,
+            Self::HEADER_HEIGHT,
+            PRIMARY,
+            None,
+        );
+        for column in &self.columns {
+            let title = PdfGeneratorService::truncate(
+                &column.title,
+                max_chars(column.width - 2.0 * CELL_PADDING, Self::FONT_SIZE),
+            );
+            canvas.text(
+                &title,
+                Self::FONT_SIZE,
+                x + CELL_PADDING,
+                canvas.y - Self::HEADER_HEIGHT + 2.2,
+                true,
+                0xffffff,
+            );
+            x += column.width;
+        }
+        canvas.y -= Self::HEADER_HEIGHT;
+    }
+
+    fn row(&self, canvas: &mut Canvas, cells: &[Cell]) {
+        let lines: Vec<Vec<String>> = self
+            .columns
+            .iter()
+            .zip(cells)
+            .map(|(column, cell)| {
+                wrap(
+                    &cell.text,
+                    max_chars(column.width - 2.0 * CELL_PADDING, Self::FONT_SIZE),
+                )
+            })
+            .collect();
+        let line_count = lines.iter().map(Vec::len).max().unwrap_or(1).max(1);
+        let height = line_count as f32 * Self::line_height() + 2.0 * CELL_PADDING;
+
+        if canvas.ensure_space(height) {
+            self.header(canvas);
+        }
+
+        let top = canvas.y;
+        let mut x = MARGIN;
+        for ((column, cell), cell_lines) in self.columns.iter().zip(cells).zip(&lines) {
+            let (fill, color) = cell
+                .severity
+                .map(severity_colors)
+                .unwrap_or((0xffffff, 0x000000));
+            if cell.severity.is_some() {
+                canvas.rect(x, top - height, column.width, height, fill, None);
+            }
+            for (index, line) in cell_lines.iter().enumerate() {
+                let baseline =
+                    top - CELL_PADDING - (index as f32 + 1.0) * Self::line_height() + 0.8;
+                canvas.text(
+                    line,
+                    Self::FONT_SIZE,
+                    x + CELL_PADDING,
+                    baseline,
+                    cell.severity.is_some(),
+                    color,
+                );
+            }
+            x += column.width;
+        }
+        canvas.line(
+            MARGIN,
+            top - height,
+            PAGE_WIDTH - MARGIN,
+            top - height,
+            BORDER,
+            0.3,
+        );
+        canvas.y -= height;
+    }
+}
+
+/// Background and text colour of a severity cell
+fn severity_colors(severity: &str) -> (u32, u32) {
+    match severity {
+        "high" => (0xfecaca, 0x991b1b),
+        "medium" => (0xfef08a, 0x854d0e),
+        "low" => (0xbbf7d0, 0x166534),
+        _ => (0xffffff, 0x000000),
+    }
+}
+
+fn rgb(hex: u32) -> Color {
+    let channel = |shift: u32| ((hex >> shift) & 0xff) as f32 / 255.0;
+    Color::Rgb(Rgb::new(channel(16), channel(8), channel(0), None))
+}
+
+fn text_width(text: &str, size: f32) -> f32 {
+    text.chars().count() as f32 * size * GLYPH_WIDTH * PT
+}
+
+/// Approximate number of characters fitting in `width` mm
+fn max_chars(width: f32, size: f32) -> usize {
+    ((width / (size * GLYPH_WIDTH * PT)) as usize).max(1)
+}
+
+/// Greedy word wrap to lines of at most `max_chars` characters, honouring
+/// explicit line breaks and splitting words longer than a line.
+fn wrap(text: &str, max_chars: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let mut word: Vec<char> = word.chars().collect();
+            while word.len() > max_chars {
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                lines.push(word.drain(..max_chars).collect());
+            }
+            let word: String = word.into_iter().collect();
+            if word.is_empty() {
+                continue;
+            }
+            let needed = if line.is_empty() {
+                word.chars().count()
+            } else {
+                line.chars().count() + 1 + word.chars().count()
+            };
+            if needed > max_chars && !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&word);
+        }
+        lines.push(line);
+    }
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
+
+fn pdf_error(e: printpdf::Error) -> AppError {
+    AppError::InternalServerError(format!("Failed to render PDF: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::reports::ReportType;
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(wrap("one two three", 7), ["one two", "three"]);
+        assert_eq!(wrap("abcdefghij", 4), ["abcd", "efgh", "ij"]);
+        assert_eq!(wrap("a\nb", 10), ["a", "b"]);
+        assert_eq!(wrap("", 10), [""]);
+    }
+
+    #[test]
+    fn test_render_produces_pdf() {
+        let row = PdfReportRow {
+            category: "Strategy".to_string(),
+            gap: "Hoch".to_string(),
+            gap_class: "high".to_string(),
+            result: "No digital strategy in place yet".repeat(5),
+            current_score: 1,
+            desired_score: 5,
+            recommendations: vec!["Write a digital strategy".to_string()],
+        };
+        let data = PdfReportData {
+            assessment_title: "Assessment".to_string(),
+            organization_id: "org".to_string(),
+            assessment_status: "completed".to_string(),
+            overall_score: Some(20.0),
+            // Enough rows to need a second page
+            rows: vec![row; 40],
+            actions: vec![ReportActionItem {
+                dimension: "Strategy".to_string(),
+                recommendation: "Write a digital strategy".to_string(),
+                priority: "high".to_string(),
+                status: "in_progress".to_string(),
+            }],
+            chart_data: None,
+            generation_date: "18. Oktober 2026 12:00 UTC".to_string(),
+            locale: Locale::De,
+        };
+
+        let bytes =
+            NativePdfRenderer::render(&data, &ReportOptions::for_type(ReportType::Detailed))
+                .unwrap();
+        assert!(bytes.starts_with(b"%PDF"));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::config::PdfBackend;
+use crate::entities::reports::ReportType;
 use crate::error::AppError;
 use crate::repositories::{
     action_items::ActionItemsRepository, assessments::AssessmentsRepository,
@@ -5,14 +7,17 @@ use crate::repositories::{
     dimension_assessments::DimensionAssessmentsRepository, dimensions::DimensionsRepository,
     gaps::GapsRepository, recommendations::RecommendationsRepository,
 };
-use base64::{engine::general_purpose, Engine as _};
+use crate::services::browser_pool::BrowserPool;
+use crate::services::chart_svg::ChartRenderer;
+use crate::services::i18n::Locale;
+use crate::services::native_pdf::NativePdfRenderer;
+use crate::services::report_templates::ReportTemplateService;
 use bytes::Bytes;
-use headless_chrome::{Browser, LaunchOptions};
-use sea_orm::DatabaseConnection;
-use serde::Serialize;
-use std::ffi::OsStr;
-use tera::{Context, Tera};
-use tracing::{error, info, instrument, warn};
+use sea_orm::{ActiveEnum, DatabaseConnection};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tera::Context;
+use tracing::{error, info, instrument};
 use uuid::Uuid;
 
 /// Data structure for a single row in the PDF report
@@ -22,9 +27,20 @@ pub struct PdfReportRow {
     pub gap: String,
     pub gap_class: String, // "high", "medium", or "low" for CSS class
     pub result: String,
+    pub current_score: i32,
+    pub desired_score: i32,
     pub recommendations: Vec<String>,
 }
 
+/// One action item of the report's action plan
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportActionItem {
+    pub dimension: String,
+    pub recommendation: String,
+    pub priority: String,
+    pub status: String,
+}
+
 /// Chart data structure
 #[derive(Debug, Clone, Serialize)]
 pub struct ChartData {
@@ -37,45 +53,176 @@ pub struct ChartData {
 #[derive(Debug, Clone, Serialize)]
 pub struct PdfReportData {
     pub assessment_title: String,
+    pub organization_id: String,
+    pub assessment_status: String,
+    /// Maturity index frozen at submission, if the assessment was submitted
+    pub overall_score: Option<f64>,
     pub rows: Vec<PdfReportRow>,
-    pub chart_data: Option<String>, // JSON string for Chart.js
+    pub actions: Vec<ReportActionItem>,
+    pub chart_data: Option<ChartData>,
     pub generation_date: String,
+    pub locale: Locale,
+}
+
+/// Blocks a report can be made of
+pub const REPORT_SECTIONS: [&str; 5] = [
+    "overview",
+    "chart",
+    "dimensions",
+    "recommendations",
+    "action_plan",
+];
+
+/// What goes into a generated report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportOptions {
+    pub report_type: ReportType,
+    pub include_recommendations: bool,
+    pub include_action_plans: bool,
+    /// Restricts the report to these [`REPORT_SECTIONS`]
+    pub custom_sections: Option<Vec<String>>,
+    /// Language of the report, English for reports queued before it existed
+    #[serde(default)]
+    pub locale: Locale,
+}
+
+impl ReportOptions {
+    /// Defaults of a report type: summaries leave out recommendations and
+    /// the action plan, the other types include both.
+    pub fn for_type(report_type: ReportType) -> Self {
+        let detailed = report_type != ReportType::Summary;
+        Self {
+            report_type,
+            include_recommendations: detailed,
+            include_action_plans: detailed,
+            custom_sections: None,
+            locale: Locale::default(),
+        }
+    }
+
+    /// Options of a report request, unset flags falling back to the defaults
+    /// of the report type.
+    pub fn new(
+        report_type: ReportType,
+        include_recommendations: Option<bool>,
+        include_action_plans: Option<bool>,
+        custom_sections: Option<Vec<String>>,
+    ) -> Result<Self, AppError> {
+        let defaults = Self::for_type(report_type);
+        let custom_sections = match custom_sections {
+            Some(sections) => {
+                let sections: Vec<String> = sections
+                    .iter()
+                    .map(|section| section.trim().to_lowercase())
+                    .collect();
+                if let Some(unknown) = sections
+                    .iter()
+                    .find(|section| !REPORT_SECTIONS.contains(&section.as_str()))
+                {
+                    return Err(AppError::ValidationError(format!(
+                        "Unknown report section '{}', expected one of: {}",
+                        unknown,
+                        REPORT_SECTIONS.join(", ")
+                    )));
+                }
+                Some(sections)
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            include_recommendations: include_recommendations
+                .unwrap_or(defaults.include_recommendations),
+            include_action_plans: include_action_plans.unwrap_or(defaults.include_action_plans),
+            custom_sections,
+            ..defaults
+        })
+    }
+
+    /// Whether `section` is rendered
+    pub fn includes(&self, section: &str) -> bool {
+        let enabled = match section {
+            "recommendations" => self.include_recommendations,
+            "action_plan" => self.include_action_plans,
+            _ => true,
+        };
+        enabled
+            && self
+                .custom_sections
+                .as_ref()
+                .is_none_or(|sections| sections.iter().any(|s| s == section))
+    }
+}
+
+/// Headline figures of the report
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportOverview {
+    pub maturity_index: Option<f64>,
+    /// `maturity_index` formatted for the report's locale
+    pub maturity_index_text: Option<String>,
+    pub dimension_count: usize,
+    pub high_gaps: usize,
+    pub medium_gaps: usize,
+    pub low_gaps: usize,
 }
 
 pub struct PdfGeneratorService;
 
 impl PdfGeneratorService {
     /// Main entry point to generate a PDF report for an assessment
-    #[instrument(skip(db), fields(assessment_id = %assessment_id))]
+    #[instrument(skip(db, browser_pool, templates), fields(assessment_id = %assessment_id))]
     pub async fn generate_assessment_pdf(
         db: &DatabaseConnection,
         assessment_id: Uuid,
+        options: &ReportOptions,
+        backend: PdfBackend,
+        browser_pool: &BrowserPool,
+        templates: &ReportTemplateService,
     ) -> Result<Bytes, AppError> {
         info!("Starting PDF generation process for assessment.");
 
         // Fetch all required data
         info!("Fetching report data from database.");
-        let report_data = Self::fetch_report_data(db, assessment_id).await?;
+        let report_data = Self::fetch_report_data(db, assessment_id, options.locale).await?;
         info!("Successfully fetched report data.");
 
+        Self::render_pdf(&report_data, options, backend, browser_pool, templates).await
+    }
+
+    /// Render already fetched report data as a PDF with `backend`
+    pub async fn render_pdf(
+        report_data: &PdfReportData,
+        options: &ReportOptions,
+        backend: PdfBackend,
+        browser_pool: &BrowserPool,
+        templates: &ReportTemplateService,
+    ) -> Result<Bytes, AppError> {
+        if backend == PdfBackend::Native {
+            info!("Rendering PDF natively.");
+            return NativePdfRenderer::render(report_data, options);
+        }
+
         // Generate HTML from template
         info!("Rendering HTML template.");
-        let html = Self::render_html_template(&report_data)?;
+        let html = templates.render(report_data, options).await?;
         info!("Successfully rendered HTML template.");
 
-        // Convert HTML to PDF using headless Chrome
+        // Convert HTML to PDF using a pooled headless Chrome
         info!("Converting HTML to PDF.");
-        let pdf_bytes = Self::html_to_pdf(&html).await?;
+        let pdf_bytes = browser_pool.html_to_pdf(&html).await?;
         info!("Successfully converted HTML to PDF.");
 
         Ok(pdf_bytes)
     }
 
-    /// Aggregates all data needed for the PDF report
-    #[instrument(skip(db), fields(assessment_id = %assessment_id))]
-    async fn fetch_report_data(
+    /// Aggregates all data needed for a report, whatever its output format.
+    /// Dimension names, recommendations and labels are given in `locale`
+    /// where a translation exists.
+    #[instrument(skip(db), fields(assessment_id = %assessment_id, locale = %locale))]
+    pub async fn fetch_report_data(
         db: &DatabaseConnection,
         assessment_id: Uuid,
+        locale: Locale,
     ) -> Result<PdfReportData, AppError> {
         // Get assessment
         let assessment = AssessmentsRepository::find_by_id(db, assessment_id)
@@ -95,6 +242,7 @@ impl PdfGeneratorService {
         );
 
         let mut rows = Vec::new();
+        let mut actions = Vec::new();
         let mut chart_labels = Vec::new();
         let mut chart_current = Vec::new();
         let mut chart_desired = Vec::new();
@@ -107,6 +255,9 @@ impl PdfGeneratorService {
                     error!(dimension_id = %dim_assessment.dimension_id, "Dimension not found.");
                     AppError::NotFound("Dimension not found".to_string())
                 })?;
+            let dimension_name = locale
+                .translated(dimension.translations.as_ref(), "name")
+                .unwrap_or(dimension.name);
 
             // Get gap details
             let gap = GapsRepository::find_by_id(db, dim_assessment.gap_id)
@@ -129,7 +280,16 @@ impl PdfGeneratorService {
                 if let Some(recommendation) =
                     RecommendationsRepository::find_by_id(db, action_item.recommendation_id).await?
                 {
-                    recommendations.push(recommendation.description);
+                    let description = locale
+                        .translated(recommendation.translations.as_ref(), "description")
+                        .unwrap_or(recommendation.description);
+                    actions.push(ReportActionItem {
+                        dimension: dimension_name.clone(),
+                        recommendation: description.clone(),
+                        priority: action_item.priority.to_string(),
+                        status: action_item.status.to_string(),
+                    });
+                    recommendations.push(description);
                 }
             }
 
@@ -160,121 +320,256 @@ impl PdfGeneratorService {
             };
 
             rows.push(PdfReportRow {
-                category: dimension.name.clone(),
-                gap: gap_severity_str,
+                category: dimension_name.clone(),
+                gap: locale.text(&format!("severity.{}", gap_class)),
                 gap_class: gap_class.to_string(),
                 result: gap
                     .gap_description
-                    .unwrap_or_else(|| "No description".to_string()),
+                    .unwrap_or_else(|| locale.text("text.no_description")),
+                current_score: current_state_level,
+                desired_score: desired_state_level,
                 recommendations,
             });
 
             // Add to chart data
-            chart_labels.push(dimension.name);
+            chart_labels.push(dimension_name);
             chart_current.push(current_state_level);
             chart_desired.push(desired_state_level);
         }
 
-        // Create chart data JSON
         let chart_data = if !chart_labels.is_empty() {
-            let chart = ChartData {
+            Some(ChartData {
                 labels: chart_labels,
                 current_state: chart_current,
                 desired_state: chart_desired,
-            };
-            Some(serde_json::to_string(&chart).unwrap_or_default())
+            })
         } else {
             None
         };
 
         Ok(PdfReportData {
             assessment_title: assessment.document_title,
+            organization_id: assessment.organization_id,
+            assessment_status: assessment.status.to_value(),
+            overall_score: assessment.overall_score,
             rows,
+            actions,
             chart_data,
-            generation_date: chrono::Utc::now().format("%Y-%m-%d %H:%M UTC").to_string(),
+            generation_date: locale.format_date(chrono::Utc::now()),
+            locale,
         })
     }
 
-    /// Renders the HTML template with the report data
-    #[instrument(skip(data))]
-    fn render_html_template(data: &PdfReportData) -> Result<String, AppError> {
-        let tera = match Tera::new("templates/**/*.html") {
-            Ok(t) => t,
-            Err(e) => {
-                error!(error = %e, "Failed to parse templates.");
-                return Err(AppError::InternalServerError(format!(
-                    "Template error: {}",
-                    e
-                )));
-            }
+    /// Rows and action items in report order. Summaries and action plans
+    /// list the most pressing items first; detailed reports keep the
+    /// assessment order.
+    pub fn ordered_items(
+        data: &PdfReportData,
+        options: &ReportOptions,
+    ) -> (Vec<PdfReportRow>, Vec<ReportActionItem>) {
+        let mut rows = data.rows.clone();
+        let mut actions = data.actions.clone();
+        if options.report_type != ReportType::Detailed {
+            rows.sort_by_key(|row| {
+                (
+                    std::cmp::Reverse(level(&row.gap_class)),
+                    row.current_score - row.desired_score,
+                )
+            });
+            actions.sort_by_key(|action| std::cmp::Reverse(level(&action.priority)));
+        }
+        (rows, actions)
+    }
+
+    pub fn overview(data: &PdfReportData) -> ReportOverview {
+        let count = |class: &str| data.rows.iter().filter(|r| r.gap_class == class).count();
+        ReportOverview {
+            maturity_index: data.overall_score,
+            maturity_index_text: data
+                .overall_score
+                .map(|score| data.locale.format_decimal(score)),
+            dimension_count: data.rows.len(),
+            high_gaps: count("high"),
+            medium_gaps: count("medium"),
+            low_gaps: count("low"),
+        }
+    }
+
+    pub fn report_heading(report_type: &ReportType, locale: Locale) -> String {
+        let key = match report_type {
+            ReportType::Summary => "summary",
+            ReportType::Detailed => "detailed",
+            ReportType::ActionPlan => "action_plan",
+            ReportType::Portfolio => "portfolio",
         };
+        locale.text(&format!("heading.{}", key))
+    }
+
+    /// Shorten `text` to at most `max_chars` characters, ending in an ellipsis
+    /// when cut. Shared by the chart and native PDF renderers.
+    pub fn truncate(text: &str, max_chars: usize) -> String {
+        if text.chars().count() <= max_chars {
+            return text.to_string();
+        }
+        let mut truncated: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+        truncated.push('…');
+        truncated
+    }
+
+    /// Template context of a report
+    pub fn template_context(data: &PdfReportData, options: &ReportOptions) -> Context {
+        let (rows, actions) = Self::ordered_items(data, options);
+        let overview = Self::overview(data);
+        let sections: HashMap<&str, bool> = REPORT_SECTIONS
+            .iter()
+            .map(|section| (*section, options.includes(section)))
+            .collect();
 
         let mut context = Context::new();
+        context.insert(
+            "report_heading",
+            &Self::report_heading(&options.report_type, data.locale),
+        );
+        context.insert("locale", &data.locale);
+        context.insert("t", data.locale.catalog());
         context.insert("assessment_title", &data.assessment_title);
-        context.insert("rows", &data.rows);
-        context.insert("chart_data", &data.chart_data);
+        context.insert("overview", &overview);
+        context.insert("rows", &rows);
+        context.insert("actions", &actions);
+        let chart_svg = data
+            .chart_data
+            .as_ref()
+            .filter(|_| options.includes("chart"))
+            .map(|chart| ChartRenderer::bar_chart_svg(chart, data.locale));
+        context.insert("chart_svg", &chart_svg);
         context.insert("generation_date", &data.generation_date);
+        context.insert("sections", &sections);
+        context
+    }
+}
 
-        match tera.render("report.html", &context) {
-            Ok(html) => Ok(html),
-            Err(e) => {
-                error!(error = %e, "Failed to render HTML template.");
-                Err(AppError::InternalServerError(format!(
-                    "Template render error: {}",
-                    e
-                )))
-            }
-        }
+/// Rank of a `high` / `medium` / `low` value, higher is more urgent
+fn level(value: &str) -> u8 {
+    match value {
+        "high" => 3,
+        "medium" => 2,
+        "low" => 1,
+        _ => 0,
     }
+}
 
-    /// Converts HTML to PDF using a headless browser
-    #[instrument(skip(html))]
-    async fn html_to_pdf(html: &str) -> Result<Bytes, AppError> {
-        info!("Initializing headless browser for PDF conversion.");
-        let browser = Browser::new(LaunchOptions {
-            headless: true,
-            sandbox: true,
-            args: vec![OsStr::new("--no-sandbox"), OsStr::new("--disable-gpu")],
-            ..Default::default()
-        })
-        .map_err(|e| {
-            error!(error = %e, "Failed to launch headless browser.");
-            AppError::InternalServerError(format!("Failed to launch browser: {}", e))
-        })?;
-        info!("Browser launched successfully.");
-
-        let tab = browser.new_tab().map_err(|e| {
-            error!(error = %e, "Failed to create new browser tab.");
-            AppError::InternalServerError(format!("Failed to create new tab: {}", e))
-        })?;
-        info!("New browser tab created.");
-
-        // Navigate to a data URL to load the HTML content
-        let data_url = format!(
-            "data:text/html;base64,{}",
-            general_purpose::STANDARD.encode(html)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(PdfGeneratorService::truncate("Strategie", 9), "Strategie");
+        assert_eq!(
+            PdfGeneratorService::truncate("Digitale Strategie", 9),
+            "Digitale…"
         );
-        tab.navigate_to(&data_url).map_err(|e| {
-            error!(error = %e, "Failed to navigate to data URL in headless browser.");
-            AppError::InternalServerError(format!("Failed to navigate: {}", e))
-        })?;
-        info!("Navigated to data URL.");
-
-        // Wait for the chart to be rendered before printing
-        info!("Waiting for chart image element to be ready.");
-        if let Err(e) = tab.wait_for_element("img#chartImage") {
-            warn!(error = %e, "Failed to wait for chart image. This might be okay if there is no chart.");
-        } else {
-            info!("Chart image element found.");
+        assert_eq!(PdfGeneratorService::truncate("Über", 1), "…");
+    }
+
+    fn row(category: &str, gap_class: &str, current_score: i32) -> PdfReportRow {
+        PdfReportRow {
+            category: category.to_string(),
+            gap: gap_class.to_uppercase(),
+            gap_class: gap_class.to_string(),
+            result: String::new(),
+            current_score,
+            desired_score: 5,
+            recommendations: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_options_default_by_report_type() {
+        let summary = ReportOptions::for_type(ReportType::Summary);
+        assert!(!summary.includes("recommendations"));
+        assert!(!summary.includes("action_plan"));
+        assert!(summary.includes("chart"));
+
+        let options = ReportOptions::new(ReportType::Summary, Some(true), None, None).unwrap();
+        assert!(options.includes("recommendations"));
+        assert!(ReportOptions::for_type(ReportType::ActionPlan).includes("action_plan"));
+    }
+
+    #[test]
+    fn test_custom_sections_restrict_report() {
+        let options = ReportOptions::new(
+            ReportType::Detailed,
+            None,
+            Some(false),
+            Some(vec!["Chart".to_string(), "action_plan".to_string()]),
+        )
+        .unwrap();
+        assert!(options.includes("chart"));
+        assert!(!options.includes("dimensions"));
+        // Include flags still apply
+        assert!(!options.includes("action_plan"));
+
+        assert!(ReportOptions::new(
+            ReportType::Detailed,
+            None,
+            None,
+            Some(vec!["appendix".to_string()])
+        )
+        .is_err());
+    }
+
+    fn report_data(rows: Vec<PdfReportRow>, locale: Locale) -> PdfReportData {
+        PdfReportData {
+            assessment_title: "Assessment".to_string(),
+            organization_id: "org".to_string(),
+            assessment_status: "completed".to_string(),
+            overall_score: Some(62.5),
+            rows,
+            actions: Vec::new(),
+            chart_data: None,
+            generation_date: String::new(),
+            locale,
         }
+    }
 
-        info!("Printing page to PDF.");
-        let pdf_data = tab.print_to_pdf(None).map_err(|e| {
-            error!(error = %e, "Failed to print to PDF.");
-            AppError::InternalServerError(format!("Failed to print to PDF: {}", e))
-        })?;
-        info!("PDF data generated successfully.");
+    #[test]
+    fn test_summary_lists_largest_gaps_first() {
+        let data = report_data(
+            vec![row("A", "low", 4), row("B", "high", 1), row("C", "high", 0)],
+            Locale::En,
+        );
+
+        let context = PdfGeneratorService::template_context(
+            &data,
+            &ReportOptions::for_type(ReportType::Summary),
+        )
+        .into_json();
+        let order: Vec<&str> = context["rows"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|row| row["category"].as_str().unwrap())
+            .collect();
+        assert_eq!(order, ["C", "B", "A"]);
+        assert_eq!(context["overview"]["high_gaps"], 2);
+        assert_eq!(context["sections"]["recommendations"], false);
+    }
 
-        Ok(Bytes::from(pdf_data))
+    #[test]
+    fn test_context_uses_report_locale() {
+        let data = report_data(vec![row("A", "low", 4)], Locale::Fr);
+
+        let context = PdfGeneratorService::template_context(
+            &data,
+            &ReportOptions::for_type(ReportType::Detailed),
+        )
+        .into_json();
+        assert_eq!(
+            context["report_heading"],
+            "Rapport d'analyse des écarts numériques"
+        );
+        assert_eq!(context["overview"]["maturity_index_text"], "62,5");
+        assert_eq!(context["t"]["columns"]["category"], "Catégorie");
     }
 }
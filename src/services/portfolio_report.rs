@@ -0,0 +1,533 @@
+use std::collections::{HashMap, HashSet};
+
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tera::Context;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use crate::entities::assessments::{self, AssessmentStatus};
+use crate::entities::gaps::{self, GapSeverity};
+use crate::entities::{
+    action_items, current_states, desired_states, dimension_assessments, dimensions,
+    recommendations,
+};
+use crate::error::AppError;
+use crate::services::benchmark::BenchmarkService;
+use crate::services::i18n::Locale;
+
+/// Dimensions listed as the weakest of a portfolio
+const WEAKEST_DIMENSIONS: usize = 5;
+/// Gaps and recommendations listed as the most common of a portfolio
+const MOST_COMMON: usize = 10;
+
+/// What goes into a portfolio report, stored as the report's options
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PortfolioOptions {
+    /// Restricts the report to these cooperations of the organization
+    pub cooperation_ids: Option<Vec<String>>,
+    #[serde(default)]
+    pub locale: Locale,
+}
+
+/// Scores of one cooperative in one dimension
+#[derive(Debug, Clone, Serialize)]
+pub struct PortfolioCell {
+    pub current_score: i32,
+    pub desired_score: i32,
+    pub gap_class: String,
+}
+
+/// One heatmap row: the latest completed assessment of a cooperative
+#[derive(Debug, Clone, Serialize)]
+pub struct PortfolioAssessment {
+    /// `None` for assessments of the organization itself
+    pub cooperation_id: Option<String>,
+    pub assessment_title: String,
+    pub overall_score: Option<f64>,
+    /// One cell per [`PortfolioReportData::dimensions`] entry
+    pub cells: Vec<Option<PortfolioCell>>,
+}
+
+/// Averages of one dimension over the portfolio
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PortfolioDimension {
+    pub name: String,
+    /// Cooperatives that answered the dimension
+    pub assessed: usize,
+    pub average_current: f64,
+    pub average_desired: f64,
+    pub average_gap: f64,
+    pub high_gaps: usize,
+}
+
+/// A gap or recommendation and how many cooperatives share it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PortfolioItem {
+    pub dimension: String,
+    pub text: String,
+    pub occurrences: usize,
+}
+
+/// Aggregated data of a portfolio report
+#[derive(Debug, Clone, Serialize)]
+pub struct PortfolioReportData {
+    pub title: String,
+    pub organization_id: String,
+    /// Heatmap columns, by name
+    pub dimensions: Vec<PortfolioDimension>,
+    /// Heatmap rows, by cooperation
+    pub assessments: Vec<PortfolioAssessment>,
+    pub average_maturity: Option<f64>,
+    /// Largest average gaps first
+    pub weakest_dimensions: Vec<PortfolioDimension>,
+    pub common_high_gaps: Vec<PortfolioItem>,
+    pub common_recommendations: Vec<PortfolioItem>,
+    pub generation_date: String,
+    pub locale: Locale,
+}
+
+/// One cooperative's answer for a dimension
+#[derive(Debug, Clone)]
+pub struct PortfolioSample {
+    pub current_score: i32,
+    pub desired_score: i32,
+    pub gap_severity: GapSeverity,
+}
+
+/// Organization-wide reports over the latest completed assessment of each
+/// cooperative.
+pub struct PortfolioReportService;
+
+impl PortfolioReportService {
+    #[instrument(skip(db, options))]
+    pub async fn fetch_portfolio_data(
+        db: &DatabaseConnection,
+        organization_id: &str,
+        title: &str,
+        options: &PortfolioOptions,
+    ) -> Result<PortfolioReportData, AppError> {
+        let locale = options.locale;
+        let mut query = assessments::Entity::find()
+            .filter(assessments::Column::OrganizationId.eq(organization_id))
+            .filter(assessments::Column::Status.eq(AssessmentStatus::Completed));
+        if let Some(cooperation_ids) = &options.cooperation_ids {
+            query = query.filter(assessments::Column::CooperationId.is_in(cooperation_ids.clone()));
+        }
+        let mut latest = BenchmarkService::latest_per_cooperation(query.all(db).await?);
+        latest.sort_by(|a, b| a.cooperation_id.cmp(&b.cooperation_id));
+        info!(count = latest.len(), "Found latest completed assessments.");
+
+        let dimension_assessments = dimension_assessments::Entity::find()
+            .filter(
+                dimension_assessments::Column::AssessmentId
+                    .is_in(latest.iter().map(|a| a.assessment_id).collect::<Vec<_>>()),
+            )
+            .all(db)
+            .await?;
+
+        let dimension_ids: HashSet<Uuid> = dimension_assessments
+            .iter()
+            .map(|da| da.dimension_id)
+            .collect();
+        // Cooperatives may have answered different framework versions; their
+        // copies of a dimension share one column, named after the newest copy
+        let mut answered = dimensions::Entity::find()
+            .filter(dimensions::Column::DimensionId.is_in(dimension_ids))
+            .all(db)
+            .await?;
+        answered.sort_by_key(|dimension| dimension.created_at);
+        let origins: HashMap<Uuid, Uuid> = answered
+            .iter()
+            .map(|dimension| (dimension.dimension_id, dimension.origin_dimension_id))
+            .collect();
+        let dimension_names: HashMap<Uuid, String> = answered
+            .into_iter()
+            .map(|dimension| {
+                let name = locale
+                    .translated(dimension.translations.as_ref(), "name")
+                    .unwrap_or(dimension.name);
+                (dimension.origin_dimension_id, name)
+            })
+            .collect();
+        let mut dimensions: Vec<(Uuid, String)> = dimension_names.clone().into_iter().collect();
+        dimensions.sort_by(|a, b| a.1.cmp(&b.1));
+
+        let current_scores: HashMap<Uuid, i32> = current_states::Entity::find()
+            .filter(
+                current_states::Column::CurrentStateId.is_in(
+                    dimension_assessments
+                        .iter()
+                        .map(|da| da.current_state_id)
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|state| (state.current_state_id, state.score))
+            .collect();
+        let desired_scores: HashMap<Uuid, i32> = desired_states::Entity::find()
+            .filter(
+                desired_states::Column::DesiredStateId.is_in(
+                    dimension_assessments
+                        .iter()
+                        .map(|da| da.desired_state_id)
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|state| (state.desired_state_id, state.score))
+            .collect();
+        let gaps: HashMap<Uuid, gaps::Model> = gaps::Entity::find()
+            .filter(
+                gaps::Column::GapId.is_in(
+                    dimension_assessments
+                        .iter()
+                        .map(|da| da.gap_id)
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|gap| (gap.gap_id, gap))
+            .collect();
+
+        // Samples per assessment and dimension, plus the high gaps of each
+        // cooperative
+        let mut samples: HashMap<(Uuid, Uuid), PortfolioSample> = HashMap::new();
+        let mut high_gaps: HashMap<Uuid, HashSet<Uuid>> = HashMap::new();
+        for da in &dimension_assessments {
+            let (Some(current_score), Some(desired_score), Some(gap), Some(origin)) = (
+                current_scores.get(&da.current_state_id),
+                desired_scores.get(&da.desired_state_id),
+                gaps.get(&da.gap_id),
+                origins.get(&da.dimension_id),
+            ) else {
+                continue;
+            };
+            if gap.gap_severity == GapSeverity::High {
+                high_gaps
+                    .entry(gap.gap_id)
+                    .or_default()
+                    .insert(da.assessment_id);
+            }
+            samples.insert(
+                (da.assessment_id, *origin),
+                PortfolioSample {
+                    current_score: *current_score,
+                    desired_score: *desired_score,
+                    gap_severity: gap.gap_severity.clone(),
+                },
+            );
+        }
+
+        let action_items = action_items::Entity::find()
+            .filter(
+                action_items::Column::DimensionAssessmentId.is_in(
+                    dimension_assessments
+                        .iter()
+                        .map(|da| da.dimension_assessment_id)
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .all(db)
+            .await?;
+        let assessment_of: HashMap<Uuid, Uuid> = dimension_assessments
+            .iter()
+            .map(|da| (da.dimension_assessment_id, da.assessment_id))
+            .collect();
+        let mut planned: HashMap<Uuid, HashSet<Uuid>> = HashMap::new();
+        for item in &action_items {
+            if let Some(assessment_id) = assessment_of.get(&item.dimension_assessment_id) {
+                planned
+                    .entry(item.recommendation_id)
+                    .or_default()
+                    .insert(*assessment_id);
+            }
+        }
+        let recommendations: HashMap<Uuid, recommendations::Model> =
+            recommendations::Entity::find()
+                .filter(
+                    recommendations::Column::RecommendationId
+                        .is_in(planned.keys().copied().collect::<Vec<_>>()),
+                )
+                .all(db)
+                .await?
+                .into_iter()
+                .map(|recommendation| (recommendation.recommendation_id, recommendation))
+                .collect();
+
+        let assessments: Vec<PortfolioAssessment> = latest
+            .iter()
+            .map(|assessment| PortfolioAssessment {
+                cooperation_id: assessment.cooperation_id.clone(),
+                assessment_title: assessment.document_title.clone(),
+                overall_score: assessment.overall_score,
+                cells: dimensions
+                    .iter()
+                    .map(|(dimension_id, _)| {
+                        samples
+                            .get(&(assessment.assessment_id, *dimension_id))
+                            .map(|sample| PortfolioCell {
+                                current_score: sample.current_score,
+                                desired_score: sample.desired_score,
+                                gap_class: gap_class(&sample.gap_severity).to_string(),
+                            })
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let dimension_summaries: Vec<PortfolioDimension> = dimensions
+            .iter()
+            .map(|(dimension_id, name)| {
+                let dimension_samples: Vec<PortfolioSample> = latest
+                    .iter()
+                    .filter_map(|a| samples.get(&(a.assessment_id, *dimension_id)).cloned())
+                    .collect();
+                Self::summarize(name.clone(), &dimension_samples)
+            })
+            .collect();
+
+        // Copies of a gap or recommendation in different framework versions
+        // count as one item
+        let mut gap_groups: HashMap<(Uuid, String), HashSet<Uuid>> = HashMap::new();
+        for (gap_id, assessments) in high_gaps {
+            let Some(gap) = gaps.get(&gap_id) else {
+                continue;
+            };
+            let Some(origin) = origins.get(&gap.dimension_id) else {
+                continue;
+            };
+            let text = gap
+                .gap_description
+                .clone()
+                .unwrap_or_else(|| locale.text("text.no_description"));
+            gap_groups
+                .entry((*origin, text))
+                .or_default()
+                .extend(assessments);
+        }
+        let mut recommendation_groups: HashMap<(Uuid, String), HashSet<Uuid>> = HashMap::new();
+        for (recommendation_id, assessments) in planned {
+            let Some(recommendation) = recommendations.get(&recommendation_id) else {
+                continue;
+            };
+            let Some(origin) = origins.get(&recommendation.dimension_id) else {
+                continue;
+            };
+            let text = locale
+                .translated(recommendation.translations.as_ref(), "description")
+                .unwrap_or_else(|| recommendation.description.clone());
+            recommendation_groups
+                .entry((*origin, text))
+                .or_default()
+                .extend(assessments);
+        }
+        let to_item = |((origin, text), assessments): ((Uuid, String), HashSet<Uuid>)| {
+            Some(PortfolioItem {
+                dimension: dimension_names.get(&origin)?.clone(),
+                text,
+                occurrences: assessments.len(),
+            })
+        };
+        let common_high_gaps = Self::most_common(gap_groups.into_iter().filter_map(to_item));
+        let common_recommendations =
+            Self::most_common(recommendation_groups.into_iter().filter_map(to_item));
+
+        let scores: Vec<f64> = latest.iter().filter_map(|a| a.overall_score).collect();
+        let average_maturity =
+            (!scores.is_empty()).then(|| scores.iter().sum::<f64>() / scores.len() as f64);
+
+        Ok(PortfolioReportData {
+            title: title.to_string(),
+            organization_id: organization_id.to_string(),
+            weakest_dimensions: Self::weakest(&dimension_summaries),
+            dimensions: dimension_summaries,
+            assessments,
+            average_maturity,
+            common_high_gaps,
+            common_recommendations,
+            generation_date: locale.format_date(chrono::Utc::now()),
+            locale,
+        })
+    }
+
+    /// Averages of one dimension over the cooperatives that answered it
+    pub fn summarize(name: String, samples: &[PortfolioSample]) -> PortfolioDimension {
+        let average = |score: fn(&PortfolioSample) -> i32| {
+            if samples.is_empty() {
+                0.0
+            } else {
+                samples.iter().map(|s| score(s) as f64).sum::<f64>() / samples.len() as f64
+            }
+        };
+        PortfolioDimension {
+            name,
+            assessed: samples.len(),
+            average_current: average(|s| s.current_score),
+            average_desired: average(|s| s.desired_score),
+            average_gap: average(|s| s.desired_score - s.current_score),
+            high_gaps: samples
+                .iter()
+                .filter(|s| s.gap_severity == GapSeverity::High)
+                .count(),
+        }
+    }
+
+    /// Answered dimensions with the largest average gap, ties broken by the
+    /// number of high gaps
+    pub fn weakest(dimensions: &[PortfolioDimension]) -> Vec<PortfolioDimension> {
+        let mut weakest: Vec<PortfolioDimension> = dimensions
+            .iter()
+            .filter(|d| d.assessed > 0)
+            .cloned()
+            .collect();
+        weakest.sort_by(|a, b| {
+            b.average_gap
+                .total_cmp(&a.average_gap)
+                .then(b.high_gaps.cmp(&a.high_gaps))
+                .then(a.name.cmp(&b.name))
+        });
+        weakest.truncate(WEAKEST_DIMENSIONS);
+        weakest
+    }
+
+    fn most_common(items: impl Iterator<Item = PortfolioItem>) -> Vec<PortfolioItem> {
+        let mut items: Vec<PortfolioItem> = items.collect();
+        items.sort_by(|a, b| {
+            b.occurrences
+                .cmp(&a.occurrences)
+                .then(a.dimension.cmp(&b.dimension))
+                .then(a.text.cmp(&b.text))
+        });
+        items.truncate(MOST_COMMON);
+        items
+    }
+
+    pub fn report_heading(locale: Locale) -> String {
+        locale.text("heading.portfolio")
+    }
+
+    /// Heatmap row label of an assessment
+    pub fn row_label(assessment: &PortfolioAssessment, locale: Locale) -> String {
+        assessment
+            .cooperation_id
+            .clone()
+            .unwrap_or_else(|| locale.text("portfolio.organization_level"))
+    }
+
+    /// Template context of `report_portfolio.html`; averages are formatted
+    /// for the report's locale
+    pub fn template_context(data: &PortfolioReportData) -> Context {
+        let locale = data.locale;
+        let format = |d: &PortfolioDimension| {
+            json!({
+                "name": d.name,
+                "assessed": d.assessed,
+                "average_current": locale.format_decimal(d.average_current),
+                "average_desired": locale.format_decimal(d.average_desired),
+                "average_gap": locale.format_decimal(d.average_gap),
+                "high_gaps": d.high_gaps,
+            })
+        };
+        let rows: Vec<_> = data
+            .assessments
+            .iter()
+            .map(|assessment| {
+                json!({
+                    "label": Self::row_label(assessment, locale),
+                    "assessment_title": assessment.assessment_title,
+                    "maturity_index": assessment.overall_score.map(|s| locale.format_decimal(s)),
+                    "cells": assessment.cells,
+                })
+            })
+            .collect();
+
+        let mut context = Context::new();
+        context.insert("report_heading", &Self::report_heading(locale));
+        context.insert("assessment_title", &data.title);
+        context.insert("locale", &locale);
+        context.insert("t", locale.catalog());
+        context.insert(
+            "dimensions",
+            &data.dimensions.iter().map(format).collect::<Vec<_>>(),
+        );
+        context.insert("rows", &rows);
+        context.insert(
+            "average_maturity",
+            &data.average_maturity.map(|s| locale.format_decimal(s)),
+        );
+        context.insert(
+            "weakest_dimensions",
+            &data
+                .weakest_dimensions
+                .iter()
+                .map(format)
+                .collect::<Vec<_>>(),
+        );
+        context.insert("common_high_gaps", &data.common_high_gaps);
+        context.insert("common_recommendations", &data.common_recommendations);
+        context.insert("generation_date", &data.generation_date);
+        context
+    }
+}
+
+pub fn gap_class(severity: &GapSeverity) -> &'static str {
+    match severity {
+        GapSeverity::High => "high",
+        GapSeverity::Medium => "medium",
+        GapSeverity::Low => "low",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(
+        current_score: i32,
+        desired_score: i32,
+        gap_severity: GapSeverity,
+    ) -> PortfolioSample {
+        PortfolioSample {
+            current_score,
+            desired_score,
+            gap_severity,
+        }
+    }
+
+    #[test]
+    fn test_summarize_dimension() {
+        let summary = PortfolioReportService::summarize(
+            "Strategy".to_string(),
+            &[
+                sample(1, 5, GapSeverity::High),
+                sample(3, 4, GapSeverity::Low),
+            ],
+        );
+        assert_eq!(summary.assessed, 2);
+        assert_eq!(summary.average_current, 2.0);
+        assert_eq!(summary.average_gap, 2.5);
+        assert_eq!(summary.high_gaps, 1);
+    }
+
+    #[test]
+    fn test_weakest_dimensions_first() {
+        let dimensions = vec![
+            PortfolioReportService::summarize("A".to_string(), &[sample(4, 5, GapSeverity::Low)]),
+            PortfolioReportService::summarize("B".to_string(), &[sample(1, 5, GapSeverity::High)]),
+            PortfolioReportService::summarize("C".to_string(), &[]),
+        ];
+        let names: Vec<String> = PortfolioReportService::weakest(&dimensions)
+            .into_iter()
+            .map(|d| d.name)
+            .collect();
+        assert_eq!(names, ["B", "A"]);
+    }
+}
@@ -0,0 +1,270 @@
+use crate::config::ReportQueueConfig;
+use crate::entities::reports::{self, ReportFormat, ReportStatus, ReportType};
+use crate::error::AppError;
+use crate::repositories::reports::ReportsRepository;
+use crate::services::pdf_generator::ReportOptions;
+use crate::services::portfolio_report::PortfolioOptions;
+use crate::services::report_service::ReportService;
+use sea_orm::{ConnectionTrait, DatabaseConnection, Set};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+/// Report generation queue persisted in the `reports` table.
+///
+/// A report moves `pending -> generating -> completed | failed`. Failed
+/// attempts go back to `pending` with an exponentially growing delay until
+/// `max_attempts` is reached. A fixed number of workers bounds how many
+/// reports are rendered at once.
+pub struct ReportQueue {
+    db: Arc<DatabaseConnection>,
+    report_service: Arc<ReportService>,
+    config: ReportQueueConfig,
+    notify: Notify,
+}
+
+impl ReportQueue {
+    pub fn new(
+        db: Arc<DatabaseConnection>,
+        report_service: Arc<ReportService>,
+        config: ReportQueueConfig,
+    ) -> Self {
+        Self {
+            db,
+            report_service,
+            config,
+            notify: Notify::new(),
+        }
+    }
+
+    /// Store a pending report and wake a worker to generate it.
+    pub async fn enqueue(
+        &self,
+        assessment_id: Uuid,
+        report_type: ReportType,
+        title: String,
+        format: ReportFormat,
+        options: &ReportOptions,
+    ) -> Result<reports::Model, AppError> {
+        let report = self
+            .enqueue_in(
+                self.db.as_ref(),
+                assessment_id,
+                report_type,
+                title,
+                format,
+                options,
+            )
+            .await?;
+        self.wake();
+        Ok(report)
+    }
+
+    /// Store a pending report through `db`, e.g. inside the transaction that
+    /// makes it necessary. Call [`Self::wake`] once the report is committed.
+    pub async fn enqueue_in<C: ConnectionTrait>(
+        &self,
+        db: &C,
+        assessment_id: Uuid,
+        report_type: ReportType,
+        title: String,
+        format: ReportFormat,
+        options: &ReportOptions,
+    ) -> Result<reports::Model, AppError> {
+        Self::insert_pending(
+            db,
+            Some(assessment_id),
+            None,
+            report_type,
+            title,
+            format,
+            options_value(options)?,
+        )
+        .await
+    }
+
+    /// Store a pending portfolio report of an organization.
+    pub async fn enqueue_portfolio(
+        &self,
+        organization_id: String,
+        title: String,
+        format: ReportFormat,
+        options: &PortfolioOptions,
+    ) -> Result<reports::Model, AppError> {
+        let report = Self::insert_pending(
+            self.db.as_ref(),
+            None,
+            Some(organization_id),
+            ReportType::Portfolio,
+            title,
+            format,
+            options_value(options)?,
+        )
+        .await?;
+        self.wake();
+        Ok(report)
+    }
+
+    /// Wake a worker to pick up newly queued reports.
+    pub fn wake(&self) {
+        self.notify.notify_one();
+    }
+
+    async fn insert_pending<C: ConnectionTrait>(
+        db: &C,
+        assessment_id: Option<Uuid>,
+        organization_id: Option<String>,
+        report_type: ReportType,
+        title: String,
+        format: ReportFormat,
+        options: serde_json::Value,
+    ) -> Result<reports::Model, AppError> {
+        let now = chrono::Utc::now();
+        let report = reports::ActiveModel {
+            report_id: Set(Uuid::new_v4()),
+            assessment_id: Set(assessment_id),
+            organization_id: Set(organization_id),
+            report_type: Set(report_type),
+            title: Set(title),
+            format: Set(format),
+            summary: Set(None),
+            report_data: Set(None),
+            file_path: Set(None),
+            file_sha256: Set(None),
+            status: Set(ReportStatus::Pending),
+            options: Set(Some(options)),
+            progress: Set(0),
+            attempts: Set(0),
+            next_attempt_at: Set(None),
+            failure_reason: Set(None),
+            claimed_at: Set(None),
+            generated_at: Set(now),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+
+        let report = ReportsRepository::create(db, report).await?;
+        info!(report_id = %report.report_id, "Report queued for generation");
+        Ok(report)
+    }
+
+    /// Requeue reports whose worker stopped and spawn the workers.
+    pub async fn start(self: &Arc<Self>) -> Result<(), AppError> {
+        self.requeue_interrupted().await?;
+
+        let workers = self.config.workers.max(1);
+        for worker in 0..workers {
+            tokio::spawn(self.clone().run_worker(worker));
+        }
+        info!(workers, "Report workers started");
+        Ok(())
+    }
+
+    /// Requeue generating reports whose lease expired, see
+    /// [`ReportsRepository::requeue_interrupted`].
+    async fn requeue_interrupted(&self) -> Result<(), AppError> {
+        let lease = Duration::from_secs(self.config.lease_secs);
+        let requeued = ReportsRepository::requeue_interrupted(self.db.as_ref(), lease).await?;
+        if requeued > 0 {
+            info!(count = requeued, "Requeued interrupted report jobs");
+        }
+        Ok(())
+    }
+
+    async fn run_worker(self: Arc<Self>, worker: usize) {
+        let poll_interval = Duration::from_secs(self.config.poll_interval_secs);
+        loop {
+            match ReportsRepository::claim_next_pending(self.db.as_ref()).await {
+                Ok(Some(report)) => self.process(worker, report).await,
+                Ok(None) => {
+                    // Jobs of a stopped server are taken over once their
+                    // lease expires
+                    if let Err(e) = self.requeue_interrupted().await {
+                        warn!(worker, error = %e, "Failed to requeue interrupted report jobs");
+                    }
+                    // Retries become due without a notification, so idle
+                    // workers also poll
+                    tokio::select! {
+                        _ = self.notify.notified() => {}
+                        _ = tokio::time::sleep(poll_interval) => {}
+                    }
+                }
+                Err(e) => {
+                    error!(worker, error = %e, "Failed to claim report job");
+                    tokio::time::sleep(poll_interval).await;
+                }
+            }
+        }
+    }
+
+    async fn process(&self, worker: usize, report: reports::Model) {
+        let report_id = report.report_id;
+        info!(worker, report_id = %report_id, attempt = report.attempts, "Generating report");
+
+        let result = match self.report_service.generate_report_file(&report).await {
+            Ok(stored) => {
+                info!(report_id = %report_id, "Report generated");
+                ReportsRepository::mark_completed(
+                    self.db.as_ref(),
+                    report_id,
+                    stored.object_name,
+                    stored.file_sha256,
+                    stored.snapshot,
+                )
+                .await
+            }
+            Err(e) if report.attempts < self.config.max_attempts => {
+                let delay = Self::retry_delay(self.config.retry_backoff_secs, report.attempts);
+                warn!(report_id = %report_id, error = %e, retry_in_secs = delay.as_secs(), "Report generation failed, retrying");
+                let next_attempt_at =
+                    chrono::Utc::now() + chrono::Duration::seconds(delay.as_secs() as i64);
+                ReportsRepository::schedule_retry(
+                    self.db.as_ref(),
+                    report_id,
+                    e.to_string(),
+                    next_attempt_at,
+                )
+                .await
+            }
+            Err(e) => {
+                error!(report_id = %report_id, error = %e, "Report generation failed, giving up");
+                ReportsRepository::mark_failed(self.db.as_ref(), report_id, e.to_string()).await
+            }
+        };
+
+        if let Err(e) = result {
+            error!(report_id = %report_id, error = %e, "Failed to record report job outcome");
+        }
+    }
+
+    /// Delay before retrying after `attempt` failed attempts:
+    /// `base * 2^(attempt - 1)`, capped at one day.
+    pub fn retry_delay(base_secs: u64, attempt: i32) -> Duration {
+        let exponent = attempt.clamp(1, 17) as u32 - 1;
+        Duration::from_secs(base_secs.saturating_mul(1 << exponent).min(24 * 60 * 60))
+    }
+}
+
+fn options_value(options: &impl serde::Serialize) -> Result<serde_json::Value, AppError> {
+    serde_json::to_value(options).map_err(|e| {
+        AppError::InternalServerError(format!("Failed to serialize report options: {}", e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay_doubles() {
+        assert_eq!(ReportQueue::retry_delay(30, 1), Duration::from_secs(30));
+        assert_eq!(ReportQueue::retry_delay(30, 2), Duration::from_secs(60));
+        assert_eq!(ReportQueue::retry_delay(30, 3), Duration::from_secs(120));
+        assert_eq!(
+            ReportQueue::retry_delay(30, 40),
+            Duration::from_secs(24 * 60 * 60)
+        );
+    }
+}
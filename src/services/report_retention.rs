@@ -0,0 +1,352 @@
+use crate::config::{ReportRetentionConfig, RetentionPolicies};
+use crate::entities::reports::{self, ReportStatus};
+use crate::error::AppError;
+use crate::repositories::reports::ReportsRepository;
+use crate::services::report_service::ReportService;
+use crate::services::s3_storage::{FileStorageService, StoredFile, REPORT_OBJECT_PREFIX};
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::{ActiveEnum, DatabaseConnection};
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+/// What a sweep cleaned up
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SweepSummary {
+    pub expired_reports: usize,
+    pub orphaned_files: usize,
+    pub missing_files: usize,
+}
+
+/// Differences between the `reports` table and storage
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Reconciliation {
+    /// Stored files no report refers to
+    pub orphaned_files: Vec<String>,
+    /// Completed reports whose file is not stored
+    pub missing_files: Vec<Uuid>,
+}
+
+/// Periodically deletes reports past their retention policy and reconciles
+/// the `reports` table with storage.
+///
+/// Files left behind by a failed upload or insert are deleted once they are
+/// older than `orphan_grace_secs`. Completed reports whose file is gone are
+/// marked as failed.
+pub struct ReportRetention {
+    db: Arc<DatabaseConnection>,
+    report_service: Arc<ReportService>,
+    config: ReportRetentionConfig,
+}
+
+impl ReportRetention {
+    pub fn new(
+        db: Arc<DatabaseConnection>,
+        report_service: Arc<ReportService>,
+        config: ReportRetentionConfig,
+    ) -> Self {
+        Self {
+            db,
+            report_service,
+            config,
+        }
+    }
+
+    /// Spawn the sweeper, unless `sweep_interval_secs` is 0
+    pub fn start(self: &Arc<Self>) {
+        if self.config.sweep_interval_secs == 0 {
+            info!("Report retention sweeper disabled");
+            return;
+        }
+        tokio::spawn(self.clone().run());
+        info!(
+            interval_secs = self.config.sweep_interval_secs,
+            "Report retention sweeper started"
+        );
+    }
+
+    async fn run(self: Arc<Self>) {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(
+            self.config.sweep_interval_secs,
+        ));
+        loop {
+            interval.tick().await;
+            match self.sweep().await {
+                Ok(summary) => info!(?summary, "Report retention sweep finished"),
+                Err(e) => error!(error = %e, "Report retention sweep failed"),
+            }
+        }
+    }
+
+    /// Delete expired reports, then reconcile the remaining ones with
+    /// storage
+    pub async fn sweep(&self) -> Result<SweepSummary, AppError> {
+        let now = Utc::now();
+        let mut summary = SweepSummary::default();
+        let reports = ReportsRepository::find_all_unscoped(self.db.as_ref()).await?;
+
+        let expired: HashSet<Uuid> = expired_reports(&reports, &self.config.policies, now)
+            .into_iter()
+            .collect();
+        for report_id in &expired {
+            match self.report_service.delete_report(*report_id).await {
+                Ok(_) => summary.expired_reports += 1,
+                Err(e) => {
+                    warn!(report_id = %report_id, error = %e, "Failed to delete expired report")
+                }
+            }
+        }
+
+        let remaining: Vec<reports::Model> = reports
+            .into_iter()
+            .filter(|report| !expired.contains(&report.report_id))
+            .collect();
+        let files = self.report_service.list_files(REPORT_OBJECT_PREFIX).await?;
+        let uploaded_before = now - Duration::seconds(self.config.orphan_grace_secs as i64);
+        let reconciliation = reconcile(&remaining, &files, uploaded_before);
+
+        for object_name in &reconciliation.orphaned_files {
+            match self.report_service.delete_file(object_name).await {
+                Ok(()) => summary.orphaned_files += 1,
+                Err(e) => {
+                    warn!(object_name = %object_name, error = %e, "Failed to delete orphaned file")
+                }
+            }
+        }
+        for report_id in &reconciliation.missing_files {
+            match ReportsRepository::mark_file_missing(self.db.as_ref(), *report_id).await {
+                Ok(_) => summary.missing_files += 1,
+                Err(e) => {
+                    warn!(report_id = %report_id, error = %e, "Failed to mark report with missing file")
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+/// Finished reports past their type's retention policy at `now`
+pub fn expired_reports(
+    reports: &[reports::Model],
+    policies: &RetentionPolicies,
+    now: DateTime<Utc>,
+) -> Vec<Uuid> {
+    let mut finished: Vec<&reports::Model> = reports
+        .iter()
+        .filter(|report| {
+            matches!(
+                report.status,
+                ReportStatus::Completed | ReportStatus::Failed
+            )
+        })
+        .collect();
+    finished.sort_by_key(|report| Reverse(report.created_at));
+
+    // Newer reports seen so far, per report type and assessment or
+    // organization
+    let mut newer: HashMap<(String, Option<Uuid>, Option<&str>), usize> = HashMap::new();
+    finished
+        .into_iter()
+        .filter(|report| {
+            let Some(policy) = policies.for_type(&report.report_type) else {
+                return false;
+            };
+            let scope = (
+                report.report_type.to_value(),
+                report.assessment_id,
+                report.organization_id.as_deref(),
+            );
+            let rank = newer.entry(scope).or_insert(0);
+            *rank += 1;
+
+            let too_old = policy
+                .max_age_days
+                .is_some_and(|days| report.created_at < now - Duration::days(days.into()));
+            let superseded = policy.keep_latest.is_some_and(|keep| *rank > keep);
+            too_old || superseded
+        })
+        .map(|report| report.report_id)
+        .collect()
+}
+
+/// Compare reports with the report files in storage. Files modified after
+/// `uploaded_before`, or belonging to a report still being generated, may
+/// not be recorded yet and are never orphans.
+pub fn reconcile(
+    reports: &[reports::Model],
+    files: &[StoredFile],
+    uploaded_before: DateTime<Utc>,
+) -> Reconciliation {
+    let referenced: HashSet<&str> = reports
+        .iter()
+        .filter_map(|report| report.file_path.as_deref())
+        .collect();
+    let in_progress: HashSet<Uuid> = reports
+        .iter()
+        .filter(|report| {
+            matches!(
+                report.status,
+                ReportStatus::Pending | ReportStatus::Generating
+            )
+        })
+        .map(|report| report.report_id)
+        .collect();
+    let stored: HashSet<&str> = files.iter().map(|file| file.object_name.as_str()).collect();
+
+    let orphaned_files = files
+        .iter()
+        .filter(|file| !referenced.contains(file.object_name.as_str()))
+        .filter(|file| {
+            file.last_modified
+                .is_some_and(|time| time < uploaded_before)
+        })
+        .filter(|file| report_id_of(&file.object_name).is_none_or(|id| !in_progress.contains(&id)))
+        .map(|file| file.object_name.clone())
+        .collect();
+    let missing_files = reports
+        .iter()
+        .filter(|report| report.status == ReportStatus::Completed)
+        .filter(|report| {
+            report.file_path.as_deref().is_some_and(|path| {
+                path.starts_with(REPORT_OBJECT_PREFIX) && !stored.contains(path)
+            })
+        })
+        .map(|report| report.report_id)
+        .collect();
+
+    Reconciliation {
+        orphaned_files,
+        missing_files,
+    }
+}
+
+/// Report a file belongs to, from its object name
+fn report_id_of(object_name: &str) -> Option<Uuid> {
+    object_name
+        .strip_prefix(REPORT_OBJECT_PREFIX)?
+        .split('/')
+        .next()?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::reports::{ReportFormat, ReportType};
+    use crate::services::s3_storage::report_object_name;
+
+    fn report(
+        report_type: ReportType,
+        assessment_id: Uuid,
+        age_days: i64,
+        status: ReportStatus,
+    ) -> reports::Model {
+        let report_id = Uuid::new_v4();
+        let created_at = Utc::now() - Duration::days(age_days);
+        reports::Model {
+            report_id,
+            assessment_id: Some(assessment_id),
+            organization_id: None,
+            report_type,
+            title: "Report".to_string(),
+            format: ReportFormat::Pdf,
+            summary: None,
+            report_data: None,
+            file_path: (status == ReportStatus::Completed)
+                .then(|| report_object_name(&report_id, "pdf")),
+            file_sha256: None,
+            status,
+            options: None,
+            progress: 100,
+            attempts: 1,
+            next_attempt_at: None,
+            failure_reason: None,
+            claimed_at: None,
+            generated_at: created_at,
+            created_at,
+            updated_at: created_at,
+        }
+    }
+
+    #[test]
+    fn test_expired_reports() {
+        let policies: RetentionPolicies = "summary:keep=2;detailed:days=30".parse().unwrap();
+        let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+        let reports = vec![
+            report(ReportType::Summary, first, 1, ReportStatus::Completed),
+            report(ReportType::Summary, first, 2, ReportStatus::Failed),
+            report(ReportType::Summary, first, 3, ReportStatus::Completed),
+            report(ReportType::Summary, first, 4, ReportStatus::Pending),
+            report(ReportType::Summary, second, 5, ReportStatus::Completed),
+            report(ReportType::Detailed, first, 10, ReportStatus::Completed),
+            report(ReportType::Detailed, first, 40, ReportStatus::Completed),
+            report(ReportType::ActionPlan, first, 400, ReportStatus::Completed),
+        ];
+
+        let expired = expired_reports(&reports, &policies, Utc::now());
+        assert_eq!(expired, vec![reports[2].report_id, reports[6].report_id]);
+    }
+
+    #[test]
+    fn test_parse_retention_policies() {
+        let policies: RetentionPolicies = " summary:keep=5 ; portfolio:days=90,keep=3;"
+            .parse()
+            .unwrap();
+        let portfolio = policies.for_type(&ReportType::Portfolio).unwrap();
+        assert_eq!(portfolio.max_age_days, Some(90));
+        assert_eq!(portfolio.keep_latest, Some(3));
+        assert!(policies.for_type(&ReportType::Detailed).is_none());
+        assert!("".parse::<RetentionPolicies>().unwrap().is_empty());
+        assert!("weekly:keep=1".parse::<RetentionPolicies>().is_err());
+        assert!("summary:keep=many".parse::<RetentionPolicies>().is_err());
+        assert!("summary".parse::<RetentionPolicies>().is_err());
+    }
+
+    #[test]
+    fn test_reconcile() {
+        let assessment_id = Uuid::new_v4();
+        let completed = report(
+            ReportType::Summary,
+            assessment_id,
+            1,
+            ReportStatus::Completed,
+        );
+        let missing = report(
+            ReportType::Summary,
+            assessment_id,
+            2,
+            ReportStatus::Completed,
+        );
+        let generating = report(
+            ReportType::Summary,
+            assessment_id,
+            0,
+            ReportStatus::Generating,
+        );
+        let now = Utc::now();
+        let file = |object_name: String, age_secs: i64| StoredFile {
+            object_name,
+            last_modified: Some(now - Duration::seconds(age_secs)),
+        };
+        let orphan = report_object_name(&Uuid::new_v4(), "pdf");
+        let files = vec![
+            file(completed.file_path.clone().unwrap(), 7200),
+            file(orphan.clone(), 7200),
+            // Still uploading, or not recorded yet
+            file(report_object_name(&Uuid::new_v4(), "pdf"), 60),
+            file(report_object_name(&generating.report_id, "pdf"), 7200),
+        ];
+
+        let reconciliation = reconcile(
+            &[completed, missing.clone(), generating],
+            &files,
+            now - Duration::hours(1),
+        );
+        assert_eq!(reconciliation.orphaned_files, vec![orphan]);
+        assert_eq!(reconciliation.missing_files, vec![missing.report_id]);
+    }
+}
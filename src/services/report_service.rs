@@ -1,33 +1,169 @@
+use crate::config::{
+    BrowserPoolConfig, Config, DownloadLinkConfig, MinioConfig, PdfBackend, StorageBackend,
+};
 use crate::entities::reports::{ReportFormat, ReportStatus, ReportType};
 use crate::error::AppError;
 use crate::repositories::reports::ReportsRepository;
-use crate::services::pdf_generator::PdfGeneratorService;
-use crate::services::s3_storage::{FileStorageService, S3StorageService};
+use crate::services::browser_pool::BrowserPool;
+use crate::services::download_links::{DownloadLink, DownloadLinkSigner};
+use crate::services::json_report::JsonReportService;
+use crate::services::local_storage::LocalStorageService;
+use crate::services::native_pdf::NativePdfRenderer;
+use crate::services::pdf_generator::{PdfGeneratorService, ReportOptions};
+use crate::services::portfolio_report::{PortfolioOptions, PortfolioReportService};
+use crate::services::report_templates::ReportTemplateService;
+use crate::services::s3_storage::{
+    report_object_name, FileStorageService, S3StorageService, StoredFile,
+};
+use crate::services::xlsx_generator::XlsxGeneratorService;
 use async_trait::async_trait;
 use bytes::Bytes;
+use chrono::Utc;
 use sea_orm::DatabaseConnection;
-use tracing::{error, info, instrument};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use tracing::{info, instrument};
 use uuid::Uuid;
 
 use std::sync::Arc;
 
+/// A rendered report file with a frozen copy of the data it shows
+pub struct RenderedReport {
+    pub file: Bytes,
+    pub snapshot: serde_json::Value,
+}
+
+impl RenderedReport {
+    fn new(file: Bytes, data: &impl Serialize) -> Result<Self, AppError> {
+        let snapshot = serde_json::to_value(data).map_err(|e| {
+            AppError::InternalServerError(format!("Failed to snapshot report data: {}", e))
+        })?;
+        Ok(Self { file, snapshot })
+    }
+}
+
+/// A generated report file in storage
+pub struct StoredReportFile {
+    pub object_name: String,
+    /// Hex SHA-256 of the uploaded file
+    pub file_sha256: String,
+    pub snapshot: serde_json::Value,
+}
+
 pub struct ReportService {
-    storage_service: S3StorageService,
+    storage_service: Arc<dyn FileStorageService>,
     db: Arc<DatabaseConnection>,
+    pdf_backend: PdfBackend,
+    browser_pool: Arc<BrowserPool>,
+    report_templates: Arc<ReportTemplateService>,
+    download_links: DownloadLinkSigner,
 }
 
 impl ReportService {
-    pub async fn new(
-        config: &crate::config::MinioConfig,
+    pub async fn new(config: &MinioConfig, db: Arc<DatabaseConnection>) -> Result<Self, AppError> {
+        let storage_service = S3StorageService::new(config).await?;
+        Self::with_storage(Arc::new(storage_service), db)
+    }
+
+    /// Store report files with the backend selected by `storage_backend`
+    pub async fn from_config(
+        config: &Config,
         db: Arc<DatabaseConnection>,
     ) -> Result<Self, AppError> {
-        let storage_service = S3StorageService::new(config).await?;
+        let storage_service: Arc<dyn FileStorageService> = match config.storage_backend {
+            StorageBackend::S3 => Arc::new(S3StorageService::new(&config.minio).await?),
+            StorageBackend::Local => {
+                Arc::new(LocalStorageService::new(&config.local_storage).await?)
+            }
+        };
+        info!(storage_backend = ?config.storage_backend, "Report storage initialized.");
+        Self::with_storage(storage_service, db)
+    }
+
+    /// Store report files in `storage_service`
+    pub fn with_storage(
+        storage_service: Arc<dyn FileStorageService>,
+        db: Arc<DatabaseConnection>,
+    ) -> Result<Self, AppError> {
+        let report_templates = Arc::new(ReportTemplateService::new(db.clone())?);
         Ok(Self {
             storage_service,
             db,
+            pdf_backend: PdfBackend::default(),
+            browser_pool: Arc::new(BrowserPool::new(BrowserPoolConfig::default())),
+            report_templates,
+            download_links: DownloadLinkSigner::new(DownloadLinkConfig::default()),
         })
     }
 
+    /// Render PDF reports with `pdf_backend` instead of headless Chrome
+    pub fn with_pdf_backend(mut self, pdf_backend: PdfBackend) -> Self {
+        self.pdf_backend = pdf_backend;
+        self
+    }
+
+    /// Share `browser_pool` for Chrome rendering instead of a private pool
+    pub fn with_browser_pool(mut self, browser_pool: Arc<BrowserPool>) -> Self {
+        self.browser_pool = browser_pool;
+        self
+    }
+
+    /// Share `report_templates`, and with it the compiled template cache
+    pub fn with_report_templates(mut self, report_templates: Arc<ReportTemplateService>) -> Self {
+        self.report_templates = report_templates;
+        self
+    }
+
+    /// Sign backend download links as configured in `download_links`
+    pub fn with_download_links(mut self, download_links: DownloadLinkConfig) -> Self {
+        self.download_links = DownloadLinkSigner::new(download_links);
+        self
+    }
+
+    /// Expiring link to a generated report file, presigned by the storage
+    /// backend when it can and signed by the API otherwise
+    #[instrument(skip(self, report), fields(report_id = %report.report_id))]
+    pub async fn download_link(
+        &self,
+        report: &crate::entities::reports::Model,
+        expires_in_secs: Option<u64>,
+    ) -> Result<DownloadLink, AppError> {
+        let object_name = report
+            .file_path
+            .as_ref()
+            .ok_or_else(|| AppError::NotFound("Report file not available".to_string()))?;
+
+        let lifetime = self.download_links.lifetime(expires_in_secs);
+        let expires_at = Utc::now() + lifetime;
+        let presigned = self
+            .storage_service
+            .presigned_download_url(
+                object_name,
+                &download_file_name(report),
+                lifetime.to_std().unwrap_or_default(),
+            )
+            .await?;
+        let url = match presigned {
+            Some(url) => url,
+            None => self.download_links.signed_url(report.report_id, expires_at),
+        };
+
+        Ok(DownloadLink { url, expires_at })
+    }
+
+    /// Report file behind a link signed by `download_link`
+    pub async fn get_signed_report_file(
+        &self,
+        report_id: Uuid,
+        expires: i64,
+        signature: &str,
+    ) -> Result<(crate::entities::reports::Model, Bytes), AppError> {
+        self.download_links
+            .verify(report_id, expires, signature, Utc::now())?;
+        self.get_report_file(report_id).await
+    }
+
     pub async fn generate_and_store_report(
         &self,
         assessment_id: Uuid,
@@ -38,35 +174,34 @@ impl ReportService {
     ) -> Result<crate::entities::reports::Model, AppError> {
         // Create report record in database
         let report_id = Uuid::new_v4();
-        let object_name = self
-            .storage_service
-            .generate_object_name(&report_id, &format.to_string());
+        let object_name = report_object_name(&report_id, format.file_extension());
+        let file_sha256 = sha256_hex(&report_data);
 
-        let content_type = match format {
-            ReportFormat::Pdf => "application/pdf",
-            ReportFormat::Excel => {
-                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
-            }
-            ReportFormat::Json => "application/json",
-        };
-
-        // Upload file to S3/MinIO
+        // Upload file to storage
         let _ = self
             .storage_service
-            .upload_file(&object_name, report_data, content_type)
+            .upload_file(&object_name, report_data, format.content_type())
             .await?;
 
         // Create report entity
         let report = crate::entities::reports::ActiveModel {
             report_id: sea_orm::ActiveValue::Set(report_id),
-            assessment_id: sea_orm::ActiveValue::Set(assessment_id),
+            assessment_id: sea_orm::ActiveValue::Set(Some(assessment_id)),
+            organization_id: sea_orm::ActiveValue::Set(None),
             report_type: sea_orm::ActiveValue::Set(report_type),
             title: sea_orm::ActiveValue::Set(title),
             format: sea_orm::ActiveValue::Set(format),
             summary: sea_orm::ActiveValue::Set(None),
             report_data: sea_orm::ActiveValue::Set(None), // Store in object storage instead
             file_path: sea_orm::ActiveValue::Set(Some(object_name)),
+            file_sha256: sea_orm::ActiveValue::Set(Some(file_sha256)),
             status: sea_orm::ActiveValue::Set(ReportStatus::Completed),
+            options: sea_orm::ActiveValue::Set(None),
+            progress: sea_orm::ActiveValue::Set(100),
+            attempts: sea_orm::ActiveValue::Set(1),
+            next_attempt_at: sea_orm::ActiveValue::Set(None),
+            failure_reason: sea_orm::ActiveValue::Set(None),
+            claimed_at: sea_orm::ActiveValue::Set(None),
             generated_at: sea_orm::ActiveValue::Set(chrono::Utc::now()),
             created_at: sea_orm::ActiveValue::Set(chrono::Utc::now()),
             updated_at: sea_orm::ActiveValue::Set(chrono::Utc::now()),
@@ -75,6 +210,77 @@ impl ReportService {
         ReportsRepository::create(self.db.as_ref(), report).await
     }
 
+    /// Render the report file of an assessment in the requested format,
+    /// with the sections included by `options`
+    pub async fn render_report(
+        &self,
+        assessment_id: Uuid,
+        format: &ReportFormat,
+        options: &ReportOptions,
+    ) -> Result<RenderedReport, AppError> {
+        if *format == ReportFormat::Json {
+            let report =
+                JsonReportService::build_report(self.db.as_ref(), assessment_id, options).await?;
+            return RenderedReport::new(JsonReportService::to_bytes(&report)?, &report);
+        }
+
+        let data =
+            PdfGeneratorService::fetch_report_data(self.db.as_ref(), assessment_id, options.locale)
+                .await?;
+        let file = match format {
+            ReportFormat::Excel => XlsxGeneratorService::build_workbook(&data, options)?,
+            _ => {
+                PdfGeneratorService::render_pdf(
+                    &data,
+                    options,
+                    self.pdf_backend,
+                    &self.browser_pool,
+                    &self.report_templates,
+                )
+                .await?
+            }
+        };
+        RenderedReport::new(file, &data)
+    }
+
+    /// Render the portfolio report of an organization in the requested
+    /// format
+    pub async fn render_portfolio_report(
+        &self,
+        organization_id: &str,
+        title: &str,
+        format: &ReportFormat,
+        options: &PortfolioOptions,
+    ) -> Result<RenderedReport, AppError> {
+        let data = PortfolioReportService::fetch_portfolio_data(
+            self.db.as_ref(),
+            organization_id,
+            title,
+            options,
+        )
+        .await?;
+        let file = match format {
+            ReportFormat::Pdf if self.pdf_backend == PdfBackend::Native => {
+                NativePdfRenderer::render_portfolio(&data)?
+            }
+            ReportFormat::Pdf => {
+                let html = self.report_templates.render_portfolio(&data)?;
+                self.browser_pool.html_to_pdf(&html).await?
+            }
+            ReportFormat::Excel => XlsxGeneratorService::build_portfolio_workbook(&data)?,
+            ReportFormat::Json => {
+                let json = serde_json::to_vec_pretty(&data).map_err(|e| {
+                    AppError::InternalServerError(format!(
+                        "Failed to serialize portfolio report: {}",
+                        e
+                    ))
+                })?;
+                Bytes::from(json)
+            }
+        };
+        RenderedReport::new(file, &data)
+    }
+
     pub async fn get_report_file(
         &self,
         report_id: Uuid,
@@ -100,7 +306,7 @@ impl ReportService {
             .as_ref()
             .ok_or_else(|| AppError::NotFound("Report file not available".to_string()))?;
 
-        tracing::info!(object_name = %object_name, "Downloading file from S3");
+        tracing::info!(object_name = %object_name, "Downloading file from storage");
         let file_data = self.storage_service.download_file(object_name).await?;
 
         Ok((report, file_data))
@@ -112,7 +318,7 @@ impl ReportService {
             .await?
             .ok_or_else(|| AppError::NotFound("Report not found".to_string()))?;
 
-        // Delete file from S3/MinIO if it exists
+        // Delete file from storage if it exists
         if let Some(object_name) = &report.file_path {
             self.storage_service.delete_file(object_name).await?;
         }
@@ -120,84 +326,60 @@ impl ReportService {
         // Delete report from database
         ReportsRepository::delete(self.db.as_ref(), report_id).await
     }
-    #[instrument(skip(self), fields(report_id = %report_id))]
-    pub async fn generate_report_for_submission(&self, report_id: Uuid) -> Result<(), AppError> {
-        info!("Starting report generation for submission.");
 
-        // 1. Fetch the report to get the assessment_id
-        let report = match ReportsRepository::find_by_id(self.db.as_ref(), report_id).await {
-            Ok(Some(r)) => r,
-            Ok(None) => {
-                error!("Report not found in database.");
-                return Err(AppError::NotFound("Report not found".to_string()));
+    /// Render a queued report with its stored options and upload the file,
+    /// recording progress on the way.
+    #[instrument(skip(self, report), fields(report_id = %report.report_id))]
+    pub async fn generate_report_file(
+        &self,
+        report: &crate::entities::reports::Model,
+    ) -> Result<StoredReportFile, AppError> {
+        let rendered = match (report.assessment_id, &report.organization_id) {
+            (Some(assessment_id), _) => {
+                let options = match &report.options {
+                    Some(options) => stored_options(options)?,
+                    None => ReportOptions::for_type(report.report_type.clone()),
+                };
+                info!(assessment_id = %assessment_id, format = %report.format, "Generating report file for assessment.");
+                self.render_report(assessment_id, &report.format, &options)
+                    .await?
             }
-            Err(e) => {
-                error!(error = %e, "Database error while fetching report.");
-                return Err(e);
+            (None, Some(organization_id)) => {
+                let options = match &report.options {
+                    Some(options) => stored_options(options)?,
+                    None => PortfolioOptions::default(),
+                };
+                info!(organization_id = %organization_id, format = %report.format, "Generating portfolio report file.");
+                self.render_portfolio_report(
+                    organization_id,
+                    &report.title,
+                    &report.format,
+                    &options,
+                )
+                .await?
+            }
+            (None, None) => {
+                return Err(AppError::InternalServerError(
+                    "Report has neither an assessment nor an organization".to_string(),
+                ))
             }
         };
+        ReportsRepository::update_progress(self.db.as_ref(), report.report_id, 70).await?;
 
-        // Wrap the generation logic in a block to handle errors and update status
-        let generation_result = async {
-            // 2. Generate the PDF using the PdfGeneratorService
-            info!(assessment_id = %report.assessment_id, "Generating PDF for assessment.");
-            let pdf_bytes = PdfGeneratorService::generate_assessment_pdf(
-                self.db.as_ref(),
-                report.assessment_id,
-            )
-            .await?;
-
-            // 3. Store the report file in S3/MinIO
-            info!("Uploading generated PDF to storage.");
-            let object_name = self
-                .storage_service
-                .generate_object_name(&report.report_id, &ReportFormat::Pdf.to_string());
-            self.storage_service
-                .upload_file(&object_name, pdf_bytes, "application/pdf")
-                .await?;
-            info!(object_name = %object_name, "PDF uploaded successfully.");
-
-            // 4. Update the report status to 'Completed'
-            info!("Updating report status to 'Completed'.");
-            let mut updated_report: crate::entities::reports::ActiveModel = report.into();
-            updated_report.status = sea_orm::ActiveValue::Set(ReportStatus::Completed);
-            updated_report.file_path = sea_orm::ActiveValue::Set(Some(object_name));
-            updated_report.generated_at = sea_orm::ActiveValue::Set(chrono::Utc::now());
-            updated_report.updated_at = sea_orm::ActiveValue::Set(chrono::Utc::now());
-            ReportsRepository::update(
-                self.db.as_ref(),
-                updated_report.report_id.clone().unwrap(),
-                updated_report,
-            )
-            .await?;
-            info!("Report status updated to 'Completed'.");
-
-            Ok(())
-        }
-        .await;
-
-        if let Err(e) = generation_result {
-            error!(error = %e, "Report generation failed. Updating status to 'Failed'.");
-            let report_to_fail = ReportsRepository::find_by_id(self.db.as_ref(), report_id)
-                .await?
-                .ok_or_else(|| {
-                    AppError::NotFound("Report disappeared during failure handling".to_string())
-                })?;
-
-            let mut updated_report: crate::entities::reports::ActiveModel = report_to_fail.into();
-            updated_report.status = sea_orm::ActiveValue::Set(ReportStatus::Failed);
-            updated_report.updated_at = sea_orm::ActiveValue::Set(chrono::Utc::now());
-            ReportsRepository::update(
-                self.db.as_ref(),
-                updated_report.report_id.clone().unwrap(),
-                updated_report,
-            )
+        info!("Uploading generated report to storage.");
+        let object_name = report_object_name(&report.report_id, report.format.file_extension());
+        let file_sha256 = sha256_hex(&rendered.file);
+        self.storage_service
+            .upload_file(&object_name, rendered.file, report.format.content_type())
             .await?;
-            return Err(e);
-        }
+        ReportsRepository::update_progress(self.db.as_ref(), report.report_id, 90).await?;
+        info!(object_name = %object_name, file_sha256 = %file_sha256, "Report uploaded successfully.");
 
-        info!("Report generation process completed successfully.");
-        Ok(())
+        Ok(StoredReportFile {
+            object_name,
+            file_sha256,
+            snapshot: rendered.snapshot,
+        })
     }
 }
 
@@ -221,4 +403,118 @@ impl FileStorageService for ReportService {
     async fn delete_file(&self, object_name: &str) -> Result<(), AppError> {
         self.storage_service.delete_file(object_name).await
     }
+
+    async fn list_files(&self, prefix: &str) -> Result<Vec<StoredFile>, AppError> {
+        self.storage_service.list_files(prefix).await
+    }
+
+    async fn presigned_download_url(
+        &self,
+        object_name: &str,
+        filename: &str,
+        expires_in: std::time::Duration,
+    ) -> Result<Option<String>, AppError> {
+        self.storage_service
+            .presigned_download_url(object_name, filename, expires_in)
+            .await
+    }
+}
+
+/// Hex SHA-256 of a report file
+pub fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+/// File name a report is downloaded as. The title is user input, so control
+/// characters, quotes and path separators are dropped.
+pub fn download_file_name(report: &crate::entities::reports::Model) -> String {
+    let title: String = report
+        .title
+        .chars()
+        .filter(|c| !c.is_control() && !matches!(c, '"' | '\\' | '/'))
+        .map(|c| if c.is_whitespace() { '_' } else { c })
+        .collect();
+    format!("{}.{}", title, report.format.file_extension())
+}
+
+/// `Content-Disposition` of a download: an ASCII `filename` for old clients
+/// and the exact name as RFC 5987 `filename*`
+pub fn content_disposition(file_name: &str) -> String {
+    let ascii: String = file_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_graphic() && !matches!(c, '"' | '\\') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let mut encoded = String::with_capacity(file_name.len());
+    for byte in file_name.bytes() {
+        if byte.is_ascii_alphanumeric() || b"!#$&+-.^_`|~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    format!(
+        "attachment; filename=\"{}\"; filename*=UTF-8''{}",
+        ascii, encoded
+    )
+}
+
+fn stored_options<T: DeserializeOwned>(options: &serde_json::Value) -> Result<T, AppError> {
+    serde_json::from_value(options.clone())
+        .map_err(|e| AppError::InternalServerError(format!("Invalid report options: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256_hex() {
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_ne!(sha256_hex(b"%PDF-1.7 a"), sha256_hex(b"%PDF-1.7 b"));
+    }
+
+    #[test]
+    fn test_download_file_name_is_header_safe() {
+        let now = chrono::Utc::now();
+        let report = crate::entities::reports::Model {
+            report_id: Uuid::new_v4(),
+            assessment_id: None,
+            organization_id: None,
+            report_type: crate::entities::reports::ReportType::Summary,
+            title: "Bericht \"Ü\"\n2026/Q1\\x".to_string(),
+            format: crate::entities::reports::ReportFormat::Pdf,
+            summary: None,
+            report_data: None,
+            file_path: None,
+            file_sha256: None,
+            status: crate::entities::reports::ReportStatus::Completed,
+            options: None,
+            progress: 100,
+            attempts: 1,
+            next_attempt_at: None,
+            failure_reason: None,
+            claimed_at: None,
+            generated_at: now,
+            created_at: now,
+            updated_at: now,
+        };
+        let file_name = download_file_name(&report);
+        assert_eq!(file_name, "Bericht_Ü2026Q1x.pdf");
+
+        let header = content_disposition(&file_name);
+        assert_eq!(
+            header,
+            "attachment; filename=\"Bericht__2026Q1x.pdf\"; filename*=UTF-8''Bericht_%C3%9C2026Q1x.pdf"
+        );
+        assert!(http::HeaderValue::from_str(&header).is_ok());
+    }
 }
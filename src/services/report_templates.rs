@@ -0,0 +1,596 @@
+use crate::entities::report_templates;
+use crate::entities::reports::ReportType;
+use crate::error::AppError;
+use crate::repositories::report_templates::ReportTemplatesRepository;
+use crate::services::i18n::Locale;
+use crate::services::pdf_generator::{
+    ChartData, PdfGeneratorService, PdfReportData, PdfReportRow, ReportActionItem, ReportOptions,
+};
+use crate::services::portfolio_report::{PortfolioReportData, PortfolioReportService};
+use sea_orm::{DatabaseConnection, Set};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::error::Error as _;
+use std::sync::{Arc, RwLock};
+use tera::Tera;
+use tracing::{error, info, instrument};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Templates shipped with the service, compiled into the binary. Uploaded
+/// templates can extend `report_base.html` and include the partials.
+const BUILTIN_TEMPLATES: [(&str, &str); 8] = [
+    (
+        "report_base.html",
+        include_str!("../../templates/report_base.html"),
+    ),
+    (
+        "report_summary.html",
+        include_str!("../../templates/report_summary.html"),
+    ),
+    (
+        "report_detailed.html",
+        include_str!("../../templates/report_detailed.html"),
+    ),
+    (
+        "report_action_plan.html",
+        include_str!("../../templates/report_action_plan.html"),
+    ),
+    (
+        "report_portfolio.html",
+        include_str!("../../templates/report_portfolio.html"),
+    ),
+    (
+        "partials/overview.html",
+        include_str!("../../templates/partials/overview.html"),
+    ),
+    (
+        "partials/chart.html",
+        include_str!("../../templates/partials/chart.html"),
+    ),
+    (
+        "partials/action_plan.html",
+        include_str!("../../templates/partials/action_plan.html"),
+    ),
+];
+
+/// Name of an uploaded template inside its compiled `Tera` instance
+const CUSTOM_TEMPLATE: &str = "custom/report.html";
+
+/// Which organization the report header names
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportHeader {
+    #[default]
+    Dgrv,
+    /// The partner federation given in `partner_name`
+    Partner,
+}
+
+/// Per-organization look of a report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ReportBranding {
+    /// `https://` or `data:image/` URL of the logo shown in the header
+    pub logo_url: Option<String>,
+    /// Headings, table header and accents, `#rrggbb`
+    pub primary_color: String,
+    /// Subtitles and labels, `#rrggbb`
+    pub secondary_color: String,
+    pub footer_text: Option<String>,
+    pub header: ReportHeader,
+    /// Federation named in the header when `header` is `partner`
+    pub partner_name: Option<String>,
+}
+
+impl Default for ReportBranding {
+    fn default() -> Self {
+        Self {
+            logo_url: None,
+            primary_color: "#1e40af".to_string(),
+            secondary_color: "#64748b".to_string(),
+            footer_text: None,
+            header: ReportHeader::Dgrv,
+            partner_name: None,
+        }
+    }
+}
+
+impl ReportBranding {
+    pub fn validate(&self) -> Result<(), AppError> {
+        for (field, color) in [
+            ("primary_color", &self.primary_color),
+            ("secondary_color", &self.secondary_color),
+        ] {
+            let valid = color.len() == 7
+                && color.starts_with('#')
+                && color[1..].chars().all(|c| c.is_ascii_hexdigit());
+            if !valid {
+                return Err(AppError::ValidationError(format!(
+                    "{} must be a #rrggbb colour",
+                    field
+                )));
+            }
+        }
+        if let Some(logo_url) = &self.logo_url {
+            if !logo_url.starts_with("https://") && !logo_url.starts_with("data:image/") {
+                return Err(AppError::ValidationError(
+                    "logo_url must be an https:// or data:image/ URL".to_string(),
+                ));
+            }
+        }
+        if self.header == ReportHeader::Partner
+            && self.partner_name.as_deref().is_none_or(str::is_empty)
+        {
+            return Err(AppError::ValidationError(
+                "partner_name is required for a partner header".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Report templates managed through the admin API.
+///
+/// Every upload is a new version of the template of an organization and
+/// report type, or of the default for all organizations. Templates are
+/// validated on upload and kept compiled in memory; versions never change,
+/// so cached templates need no invalidation.
+pub struct ReportTemplateService {
+    db: Arc<DatabaseConnection>,
+    builtin: Arc<Tera>,
+    compiled: RwLock<HashMap<Uuid, Arc<Tera>>>,
+}
+
+impl ReportTemplateService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Result<Self, AppError> {
+        let builtin = compile(None).map_err(|e| {
+            AppError::InternalServerError(format!("Built-in templates are invalid: {}", e))
+        })?;
+        Ok(Self {
+            db,
+            builtin: Arc::new(builtin),
+            compiled: RwLock::new(HashMap::new()),
+        })
+    }
+
+    /// Validate and store a new active version of a template
+    pub async fn create(
+        &self,
+        organization_id: Option<String>,
+        report_type: ReportType,
+        content: String,
+        branding: ReportBranding,
+        created_by: Option<String>,
+    ) -> Result<report_templates::Model, AppError> {
+        let tera = Self::validate(&report_type, &content, &branding)?;
+        let branding = serde_json::to_value(&branding).map_err(|e| {
+            AppError::InternalServerError(format!("Failed to serialize branding: {}", e))
+        })?;
+
+        let now = chrono::Utc::now();
+        let template = ReportTemplatesRepository::create_version(
+            self.db.as_ref(),
+            report_templates::ActiveModel {
+                template_id: Set(Uuid::new_v4()),
+                organization_id: Set(organization_id),
+                report_type: Set(report_type),
+                version: Set(0),
+                content: Set(content),
+                branding: Set(branding),
+                is_active: Set(true),
+                created_by: Set(created_by),
+                created_at: Set(now),
+                updated_at: Set(now),
+            },
+        )
+        .await?;
+
+        info!(template_id = %template.template_id, version = template.version, "Report template stored");
+        self.compiled
+            .write()
+            .unwrap()
+            .insert(template.template_id, Arc::new(tera));
+        Ok(template)
+    }
+
+    /// Compile `content` against the built-in templates and render it with
+    /// sample data, so broken templates are rejected on upload rather than
+    /// when a report is generated. Both the source and the rendered sample
+    /// must be free of scripts and external resources, since the browser
+    /// printing the report would otherwise run or fetch them.
+    pub fn validate(
+        report_type: &ReportType,
+        content: &str,
+        branding: &ReportBranding,
+    ) -> Result<Tera, AppError> {
+        branding.validate()?;
+        if *report_type == ReportType::Portfolio {
+            return Err(AppError::ValidationError(
+                "Portfolio reports always use the built-in template".to_string(),
+            ));
+        }
+        ensure_no_active_content(content)?;
+        let tera = compile(Some(content))
+            .map_err(|e| AppError::ValidationError(format!("Invalid template: {}", e)))?;
+
+        // The logo is checked by the branding validation; a placeholder
+        // keeps it from being reported as an external resource below.
+        let sample_branding = ReportBranding {
+            logo_url: branding
+                .logo_url
+                .as_ref()
+                .map(|_| "data:image/png;base64,".to_string()),
+            ..branding.clone()
+        };
+        let options = ReportOptions::for_type(report_type.clone());
+        let mut context = PdfGeneratorService::template_context(&sample_data(), &options);
+        context.insert("branding", &sample_branding);
+        let html = tera.render(CUSTOM_TEMPLATE, &context).map_err(|e| {
+            AppError::ValidationError(format!("Template fails to render: {}", error_chain(&e)))
+        })?;
+        ensure_no_active_content(&html)?;
+        Ok(tera)
+    }
+
+    /// Render the report with the active template and branding of the
+    /// assessment's organization, falling back to the default template and
+    /// then to the built-in one
+    #[instrument(skip(self, data, options), fields(organization_id = %data.organization_id))]
+    pub async fn render(
+        &self,
+        data: &PdfReportData,
+        options: &ReportOptions,
+    ) -> Result<String, AppError> {
+        let (tera, name, branding) = match self
+            .active_template(&data.organization_id, &options.report_type)
+            .await?
+        {
+            Some(template) => {
+                let branding: ReportBranding = serde_json::from_value(template.branding.clone())
+                    .map_err(|e| {
+                        AppError::InternalServerError(format!("Invalid stored branding: {}", e))
+                    })?;
+                (self.compiled(&template)?, CUSTOM_TEMPLATE, branding)
+            }
+            None => (
+                self.builtin.clone(),
+                builtin_name(&options.report_type),
+                ReportBranding::default(),
+            ),
+        };
+
+        let mut context = PdfGeneratorService::template_context(data, options);
+        context.insert("branding", &branding);
+        tera.render(name, &context).map_err(|e| {
+            error!(error = %error_chain(&e), "Failed to render HTML template.");
+            AppError::InternalServerError(format!("Template render error: {}", error_chain(&e)))
+        })
+    }
+
+    /// Render a portfolio report with the built-in template
+    pub fn render_portfolio(&self, data: &PortfolioReportData) -> Result<String, AppError> {
+        let mut context = PortfolioReportService::template_context(data);
+        context.insert("branding", &ReportBranding::default());
+        self.builtin
+            .render(builtin_name(&ReportType::Portfolio), &context)
+            .map_err(|e| {
+                error!(error = %error_chain(&e), "Failed to render HTML template.");
+                AppError::InternalServerError(format!("Template render error: {}", error_chain(&e)))
+            })
+    }
+
+    async fn active_template(
+        &self,
+        organization_id: &str,
+        report_type: &ReportType,
+    ) -> Result<Option<report_templates::Model>, AppError> {
+        let db = self.db.as_ref();
+        match ReportTemplatesRepository::find_active(db, Some(organization_id), report_type.clone())
+            .await?
+        {
+            Some(template) => Ok(Some(template)),
+            None => ReportTemplatesRepository::find_active(db, None, report_type.clone()).await,
+        }
+    }
+
+    fn compiled(&self, template: &report_templates::Model) -> Result<Arc<Tera>, AppError> {
+        if let Some(tera) = self.compiled.read().unwrap().get(&template.template_id) {
+            return Ok(tera.clone());
+        }
+
+        let tera = Arc::new(compile(Some(&template.content)).map_err(|e| {
+            AppError::InternalServerError(format!("Stored template is invalid: {}", e))
+        })?);
+        self.compiled
+            .write()
+            .unwrap()
+            .insert(template.template_id, tera.clone());
+        Ok(tera)
+    }
+}
+
+fn builtin_name(report_type: &ReportType) -> &'static str {
+    match report_type {
+        ReportType::Summary => "report_summary.html",
+        ReportType::Detailed => "report_detailed.html",
+        ReportType::ActionPlan => "report_action_plan.html",
+        ReportType::Portfolio => "report_portfolio.html",
+    }
+}
+
+/// Built-in templates plus, optionally, an uploaded one
+fn compile(custom: Option<&str>) -> Result<Tera, String> {
+    let mut tera = Tera::default();
+    let templates = BUILTIN_TEMPLATES
+        .iter()
+        .copied()
+        .chain(custom.map(|content| (CUSTOM_TEMPLATE, content)));
+    tera.add_raw_templates(templates)
+        .map_err(|e| error_chain(&e))?;
+    Ok(tera)
+}
+
+/// Tera puts the useful part of an error, such as the parse position, in
+/// its sources
+fn error_chain(e: &tera::Error) -> String {
+    let mut message = e.to_string();
+    let mut source = e.source();
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    message
+}
+
+/// Markup that runs scripts or loads documents, none of which a report needs
+const FORBIDDEN_MARKUP: &[&str] = &[
+    "<script",
+    "javascript:",
+    "<iframe",
+    "<frame",
+    "<object",
+    "<embed",
+    "<link",
+    "<base",
+    "http-equiv",
+    "@import",
+];
+
+/// Attributes and CSS functions whose value the browser fetches
+const RESOURCE_REFERENCES: &[&str] = &["src=", "srcset=", "href=", "action=", "poster=", "url("];
+
+/// Rejects scripts, inline event handlers and references to anything but
+/// inline `data:` URLs, anchors and the branding logo
+fn ensure_no_active_content(html: &str) -> Result<(), AppError> {
+    let html = normalize_markup(html);
+    if let Some(markup) = FORBIDDEN_MARKUP.iter().find(|m| html.contains(*m)) {
+        return Err(AppError::ValidationError(format!(
+            "Templates must not contain {}",
+            markup
+        )));
+    }
+    if has_event_handler(&html) {
+        return Err(AppError::ValidationError(
+            "Templates must not contain event handler attributes".to_string(),
+        ));
+    }
+    for reference in RESOURCE_REFERENCES {
+        for (start, _) in html.match_indices(reference) {
+            let value = html[start + reference.len()..]
+                .trim_start_matches(|c: char| c.is_whitespace() || c == '"' || c == '\'');
+            let allowed = value.starts_with("data:")
+                || value.starts_with('#')
+                || value
+                    .strip_prefix("{{")
+                    .is_some_and(|v| v.trim_start().starts_with("branding.logo_url"));
+            if !allowed {
+                return Err(AppError::ValidationError(format!(
+                    "Templates must not reference external resources ({})",
+                    reference.trim_end_matches(['=', '('])
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Lowercases `html` and drops whitespace around `=` and before `(`, which
+/// HTML and CSS allow in `src = "..."` or `url (...)`, so that attributes and
+/// CSS functions can be matched literally
+fn normalize_markup(html: &str) -> String {
+    let mut normalized = String::with_capacity(html.len());
+    let mut chars = html.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() {
+            let mut run = c.to_string();
+            while let Some(next) = chars.next_if(|c| c.is_whitespace()) {
+                run.push(next);
+            }
+            let joins = normalized.ends_with('=') || matches!(chars.peek(), Some('=' | '('));
+            if !joins {
+                normalized.push_str(&run);
+            }
+        } else {
+            normalized.push(c.to_ascii_lowercase());
+        }
+    }
+    normalized
+}
+
+/// Whether a tag carries an `on...=` attribute such as `onload` or `onerror`
+fn has_event_handler(html: &str) -> bool {
+    html.match_indices("on").any(|(start, _)| {
+        let after_whitespace = html[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_whitespace() || c == '/');
+        let rest = &html[start + 2..];
+        let name_len = rest.chars().take_while(char::is_ascii_alphabetic).count();
+        after_whitespace && name_len > 0 && rest[name_len..].trim_start().starts_with('=')
+    })
+}
+
+/// Report data exercising every section of a template
+fn sample_data() -> PdfReportData {
+    PdfReportData {
+        assessment_title: "Sample assessment".to_string(),
+        organization_id: "sample".to_string(),
+        assessment_status: "completed".to_string(),
+        overall_score: Some(55.0),
+        rows: vec![PdfReportRow {
+            category: "Digital strategy".to_string(),
+            gap: "High".to_string(),
+            gap_class: "high".to_string(),
+            result: "No digital strategy in place".to_string(),
+            current_score: 1,
+            desired_score: 4,
+            recommendations: vec!["Define a digital strategy".to_string()],
+        }],
+        actions: vec![ReportActionItem {
+            dimension: "Digital strategy".to_string(),
+            recommendation: "Define a digital strategy".to_string(),
+            priority: "high".to_string(),
+            status: "todo".to_string(),
+        }],
+        chart_data: Some(ChartData {
+            labels: vec!["Digital strategy".to_string()],
+            current_state: vec![1],
+            desired_state: vec![4],
+        }),
+        generation_date: "January 1, 2026 00:00 UTC".to_string(),
+        locale: Locale::En,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_templates_render() {
+        let tera = compile(None).unwrap();
+        for report_type in [
+            ReportType::Summary,
+            ReportType::Detailed,
+            ReportType::ActionPlan,
+        ] {
+            let options = ReportOptions::for_type(report_type.clone());
+            let mut context = PdfGeneratorService::template_context(&sample_data(), &options);
+            context.insert("branding", &ReportBranding::default());
+            let html = tera.render(builtin_name(&report_type), &context).unwrap();
+            assert!(html.contains("Sample assessment"));
+        }
+    }
+
+    #[test]
+    fn test_portfolio_template_renders() {
+        use crate::services::portfolio_report::{PortfolioAssessment, PortfolioCell};
+
+        let dimension = PortfolioReportService::summarize("Digital strategy".to_string(), &[]);
+        let data = PortfolioReportData {
+            title: "Portfolio 2026".to_string(),
+            organization_id: "sample".to_string(),
+            dimensions: vec![dimension.clone()],
+            assessments: vec![PortfolioAssessment {
+                cooperation_id: None,
+                assessment_title: "Sample assessment".to_string(),
+                overall_score: Some(55.0),
+                cells: vec![Some(PortfolioCell {
+                    current_score: 1,
+                    desired_score: 4,
+                    gap_class: "high".to_string(),
+                })],
+            }],
+            average_maturity: Some(55.0),
+            weakest_dimensions: vec![dimension],
+            common_high_gaps: Vec::new(),
+            common_recommendations: Vec::new(),
+            generation_date: "January 1, 2026 00:00 UTC".to_string(),
+            locale: Locale::En,
+        };
+
+        let service_tera = compile(None).unwrap();
+        let mut context = PortfolioReportService::template_context(&data);
+        context.insert("branding", &ReportBranding::default());
+        let html = service_tera
+            .render(builtin_name(&ReportType::Portfolio), &context)
+            .unwrap();
+        assert!(html.contains("Portfolio 2026"));
+        assert!(html.contains("gap-high"));
+        assert!(html.contains("Organization level"));
+    }
+
+    #[test]
+    fn test_validate_template() {
+        let branding = ReportBranding::default();
+        let valid = r#"{% extends "report_base.html" %}{% block content %}{% include "partials/overview.html" %}{% endblock content %}"#;
+        assert!(ReportTemplateService::validate(&ReportType::Summary, valid, &branding).is_ok());
+
+        let unclosed = "{% if rows %}";
+        assert!(matches!(
+            ReportTemplateService::validate(&ReportType::Summary, unclosed, &branding),
+            Err(AppError::ValidationError(_))
+        ));
+        let unknown_variable = "{{ missing.field }}";
+        assert!(matches!(
+            ReportTemplateService::validate(&ReportType::Summary, unknown_variable, &branding),
+            Err(AppError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_active_content_is_rejected() {
+        let branding = ReportBranding {
+            logo_url: Some("https://example.org/logo.png".to_string()),
+            ..Default::default()
+        };
+        let wrap = |body: &str| {
+            format!(
+                r#"{{% extends "report_base.html" %}}{{% block content %}}{}{{% endblock content %}}"#,
+                body
+            )
+        };
+        let logo = r##"<img src="{{ branding.logo_url }}"><a href="#top">Top</a>"##;
+        assert!(
+            ReportTemplateService::validate(&ReportType::Summary, &wrap(logo), &branding).is_ok()
+        );
+
+        for body in [
+            "<script>fetch('https://example.org')</script>",
+            "<SCRIPT src=\"x.js\"></SCRIPT>",
+            r#"<img src="x" onerror="alert(1)">"#,
+            r#"<img src="http://169.254.169.254/latest/meta-data">"#,
+            r#"<img src="//example.org/pixel.png">"#,
+            r#"<img src = "https://example.org/pixel.png">"#,
+            "<img\n  SRC\t=\n'https://example.org/pixel.png'>",
+            r#"<div style="background: url ( https://example.org/a.png )"></div>"#,
+            r#"<div style="background: url(https://example.org/a.png)"></div>"#,
+            r#"<img src="{{ 'http:' ~ '//example.org' }}">"#,
+        ] {
+            assert!(
+                matches!(
+                    ReportTemplateService::validate(&ReportType::Summary, &wrap(body), &branding),
+                    Err(AppError::ValidationError(_))
+                ),
+                "{} was accepted",
+                body
+            );
+        }
+    }
+
+    #[test]
+    fn test_branding_validation() {
+        assert!(ReportBranding::default().validate().is_ok());
+        let branding = ReportBranding {
+            primary_color: "blue".to_string(),
+            ..Default::default()
+        };
+        assert!(branding.validate().is_err());
+        let branding = ReportBranding {
+            header: ReportHeader::Partner,
+            ..Default::default()
+        };
+        assert!(branding.validate().is_err());
+    }
+}
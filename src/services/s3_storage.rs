@@ -1,12 +1,23 @@
 use crate::config::MinioConfig;
 use crate::error::AppError;
+use crate::services::report_service::content_disposition;
 use async_trait::async_trait;
 use aws_config::BehaviorVersion;
 use aws_credential_types::provider::SharedCredentialsProvider;
 use aws_credential_types::Credentials;
+use aws_sdk_s3::presigning::PresigningConfig;
 use aws_sdk_s3::primitives::ByteStream;
 use aws_sdk_s3::Client as S3Client;
 use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+/// A stored file found by `list_files`
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredFile {
+    pub object_name: String,
+    pub last_modified: Option<DateTime<Utc>>,
+}
 
 pub struct S3StorageService {
     client: S3Client,
@@ -116,11 +127,76 @@ impl S3StorageService {
         Ok(())
     }
 
+    /// Every object whose name starts with `prefix`
+    pub async fn list_files(&self, prefix: &str) -> Result<Vec<StoredFile>, AppError> {
+        let mut pages = self
+            .client
+            .list_objects_v2()
+            .bucket(&self.bucket_name)
+            .prefix(prefix)
+            .into_paginator()
+            .send();
+
+        let mut files = Vec::new();
+        while let Some(page) = pages.next().await {
+            let page =
+                page.map_err(|e| AppError::FileStorageError(format!("Failed to list files: {e}")))?;
+            files.extend(page.contents().iter().filter_map(|object| {
+                Some(StoredFile {
+                    object_name: object.key()?.to_string(),
+                    last_modified: object
+                        .last_modified()
+                        .and_then(|time| DateTime::from_timestamp(time.secs(), 0)),
+                })
+            }));
+        }
+
+        Ok(files)
+    }
+
+    /// URL downloading the file as `filename` without credentials, valid
+    /// for `expires_in`
+    pub async fn presigned_download_url(
+        &self,
+        object_name: &str,
+        filename: &str,
+        expires_in: Duration,
+    ) -> Result<String, AppError> {
+        let presigning_config = PresigningConfig::expires_in(expires_in).map_err(|e| {
+            AppError::FileStorageError(format!("Invalid presigned URL expiry: {e}"))
+        })?;
+
+        let request = self
+            .client
+            .get_object()
+            .bucket(&self.bucket_name)
+            .key(object_name)
+            .response_content_disposition(content_disposition(filename))
+            .presigned(presigning_config)
+            .await
+            .map_err(|e| AppError::FileStorageError(format!("Failed to presign URL: {e}")))?;
+
+        Ok(request.uri().to_string())
+    }
+
     pub fn generate_object_name(&self, report_id: &uuid::Uuid, format: &str) -> String {
-        format!("reports/{}/report.{}", report_id, format.to_lowercase())
+        report_object_name(report_id, format)
     }
 }
 
+/// Prefix of every report file's object name
+pub const REPORT_OBJECT_PREFIX: &str = "reports/";
+
+/// Name a report file is stored under, whatever the storage backend
+pub fn report_object_name(report_id: &uuid::Uuid, format: &str) -> String {
+    format!(
+        "{}{}/report.{}",
+        REPORT_OBJECT_PREFIX,
+        report_id,
+        format.to_lowercase()
+    )
+}
+
 #[async_trait]
 pub trait FileStorageService: Send + Sync {
     async fn upload_file(
@@ -133,6 +209,17 @@ pub trait FileStorageService: Send + Sync {
     async fn download_file(&self, object_name: &str) -> Result<Bytes, AppError>;
 
     async fn delete_file(&self, object_name: &str) -> Result<(), AppError>;
+
+    async fn list_files(&self, prefix: &str) -> Result<Vec<StoredFile>, AppError>;
+
+    /// Time-limited URL the file can be downloaded from without going
+    /// through the API, or `None` if the backend cannot issue one
+    async fn presigned_download_url(
+        &self,
+        object_name: &str,
+        filename: &str,
+        expires_in: Duration,
+    ) -> Result<Option<String>, AppError>;
 }
 
 #[async_trait]
@@ -153,4 +240,19 @@ impl FileStorageService for S3StorageService {
     async fn delete_file(&self, object_name: &str) -> Result<(), AppError> {
         self.delete_file(object_name).await
     }
+
+    async fn list_files(&self, prefix: &str) -> Result<Vec<StoredFile>, AppError> {
+        self.list_files(prefix).await
+    }
+
+    async fn presigned_download_url(
+        &self,
+        object_name: &str,
+        filename: &str,
+        expires_in: Duration,
+    ) -> Result<Option<String>, AppError> {
+        self.presigned_download_url(object_name, filename, expires_in)
+            .await
+            .map(Some)
+    }
 }
@@ -1,26 +1,58 @@
 use crate::{
     entities::{
         assessments::{self, AssessmentStatus, Entity as Assessment},
-        reports::{
-            ActiveModel as NewReport, Model as ReportModel, ReportFormat, ReportStatus, ReportType,
-        },
+        current_states, desired_states, dimension_assessments, dimensions,
+        reports::{Model as ReportModel, ReportFormat, ReportType},
     },
     error::AppError,
-    services::report_service::ReportService,
+    repositories::{
+        dimension_assessments::DimensionAssessmentsRepository,
+        organisation_dimension::OrganisationDimensionRepository,
+    },
+    services::{
+        assessment_lifecycle::AssessmentLifecycleService,
+        dimension_scoring::DimensionScoringService,
+        framework_versioning::FrameworkVersioningService, pdf_generator::ReportOptions,
+        report_queue::ReportQueue,
+    },
 };
-use sea_orm::{ActiveModelTrait, DbConn, EntityTrait, Set};
+use sea_orm::{ColumnTrait, DbConn, EntityTrait, QueryFilter, Set, TransactionTrait};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+/// Why an assigned dimension blocks submission.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MissingDimensionReason {
+    /// No dimension assessment was recorded for the dimension.
+    NotAssessed,
+    /// The current state does not exist or belongs to another dimension.
+    InvalidCurrentState,
+    /// The desired state does not exist or belongs to another dimension.
+    InvalidDesiredState,
+}
+
+/// A dimension that has to be completed before the assessment can be submitted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct MissingDimension {
+    pub dimension_id: Uuid,
+    pub dimension_name: Option<String>,
+    pub dimension_assessment_id: Option<Uuid>,
+    pub reasons: Vec<MissingDimensionReason>,
+}
+
 #[derive(Clone)]
 pub struct SubmissionService {
     db: Arc<DbConn>,
-    report_service: Arc<ReportService>,
+    report_queue: Arc<ReportQueue>,
 }
 
 impl SubmissionService {
-    pub fn new(db: Arc<DbConn>, report_service: Arc<ReportService>) -> Self {
-        Self { db, report_service }
+    pub fn new(db: Arc<DbConn>, report_queue: Arc<ReportQueue>) -> Self {
+        Self { db, report_queue }
     }
 
     pub async fn submit_assessment(
@@ -31,42 +63,288 @@ impl SubmissionService {
         let _user_uuid = Uuid::parse_str(&user_id)
             .map_err(|_| AppError::BadRequest("Invalid user ID format".to_string()))?;
 
-        let mut assessment: assessments::ActiveModel = Assessment::find_by_id(assessment_id)
+        let assessment = Assessment::find_by_id(assessment_id)
             .one(self.db.as_ref())
             .await?
-            .ok_or_else(|| AppError::NotFound("Assessment not found".to_string()))?
-            .into();
-
-        assessment.status = Set(AssessmentStatus::Completed);
-        assessment.updated_at = Set(chrono::Utc::now());
-        assessment.update(self.db.as_ref()).await?;
-
-        let report_id = Uuid::new_v4();
-        let new_report = NewReport {
-            report_id: Set(report_id),
-            assessment_id: Set(assessment_id),
-            status: Set(ReportStatus::Pending),
-            report_type: Set(ReportType::Summary),
-            title: Set("Summary Report".to_string()),
-            format: Set(ReportFormat::Pdf),
-            ..Default::default()
-        };
-
-        let report_model = new_report.insert(self.db.as_ref()).await?;
-
-        let report_service = self.report_service.clone();
-        tokio::spawn(async move {
-            if let Err(e) = report_service
-                .generate_report_for_submission(report_id)
-                .await
-            {
-                eprintln!(
-                    "Failed to generate report for assessment {}: {}",
-                    assessment_id, e
-                );
-            }
-        });
+            .ok_or_else(|| AppError::NotFound("Assessment not found".to_string()))?;
+        AssessmentLifecycleService::ensure_submittable(&assessment)?;
+
+        let mut active_model: assessments::ActiveModel = assessment.clone().into();
+        AssessmentLifecycleService::apply_transition(
+            &assessment,
+            &mut active_model,
+            AssessmentStatus::Completed,
+            false,
+        )?;
+
+        let missing = self.find_missing_dimensions(&assessment).await?;
+        if !missing.is_empty() {
+            return Err(AppError::ValidationFailed {
+                message: format!(
+                    "Assessment is incomplete: {} dimension(s) need a valid current and desired state",
+                    missing.len()
+                ),
+                details: serde_json::json!({ "missing_dimensions": missing }),
+            });
+        }
+
+        let score =
+            DimensionScoringService::calculate_assessment_score(self.db.as_ref(), assessment_id)
+                .await?;
+        active_model.overall_score = Set(Some(score.maturity_index));
+        active_model.scores = Set(Some(serde_json::to_value(&score).map_err(|e| {
+            AppError::InternalServerError(format!("Failed to serialize scores: {e}"))
+        })?));
+        active_model.updated_at = Set(chrono::Utc::now());
+
+        // Completing only an in-progress assessment makes concurrent submits
+        // race on the row: the loser updates nothing and gets a conflict. The
+        // summary report is queued in the same transaction, so a completed
+        // assessment always has one.
+        let txn = self.db.begin().await?;
+        let completed = Assessment::update_many()
+            .set(active_model)
+            .filter(assessments::Column::AssessmentId.eq(assessment_id))
+            .filter(assessments::Column::Status.eq(AssessmentStatus::InProgress))
+            .exec(&txn)
+            .await?;
+        if completed.rows_affected == 0 {
+            return Err(AppError::Conflict(
+                "Assessment has already been submitted".to_string(),
+            ));
+        }
+        let report_model = self
+            .report_queue
+            .enqueue_in(
+                &txn,
+                assessment_id,
+                ReportType::Summary,
+                "Summary Report".to_string(),
+                ReportFormat::Pdf,
+                &ReportOptions::for_type(ReportType::Summary),
+            )
+            .await?;
+        txn.commit().await?;
+        self.report_queue.wake();
 
         Ok(report_model)
     }
+
+    /// Dimensions assigned to `assessment` that do not have a complete
+    /// dimension assessment yet.
+    ///
+    /// The assessment's own `dimensions_id` selection is used when present,
+    /// otherwise all dimensions assigned to the organization, as far as the
+    /// assessment's framework version has them.
+    pub async fn find_missing_dimensions(
+        &self,
+        assessment: &assessments::Model,
+    ) -> Result<Vec<MissingDimension>, AppError> {
+        let db = self.db.as_ref();
+
+        let mut assigned = assigned_dimension_ids(assessment);
+        if assigned.is_empty() {
+            assigned = OrganisationDimensionRepository::list_by_organisation(
+                db,
+                &assessment.organization_id,
+            )
+            .await?
+            .into_iter()
+            .map(|assignment| assignment.dimension_id)
+            .collect();
+            // Assignments follow the current framework version
+            assigned = FrameworkVersioningService::dimensions_in_version(
+                db,
+                &assigned,
+                assessment.framework_version_id,
+            )
+            .await?;
+        }
+        if assigned.is_empty() {
+            return Err(AppError::ValidationError(
+                "Assessment has no dimensions assigned".to_string(),
+            ));
+        }
+
+        let dimension_assessments =
+            DimensionAssessmentsRepository::find_by_assessment_id(db, assessment.assessment_id)
+                .await?;
+
+        let current_states: HashMap<Uuid, Uuid> = current_states::Entity::find()
+            .filter(
+                current_states::Column::CurrentStateId.is_in(
+                    dimension_assessments
+                        .iter()
+                        .map(|da| da.current_state_id)
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|state| (state.current_state_id, state.dimension_id))
+            .collect();
+        let desired_states: HashMap<Uuid, Uuid> = desired_states::Entity::find()
+            .filter(
+                desired_states::Column::DesiredStateId.is_in(
+                    dimension_assessments
+                        .iter()
+                        .map(|da| da.desired_state_id)
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|state| (state.desired_state_id, state.dimension_id))
+            .collect();
+
+        let mut missing = check_completeness(
+            &assigned,
+            &dimension_assessments,
+            &current_states,
+            &desired_states,
+        );
+
+        if !missing.is_empty() {
+            let names: HashMap<Uuid, String> = dimensions::Entity::find()
+                .filter(
+                    dimensions::Column::DimensionId
+                        .is_in(missing.iter().map(|m| m.dimension_id).collect::<Vec<_>>()),
+                )
+                .all(db)
+                .await?
+                .into_iter()
+                .map(|dimension| (dimension.dimension_id, dimension.name))
+                .collect();
+            for entry in &mut missing {
+                entry.dimension_name = names.get(&entry.dimension_id).cloned();
+            }
+        }
+
+        Ok(missing)
+    }
+}
+
+/// Dimension IDs stored in `assessments.dimensions_id`, in order and without
+/// duplicates. Entries that are not valid UUIDs are ignored.
+fn assigned_dimension_ids(assessment: &assessments::Model) -> Vec<Uuid> {
+    let mut seen = HashSet::new();
+    assessment
+        .dimensions_id
+        .as_ref()
+        .and_then(|value| value.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|value| value.as_str())
+        .filter_map(|id| Uuid::parse_str(id).ok())
+        .filter(|id| seen.insert(*id))
+        .collect()
+}
+
+/// Compare assigned dimensions against the recorded dimension assessments.
+///
+/// `current_states` and `desired_states` map a state ID to the dimension it
+/// belongs to.
+fn check_completeness(
+    assigned: &[Uuid],
+    dimension_assessments: &[dimension_assessments::Model],
+    current_states: &HashMap<Uuid, Uuid>,
+    desired_states: &HashMap<Uuid, Uuid>,
+) -> Vec<MissingDimension> {
+    assigned
+        .iter()
+        .filter_map(|dimension_id| {
+            let Some(da) = dimension_assessments
+                .iter()
+                .find(|da| da.dimension_id == *dimension_id)
+            else {
+                return Some(MissingDimension {
+                    dimension_id: *dimension_id,
+                    dimension_name: None,
+                    dimension_assessment_id: None,
+                    reasons: vec![MissingDimensionReason::NotAssessed],
+                });
+            };
+
+            let mut reasons = Vec::new();
+            if current_states.get(&da.current_state_id) != Some(dimension_id) {
+                reasons.push(MissingDimensionReason::InvalidCurrentState);
+            }
+            if desired_states.get(&da.desired_state_id) != Some(dimension_id) {
+                reasons.push(MissingDimensionReason::InvalidDesiredState);
+            }
+            (!reasons.is_empty()).then_some(MissingDimension {
+                dimension_id: *dimension_id,
+                dimension_name: None,
+                dimension_assessment_id: Some(da.dimension_assessment_id),
+                reasons,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dimension_assessment(
+        dimension_id: Uuid,
+        current_state_id: Uuid,
+        desired_state_id: Uuid,
+    ) -> dimension_assessments::Model {
+        let now = chrono::Utc::now();
+        dimension_assessments::Model {
+            dimension_assessment_id: Uuid::new_v4(),
+            assessment_id: Uuid::new_v4(),
+            dimension_id,
+            current_state_id,
+            desired_state_id,
+            gap_score: 0,
+            gap_id: Uuid::new_v4(),
+            organization_id: "org".to_string(),
+            cooperation_id: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_unanswered_dimension_is_reported() {
+        let (answered, unanswered) = (Uuid::new_v4(), Uuid::new_v4());
+        let (current, desired) = (Uuid::new_v4(), Uuid::new_v4());
+        let das = vec![dimension_assessment(answered, current, desired)];
+        let current_states = HashMap::from([(current, answered)]);
+        let desired_states = HashMap::from([(desired, answered)]);
+
+        let missing = check_completeness(
+            &[answered, unanswered],
+            &das,
+            &current_states,
+            &desired_states,
+        );
+
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].dimension_id, unanswered);
+        assert_eq!(
+            missing[0].reasons,
+            vec![MissingDimensionReason::NotAssessed]
+        );
+    }
+
+    #[test]
+    fn test_state_of_another_dimension_is_invalid() {
+        let (dimension, other) = (Uuid::new_v4(), Uuid::new_v4());
+        let (current, desired) = (Uuid::new_v4(), Uuid::new_v4());
+        let das = vec![dimension_assessment(dimension, current, desired)];
+        let current_states = HashMap::from([(current, other)]);
+        let desired_states = HashMap::from([(desired, dimension)]);
+
+        let missing = check_completeness(&[dimension], &das, &current_states, &desired_states);
+
+        assert_eq!(
+            missing[0].reasons,
+            vec![MissingDimensionReason::InvalidCurrentState]
+        );
+    }
 }
@@ -0,0 +1,349 @@
+use crate::error::AppError;
+use crate::services::pdf_generator::{PdfGeneratorService, PdfReportData, ReportOptions};
+use crate::services::portfolio_report::{
+    PortfolioItem, PortfolioReportData, PortfolioReportService,
+};
+use bytes::Bytes;
+use rust_xlsxwriter::{Format, Workbook, Worksheet, XlsxError};
+use sea_orm::DatabaseConnection;
+use std::collections::HashMap;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+pub struct XlsxGeneratorService;
+
+impl XlsxGeneratorService {
+    /// Main entry point to generate an Excel workbook for an assessment
+    #[instrument(skip(db), fields(assessment_id = %assessment_id))]
+    pub async fn generate_assessment_xlsx(
+        db: &DatabaseConnection,
+        assessment_id: Uuid,
+        options: &ReportOptions,
+    ) -> Result<Bytes, AppError> {
+        info!("Fetching report data from database.");
+        let report_data =
+            PdfGeneratorService::fetch_report_data(db, assessment_id, options.locale).await?;
+
+        info!("Building Excel workbook.");
+        Self::build_workbook(&report_data, options)
+    }
+
+    /// Write the overview, dimensions and action plan sheets selected by
+    /// `options`
+    pub fn build_workbook(
+        data: &PdfReportData,
+        options: &ReportOptions,
+    ) -> Result<Bytes, AppError> {
+        let mut workbook = Workbook::new();
+        let header = Format::new().set_bold();
+
+        if options.includes("overview") {
+            Self::write_overview(workbook.add_worksheet(), data, &header).map_err(xlsx_error)?;
+        }
+        if options.includes("dimensions") {
+            Self::write_dimensions(
+                workbook.add_worksheet(),
+                data,
+                &header,
+                options.includes("recommendations"),
+            )
+            .map_err(xlsx_error)?;
+        }
+        if options.includes("action_plan") {
+            Self::write_action_plan(workbook.add_worksheet(), data, &header).map_err(xlsx_error)?;
+        }
+
+        let buffer = workbook.save_to_buffer().map_err(xlsx_error)?;
+        Ok(Bytes::from(buffer))
+    }
+
+    /// Write the heatmap, weakest dimensions, high gaps and recommendations
+    /// sheets of a portfolio report
+    pub fn build_portfolio_workbook(data: &PortfolioReportData) -> Result<Bytes, AppError> {
+        let mut workbook = Workbook::new();
+        let header = Format::new().set_bold();
+
+        Self::write_heatmap(workbook.add_worksheet(), data, &header).map_err(xlsx_error)?;
+        Self::write_weakest_dimensions(workbook.add_worksheet(), data, &header)
+            .map_err(xlsx_error)?;
+        for (sheet_name, text_column, items) in [
+            ("sheets.high_gaps", "columns.result", &data.common_high_gaps),
+            (
+                "sheets.recommendations",
+                "columns.recommendation",
+                &data.common_recommendations,
+            ),
+        ] {
+            Self::write_portfolio_items(
+                workbook.add_worksheet(),
+                data,
+                &header,
+                (sheet_name, text_column),
+                items,
+            )
+            .map_err(xlsx_error)?;
+        }
+
+        let buffer = workbook.save_to_buffer().map_err(xlsx_error)?;
+        Ok(Bytes::from(buffer))
+    }
+
+    fn write_heatmap(
+        sheet: &mut Worksheet,
+        data: &PortfolioReportData,
+        header: &Format,
+    ) -> Result<(), XlsxError> {
+        let locale = data.locale;
+        sheet.set_name(locale.text("sheets.heatmap"))?;
+        sheet.set_column_width(0, 28)?;
+        sheet.set_column_width(1, 16)?;
+        sheet.write_string_with_format(0, 0, locale.text("portfolio.cooperative"), header)?;
+        sheet.write_string_with_format(0, 1, locale.text("overview.maturity_index"), header)?;
+        for (index, dimension) in data.dimensions.iter().enumerate() {
+            let col = index as u16 + 2;
+            sheet.write_string_with_format(0, col, &dimension.name, header)?;
+            sheet.set_column_width(col, 16)?;
+        }
+
+        // Cells hold the gap, coloured by its severity
+        let severity_formats: HashMap<&str, Format> =
+            [("high", 0xfecaca), ("medium", 0xfef08a), ("low", 0xbbf7d0)]
+                .into_iter()
+                .map(|(class, color)| (class, Format::new().set_background_color(color)))
+                .collect();
+        for (index, assessment) in data.assessments.iter().enumerate() {
+            let line = index as u32 + 1;
+            sheet.write_string(
+                line,
+                0,
+                PortfolioReportService::row_label(assessment, locale),
+            )?;
+            if let Some(score) = assessment.overall_score {
+                sheet.write_number(line, 1, score)?;
+            }
+            for (col, cell) in assessment.cells.iter().enumerate() {
+                let Some(cell) = cell else { continue };
+                let gap = cell.desired_score - cell.current_score;
+                match severity_formats.get(cell.gap_class.as_str()) {
+                    Some(format) => {
+                        sheet.write_number_with_format(line, col as u16 + 2, gap, format)?
+                    }
+                    None => sheet.write_number(line, col as u16 + 2, gap)?,
+                };
+            }
+        }
+        Ok(())
+    }
+
+    fn write_weakest_dimensions(
+        sheet: &mut Worksheet,
+        data: &PortfolioReportData,
+        header: &Format,
+    ) -> Result<(), XlsxError> {
+        let locale = data.locale;
+        sheet.set_name(locale.text("sheets.weakest"))?;
+        let columns = [
+            ("columns.dimension", 28),
+            ("portfolio.average_current", 14),
+            ("portfolio.average_desired", 14),
+            ("portfolio.average_gap", 14),
+            ("overview.high_gaps", 14),
+            ("portfolio.assessed", 14),
+        ];
+        for (col, (title, width)) in columns.iter().enumerate() {
+            sheet.write_string_with_format(0, col as u16, locale.text(title), header)?;
+            sheet.set_column_width(col as u16, *width)?;
+        }
+
+        for (index, dimension) in data.weakest_dimensions.iter().enumerate() {
+            let line = index as u32 + 1;
+            sheet.write_string(line, 0, &dimension.name)?;
+            sheet.write_number(line, 1, dimension.average_current)?;
+            sheet.write_number(line, 2, dimension.average_desired)?;
+            sheet.write_number(line, 3, dimension.average_gap)?;
+            sheet.write_number(line, 4, dimension.high_gaps as u32)?;
+            sheet.write_number(line, 5, dimension.assessed as u32)?;
+        }
+        Ok(())
+    }
+
+    fn write_portfolio_items(
+        sheet: &mut Worksheet,
+        data: &PortfolioReportData,
+        header: &Format,
+        (sheet_name, text_column): (&str, &str),
+        items: &[PortfolioItem],
+    ) -> Result<(), XlsxError> {
+        let locale = data.locale;
+        sheet.set_name(locale.text(sheet_name))?;
+        let columns = [
+            ("columns.dimension", 28),
+            (text_column, 64),
+            ("portfolio.occurrences", 14),
+        ];
+        for (col, (title, width)) in columns.iter().enumerate() {
+            sheet.write_string_with_format(0, col as u16, locale.text(title), header)?;
+            sheet.set_column_width(col as u16, *width)?;
+        }
+
+        for (index, item) in items.iter().enumerate() {
+            let line = index as u32 + 1;
+            sheet.write_string(line, 0, &item.dimension)?;
+            sheet.write_string(line, 1, &item.text)?;
+            sheet.write_number(line, 2, item.occurrences as u32)?;
+        }
+        Ok(())
+    }
+
+    fn write_overview(
+        sheet: &mut Worksheet,
+        data: &PdfReportData,
+        header: &Format,
+    ) -> Result<(), XlsxError> {
+        let locale = data.locale;
+        sheet.set_name(locale.text("sheets.overview"))?;
+        sheet.set_column_width(0, 24)?;
+        sheet.set_column_width(1, 48)?;
+
+        let count = |class: &str| data.rows.iter().filter(|r| r.gap_class == class).count();
+        let fields = [
+            ("fields.assessment", data.assessment_title.clone()),
+            ("fields.organization", data.organization_id.clone()),
+            ("fields.status", data.assessment_status.clone()),
+            ("fields.generated", data.generation_date.clone()),
+            ("fields.dimensions_assessed", data.rows.len().to_string()),
+            ("overview.high_gaps", count("high").to_string()),
+            ("overview.medium_gaps", count("medium").to_string()),
+            ("overview.low_gaps", count("low").to_string()),
+            ("fields.action_items", data.actions.len().to_string()),
+        ];
+        for (row, (label, value)) in fields.iter().enumerate() {
+            sheet.write_string_with_format(row as u32, 0, locale.text(label), header)?;
+            sheet.write_string(row as u32, 1, value)?;
+        }
+
+        let row = fields.len() as u32;
+        sheet.write_string_with_format(row, 0, locale.text("overview.maturity_index"), header)?;
+        match data.overall_score {
+            Some(score) => sheet.write_number(row, 1, score)?,
+            None => sheet.write_string(row, 1, locale.text("text.not_submitted"))?,
+        };
+        Ok(())
+    }
+
+    fn write_dimensions(
+        sheet: &mut Worksheet,
+        data: &PdfReportData,
+        header: &Format,
+        include_recommendations: bool,
+    ) -> Result<(), XlsxError> {
+        let locale = data.locale;
+        sheet.set_name(locale.text("sheets.dimensions"))?;
+        let mut columns = vec![
+            ("columns.dimension", 28),
+            ("columns.current", 14),
+            ("columns.desired", 14),
+            ("columns.gap", 8),
+            ("columns.gap_severity", 14),
+            ("columns.result", 48),
+        ];
+        if include_recommendations {
+            columns.push(("columns.recommendations", 64));
+        }
+        for (col, (title, width)) in columns.iter().enumerate() {
+            sheet.write_string_with_format(0, col as u16, locale.text(title), header)?;
+            sheet.set_column_width(col as u16, *width)?;
+        }
+
+        for (index, row) in data.rows.iter().enumerate() {
+            let line = index as u32 + 1;
+            sheet.write_string(line, 0, &row.category)?;
+            sheet.write_number(line, 1, row.current_score)?;
+            sheet.write_number(line, 2, row.desired_score)?;
+            sheet.write_number(line, 3, row.desired_score - row.current_score)?;
+            sheet.write_string(line, 4, &row.gap)?;
+            sheet.write_string(line, 5, &row.result)?;
+            if include_recommendations {
+                sheet.write_string(line, 6, row.recommendations.join("\n"))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_action_plan(
+        sheet: &mut Worksheet,
+        data: &PdfReportData,
+        header: &Format,
+    ) -> Result<(), XlsxError> {
+        let locale = data.locale;
+        sheet.set_name(locale.text("sheets.action_plan"))?;
+        let columns = [
+            ("columns.dimension", 28),
+            ("columns.recommendation", 64),
+            ("columns.priority", 12),
+            ("columns.status", 14),
+        ];
+        for (col, (title, width)) in columns.iter().enumerate() {
+            sheet.write_string_with_format(0, col as u16, locale.text(title), header)?;
+            sheet.set_column_width(col as u16, *width)?;
+        }
+
+        for (index, action) in data.actions.iter().enumerate() {
+            let line = index as u32 + 1;
+            sheet.write_string(line, 0, &action.dimension)?;
+            sheet.write_string(line, 1, &action.recommendation)?;
+            sheet.write_string(
+                line,
+                2,
+                locale.text(&format!("priority.{}", action.priority)),
+            )?;
+            sheet.write_string(line, 3, locale.text(&format!("status.{}", action.status)))?;
+        }
+        Ok(())
+    }
+}
+
+fn xlsx_error(e: XlsxError) -> AppError {
+    AppError::InternalServerError(format!("Failed to build Excel workbook: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::reports::ReportType;
+    use crate::services::i18n::Locale;
+    use crate::services::pdf_generator::{PdfReportRow, ReportActionItem};
+
+    #[test]
+    fn test_build_workbook_produces_xlsx() {
+        let data = PdfReportData {
+            assessment_title: "Assessment".to_string(),
+            organization_id: "org".to_string(),
+            assessment_status: "completed".to_string(),
+            overall_score: Some(62.5),
+            rows: vec![PdfReportRow {
+                category: "Strategy".to_string(),
+                gap: "Moyen".to_string(),
+                gap_class: "medium".to_string(),
+                result: "No description".to_string(),
+                current_score: 2,
+                desired_score: 4,
+                recommendations: vec!["Write a digital strategy".to_string()],
+            }],
+            actions: vec![ReportActionItem {
+                dimension: "Strategy".to_string(),
+                recommendation: "Write a digital strategy".to_string(),
+                priority: "high".to_string(),
+                status: "todo".to_string(),
+            }],
+            chart_data: None,
+            generation_date: "18 octobre 2026 12:00 UTC".to_string(),
+            locale: Locale::Fr,
+        };
+
+        let options = ReportOptions::for_type(ReportType::Detailed);
+        let bytes = XlsxGeneratorService::build_workbook(&data, &options).unwrap();
+        // XLSX files are zip archives
+        assert_eq!(&bytes[..2], b"PK");
+    }
+}
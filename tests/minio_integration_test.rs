@@ -103,7 +103,7 @@ async fn test_report_generation_and_storage() {
     );
 
     let report = result.unwrap();
-    assert_eq!(report.assessment_id, assessment_id);
+    assert_eq!(report.assessment_id, Some(assessment_id));
     assert_eq!(report.title, "Test Report");
     assert_eq!(report.format, ReportFormat::Pdf);
     assert_eq!(report.report_type, ReportType::Summary);